@@ -21,6 +21,7 @@ defmodule Explorer.Backend.DataFrame do
   @type dtype :: Explorer.Series.dtype()
   @type dtypes :: %{column_name() => dtype()}
   @type io_dtypes :: [{column_name(), dtype()}]
+  @type arrow_c_data :: Explorer.Series.arrow_c_data()
 
   @type basic_types :: float() | integer() | String.t() | Date.t() | DateTime.t()
   @type mutate_value ::
@@ -35,6 +36,28 @@ defmodule Explorer.Backend.DataFrame do
   @type compression :: {algorithm :: option(atom()), level :: option(integer())}
   @type columns_for_io :: list(column_name()) | list(pos_integer()) | nil
 
+  @type csv_write_options :: %{
+          date_format: option(String.t()),
+          time_format: option(String.t()),
+          datetime_format: option(String.t()),
+          float_precision: option(non_neg_integer()),
+          null_value: String.t(),
+          line_terminator: String.t(),
+          quote_style: :necessary | :always | :non_numeric | :never
+        }
+  @type parquet_write_options :: %{
+          row_group_size: option(pos_integer()),
+          data_page_size: option(pos_integer()),
+          statistics: [:min_max | :null_count | :distinct_count]
+        }
+  @type csv_dialect :: %{
+          quote_char: option(String.t()),
+          comment_prefix: option(String.t()),
+          decimal_comma: boolean(),
+          missing_is_null: boolean(),
+          truncate_ragged_lines: boolean()
+        }
+
   @type fs_entry :: Explorer.DataFrame.fs_entry()
 
   # IO: query
@@ -58,17 +81,27 @@ defmodule Explorer.Backend.DataFrame do
               columns :: columns_for_io(),
               infer_schema_length :: option(integer()),
               parse_dates :: boolean(),
-              eol_delimiter :: option(String.t())
+              eol_delimiter :: option(String.t()),
+              dialect :: csv_dialect(),
+              include_file_paths :: option(column_name())
             ) :: io_result(df)
   @callback to_csv(
               df,
               entry :: fs_entry(),
               header? :: boolean(),
               delimiter :: String.t(),
+              options :: csv_write_options(),
+              compression(),
               streaming :: boolean()
             ) ::
               ok_result()
-  @callback dump_csv(df, header? :: boolean(), delimiter :: String.t()) :: io_result(binary())
+  @callback dump_csv(
+              df,
+              header? :: boolean(),
+              delimiter :: String.t(),
+              options :: csv_write_options(),
+              compression()
+            ) :: io_result(binary())
 
   @callback load_csv(
               contents :: String.t(),
@@ -83,7 +116,8 @@ defmodule Explorer.Backend.DataFrame do
               columns :: columns_for_io(),
               infer_schema_length :: option(integer()),
               parse_dates :: boolean(),
-              eol_delimiter :: option(String.t())
+              eol_delimiter :: option(String.t()),
+              dialect :: csv_dialect()
             ) :: io_result(df)
 
   # IO: Parquet
@@ -91,25 +125,48 @@ defmodule Explorer.Backend.DataFrame do
               entry :: fs_entry(),
               max_rows :: option(integer()),
               columns :: columns_for_io(),
-              rechunk :: boolean()
+              rechunk :: boolean(),
+              hive_partitioning :: option(boolean()),
+              include_file_paths :: option(column_name())
             ) :: io_result(df)
   @callback to_parquet(
               df,
               entry :: fs_entry(),
               compression(),
+              options :: parquet_write_options(),
               streaming :: boolean()
             ) ::
               ok_result()
-  @callback dump_parquet(df, compression()) :: io_result(binary())
+  @callback to_parquet_partitioned(
+              df,
+              entry :: fs_entry(),
+              partition_by :: [column_name()],
+              compression(),
+              options :: parquet_write_options()
+            ) ::
+              ok_result()
+  @callback dump_parquet(df, compression(), options :: parquet_write_options()) ::
+              io_result(binary())
   @callback load_parquet(contents :: binary()) :: io_result(df)
+  @callback parquet_metadata(entry :: fs_entry()) :: io_result(df)
+  @callback load_parquet_metadata(contents :: binary()) :: io_result(df)
 
   # IO: IPC
   @callback from_ipc(
               entry :: fs_entry(),
-              columns :: columns_for_io()
+              columns :: columns_for_io(),
+              hive_partitioning :: option(boolean()),
+              include_file_paths :: option(column_name())
             ) :: io_result(df)
   @callback to_ipc(df, entry :: fs_entry(), compression(), streaming :: boolean()) ::
               ok_result()
+  @callback to_ipc_partitioned(
+              df,
+              entry :: fs_entry(),
+              partition_by :: [column_name()],
+              compression()
+            ) ::
+              ok_result()
   @callback dump_ipc(df, compression()) :: io_result(binary())
   @callback load_ipc(
               contents :: binary(),
@@ -124,7 +181,8 @@ defmodule Explorer.Backend.DataFrame do
   @callback to_ipc_stream(
               df,
               entry :: fs_entry(),
-              compression()
+              compression(),
+              streaming :: boolean()
             ) ::
               ok_result()
   @callback dump_ipc_stream(df, compression()) :: io_result(binary())
@@ -139,9 +197,10 @@ defmodule Explorer.Backend.DataFrame do
               infer_schema_length :: integer(),
               batch_size :: integer()
             ) :: io_result(df)
-  @callback to_ndjson(df, entry :: fs_entry()) :: ok_result()
+  @callback to_ndjson(df, entry :: fs_entry(), compression(), streaming :: boolean()) ::
+              ok_result()
 
-  @callback dump_ndjson(df) :: io_result(binary())
+  @callback dump_ndjson(df, compression()) :: io_result(binary())
 
   @callback load_ndjson(
               contents :: String.t(),
@@ -149,6 +208,50 @@ defmodule Explorer.Backend.DataFrame do
               batch_size :: integer()
             ) :: io_result(df)
 
+  # IO: Streams
+  @callback stream_csv(
+              entry :: fs_entry(),
+              io_dtypes,
+              delimiter :: String.t(),
+              nil_values :: list(String.t()),
+              skip_rows :: integer(),
+              skip_rows_after_header :: integer(),
+              header? :: boolean(),
+              encoding :: String.t(),
+              columns :: columns_for_io(),
+              infer_schema_length :: option(integer()),
+              parse_dates :: boolean(),
+              eol_delimiter :: option(String.t()),
+              dialect :: csv_dialect(),
+              chunk_size :: pos_integer()
+            ) :: Enumerable.t()
+  @callback stream_parquet(
+              entry :: fs_entry(),
+              columns :: columns_for_io(),
+              chunk_size :: pos_integer()
+            ) :: Enumerable.t()
+  @callback stream_ipc_stream(
+              entry :: fs_entry(),
+              columns :: columns_for_io(),
+              chunk_size :: pos_integer()
+            ) :: Enumerable.t()
+  @callback stream_ndjson(
+              entry :: fs_entry(),
+              infer_schema_length :: option(integer()),
+              chunk_size :: pos_integer()
+            ) :: Enumerable.t()
+  @callback write_batches(
+              dfs :: Enumerable.t(),
+              entry :: fs_entry(),
+              format ::
+                {:csv, header? :: boolean(), delimiter :: String.t(), csv_write_options(),
+                 compression()}
+                | {:parquet, compression()}
+                | {:ipc, compression()}
+                | {:ipc_stream, compression()}
+                | {:ndjson, compression()}
+            ) :: ok_result()
+
   # Conversion
 
   @callback lazy() :: module()
@@ -158,6 +261,8 @@ defmodule Explorer.Backend.DataFrame do
   @callback from_series([{binary(), Series.t()}]) :: df
   @callback to_rows(df, atom_keys? :: boolean()) :: [map()]
   @callback to_rows_stream(df, atom_keys? :: boolean(), chunk_size :: integer()) :: Enumerable.t()
+  @callback to_arrow_c_data(df) :: arrow_c_data()
+  @callback from_arrow_c_data(array :: non_neg_integer(), schema :: non_neg_integer()) :: df
 
   # Ownership
 
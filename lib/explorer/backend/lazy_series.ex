@@ -93,6 +93,7 @@ defmodule Explorer.Backend.LazySeries do
     slice: 3,
     sample_n: 5,
     sample_frac: 5,
+    draw: 4,
     head: 2,
     tail: 2,
     shift: 3,
@@ -403,6 +404,15 @@ defmodule Explorer.Backend.LazySeries do
     Backend.Series.new(data, s.dtype)
   end
 
+  @impl true
+  def draw(%Series{} = s, distribution, params, out_dtype, seed) do
+    params = Enum.map(params, &data!/1)
+    args = [lazy_series!(s), distribution, params, seed]
+    data = new(:draw, args, out_dtype, aggregations?(params))
+
+    Backend.Series.new(data, out_dtype)
+  end
+
   # Implements all the comparison operations that
   # accepts Series or number on both sides.
   #
@@ -1005,6 +1015,11 @@ defmodule Explorer.Backend.LazySeries do
     single
   end
 
+  defp to_elixir_ast(%__MODULE__{op: :draw, args: [series, distribution, params, seed]}) do
+    params = Enum.map(params, &to_elixir_ast/1)
+    {:draw, [], [to_elixir_ast(series), distribution, params, seed]}
+  end
+
   defp to_elixir_ast(%__MODULE__{op: op, args: args}) do
     {Map.get(@to_elixir_op, op, op), [], Enum.map(args, &to_elixir_ast/1)}
   end
@@ -1246,10 +1261,12 @@ defmodule Explorer.Backend.LazySeries do
     categorise: 2,
     cut: 7,
     frequencies: 1,
+    from_arrow_c_data: 2,
     qcut: 8,
     mask: 2,
     owner_import: 1,
     owner_export: 1,
+    to_arrow_c_data: 1,
     to_iovec: 1,
     to_list: 1
   ]
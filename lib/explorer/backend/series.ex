@@ -23,6 +23,7 @@ defmodule Explorer.Backend.Series do
   @type non_finite :: Explorer.Series.non_finite()
   @type option(type) :: type | nil
   @type io_result(t) :: {:ok, t} | {:error, Exception.t()}
+  @type arrow_c_data :: Explorer.Series.arrow_c_data()
 
   # Conversion
 
@@ -30,6 +31,8 @@ defmodule Explorer.Backend.Series do
   @callback from_binary(binary(), dtype()) :: s
   @callback to_list(s) :: list()
   @callback to_iovec(s) :: [binary()]
+  @callback to_arrow_c_data(s) :: arrow_c_data()
+  @callback from_arrow_c_data(array :: non_neg_integer(), schema :: non_neg_integer()) :: s
   @callback cast(s, dtype) :: s
   @callback categorise(s, s) :: s
   @callback strptime(s, String.t()) :: s
@@ -58,6 +61,13 @@ defmodule Explorer.Backend.Series do
               shuffle :: boolean(),
               seed :: option(integer())
             ) :: s
+  @callback draw(
+              s,
+              distribution :: atom(),
+              params :: [s | number()],
+              out_dtype :: dtype(),
+              seed :: integer()
+            ) :: s
   @callback at(s, idx :: integer()) :: s
   @callback at_every(s, integer()) :: s
   @callback mask(s, mask :: s) :: s
@@ -100,9 +100,12 @@ defmodule Explorer.DataFrame do
 
   The convention Explorer uses is to have `from_*` and `to_*` functions to read and write
   to files in the formats above. `load_*` and `dump_*` versions are also available to read
-  and write those formats directly in memory.
+  and write those formats directly in memory. Local CSV, Parquet, Arrow Streaming IPC and
+  NDJSON files can also be read in chunks of rows with the `stream_*` functions, and
+  a stream of dataframes can be written to a single file with `write_batches/4`.
 
-  Files can be fetched from local or remote file system, such as S3, using the following formats:
+  Files can be fetched from local or remote file system, such as S3, Google Cloud Storage
+  or Azure Blob Storage, using the following formats:
 
       # path to a file in disk
       Explorer.DataFrame.from_parquet("/path/to/file.parquet")
@@ -116,11 +119,26 @@ defmodule Explorer.DataFrame do
       # a FSS entry (it already includes its config)
       Explorer.DataFrame.from_parquet(FSS.S3.parse("s3://bucket/file.parquet"))
 
+      # Google Cloud Storage and Azure Blob Storage
+      Explorer.DataFrame.from_parquet("gs://bucket/file.parquet", config: [service_account_key: key])
+      Explorer.DataFrame.from_parquet("az://container/file.parquet", config: [account: "my-account", access_key: "my-key"])
+
+      # HTTP(S) URLs, with optional headers
+      Explorer.DataFrame.from_parquet("https://example.com/file.parquet", config: [headers: [{"authorization", "Bearer token"}]])
+
+  Parquet and IPC files are read with ranged requests, from HTTP servers and object
+  stores alike, so HTTP servers must support `HEAD` requests and the "Range" header.
+  For Parquet, only the footer and the chunks of the selected columns, in the row groups
+  needed for `:max_rows`, are fetched. Lazy reads of HTTP URLs, with or without headers,
+  are scanned by Polars, which also skips the row groups excluded by filters.
+
   The `:config` option of `from_*` functions is only required if the filename is a path
   to a remote resource. In case it's a FSS entry, the requirement is that the config is passed
   inside the entry struct.
 
-  For more details about the options, see the [FSS docs](https://hexdocs.pm/fss).
+  For more details about the options, see the [FSS docs](https://hexdocs.pm/fss),
+  `Explorer.FSS.GCS` and `Explorer.FSS.Azure`. There is also an in-memory store,
+  described in `Explorer.FSS.Memory`, which is useful for testing.
 
   ## Selecting columns and access
 
@@ -201,6 +219,9 @@ defmodule Explorer.DataFrame do
   alias Explorer.Shared
   alias Explorer.Backend.LazySeries
 
+  alias Explorer.FSS.Azure
+  alias Explorer.FSS.GCS
+  alias Explorer.FSS.Memory
   alias FSS.HTTP
   alias FSS.Local
   alias FSS.S3
@@ -254,9 +275,10 @@ defmodule Explorer.DataFrame do
   @type column_pairs(value) :: [{column(), value}] | %{column() => value}
 
   @typedoc """
-  Represents a filesystem entry, that can be local, S3 or URL.
+  Represents a filesystem entry, that can be local, S3, Google Cloud Storage,
+  Azure Blob Storage, in-memory or URL.
   """
-  @type fs_entry :: FSS.entry()
+  @type fs_entry :: FSS.entry() | GCS.Entry.t() | Azure.Entry.t() | Memory.Entry.t()
 
   @typedoc """
   Represents a dataframe.
@@ -348,6 +370,99 @@ defmodule Explorer.DataFrame do
             "got: #{inspect(other)}"
   end
 
+  defp csv_dialect(opts) do
+    opts
+    |> Keyword.take([
+      :quote_char,
+      :comment_prefix,
+      :decimal_comma,
+      :missing_is_null,
+      :truncate_ragged_lines
+    ])
+    |> Map.new()
+  end
+
+  @csv_write_defaults [
+    date_format: nil,
+    time_format: nil,
+    datetime_format: nil,
+    float_precision: nil,
+    nil_value: "",
+    line_terminator: "\n",
+    quote_style: :necessary
+  ]
+
+  defp csv_write_options(opts) do
+    quote_style = opts[:quote_style]
+
+    unless quote_style in [:necessary, :always, :non_numeric, :never] do
+      raise ArgumentError,
+            "expected :quote_style to be one of :necessary, :always, :non_numeric or :never, " <>
+              "got: #{inspect(quote_style)}"
+    end
+
+    %{
+      date_format: opts[:date_format],
+      time_format: opts[:time_format],
+      datetime_format: opts[:datetime_format],
+      float_precision: opts[:float_precision],
+      null_value: opts[:nil_value],
+      line_terminator: opts[:line_terminator],
+      quote_style: quote_style
+    }
+  end
+
+  @parquet_write_defaults [
+    row_group_size: nil,
+    data_page_size: nil,
+    statistics: [:min_max, :null_count]
+  ]
+
+  @parquet_statistics [:min_max, :null_count, :distinct_count]
+
+  defp parquet_write_options(opts) do
+    for key <- [:row_group_size, :data_page_size] do
+      value = opts[key]
+
+      unless is_nil(value) or (is_integer(value) and value > 0) do
+        raise ArgumentError,
+              "expected #{inspect(key)} to be a positive integer or nil, got: #{inspect(value)}"
+      end
+    end
+
+    statistics = opts[:statistics]
+
+    unless is_list(statistics) and Enum.all?(statistics, &(&1 in @parquet_statistics)) do
+      raise ArgumentError,
+            "expected :statistics to be a list with :min_max, :null_count or :distinct_count, " <>
+              "got: #{inspect(statistics)}"
+    end
+
+    %{
+      row_group_size: opts[:row_group_size],
+      data_page_size: opts[:data_page_size],
+      statistics: Enum.uniq(statistics)
+    }
+  end
+
+  defp text_compression(nil, _format), do: {nil, nil}
+  defp text_compression(algorithm, _format) when algorithm in ~w(gzip zstd)a, do: {algorithm, nil}
+
+  for {algorithm, min, max} <- [{:gzip, 1, 9}, {:zstd, -7, 22}] do
+    defp text_compression({unquote(algorithm), level}, _format) do
+      if level in unquote(min)..unquote(max) or is_nil(level) do
+        {unquote(algorithm), level}
+      else
+        raise ArgumentError,
+              "#{unquote(algorithm)} compression level must be between #{unquote(min)} and #{unquote(max)} inclusive or nil, got #{level}"
+      end
+    end
+  end
+
+  defp text_compression(other, format) do
+    raise ArgumentError, "unsupported :compression #{inspect(other)} for #{format}"
+  end
+
   defp check_dtypes!(dtypes) do
     Enum.map(dtypes, fn
       {key, value} when is_atom(key) ->
@@ -561,6 +676,23 @@ defmodule Explorer.DataFrame do
 
     * `:eol_delimiter` - A single character used to represent new lines. (default: `"\n"`)
 
+    * `:quote_char` - A single character used for quoting fields, or `nil` to disable
+      quoting altogether. (default: `"\""`)
+
+    * `:comment_prefix` - Lines starting with this string are skipped. (default: `nil`)
+
+    * `:decimal_comma` - Parse floats with a comma as the decimal separator, as in `"3,14"`.
+      The delimiter must then be something other than a comma. (default: `false`)
+
+    * `:missing_is_null` - Treat missing fields as `nil`. If `false`, they are read as
+      empty strings. (default: `true`)
+
+    * `:truncate_ragged_lines` - Truncate lines that have more fields than the header.
+      If `false`, reading such lines returns an error. (default: `false`)
+
+    * `:include_file_paths` - The name of a column to add with the path of the file
+      each row was read from. Only supported by lazy dataframes. (default: `nil`)
+
     * `:config` - An optional struct, keyword list or map, normally associated with remote
       file systems. See [IO section](#module-io-operations) for more details. (default: `nil`)
 
@@ -573,6 +705,11 @@ defmodule Explorer.DataFrame do
     * `:encoding` - Encoding to use when reading the file. For now, the only possible values are `utf8` and `utf8-lossy`.
       The utf8-lossy option means that invalid utf8 values are replaced with � characters. (default: `"utf8"`)
 
+  Unlike `from_parquet/2` and `from_ipc/2`, the `:hive_partitioning` option is
+  not supported, since the CSV reader can't read the partitions from the paths.
+  Glob patterns are supported by lazy dataframes, and `:include_file_paths` can
+  be used to tell the files apart.
+
   """
   @doc type: :io
   @spec from_csv(filename :: String.t() | fs_entry(), opts :: Keyword.t()) ::
@@ -580,6 +717,12 @@ defmodule Explorer.DataFrame do
   def from_csv(filename, opts \\ []) do
     {backend_opts, opts} = Keyword.split(opts, [:backend, :lazy, :node])
 
+    if Keyword.has_key?(opts, :hive_partitioning) do
+      raise ArgumentError,
+            "the :hive_partitioning option is not supported when reading CSV files, " <>
+              "use :include_file_paths to add the path of the file of each row instead"
+    end
+
     opts =
       Keyword.validate!(opts,
         config: nil,
@@ -594,7 +737,13 @@ defmodule Explorer.DataFrame do
         columns: nil,
         infer_schema_length: @default_infer_schema_length,
         parse_dates: false,
-        eol_delimiter: nil
+        eol_delimiter: nil,
+        quote_char: "\"",
+        comment_prefix: nil,
+        decimal_comma: false,
+        missing_is_null: true,
+        truncate_ragged_lines: false,
+        include_file_paths: nil
       )
 
     backend = backend_from_options!(backend_opts)
@@ -613,7 +762,9 @@ defmodule Explorer.DataFrame do
         to_columns_for_io(opts[:columns]),
         opts[:infer_schema_length],
         opts[:parse_dates],
-        opts[:eol_delimiter]
+        opts[:eol_delimiter],
+        csv_dialect(opts),
+        opts[:include_file_paths]
       ]
 
       Shared.apply_init(backend, :from_csv, args, backend_opts)
@@ -638,6 +789,83 @@ defmodule Explorer.DataFrame do
     end
   end
 
+  @doc """
+  Streams a delimited file as dataframes of up to `:chunk_size` rows.
+
+  The file is read as the stream is consumed, so files larger than
+  the available memory can be processed one chunk at a time. All the
+  chunks have the same columns and dtypes, which are inferred from the
+  beginning of the file, as in `from_csv/2`.
+
+  Only uncompressed local files are supported, and reading a compressed
+  file raises. Use `from_csv/2` for those. Errors are raised while the
+  stream is consumed.
+
+  ## Options
+
+    * `:chunk_size` - The number of rows of each dataframe. The last
+      dataframe may have fewer rows. (default: `1000`)
+
+    * `:backend` - The Explorer backend to use. Defaults to the value returned by `Explorer.Backend.get/0`.
+
+  It also accepts the `:delimiter`, `:dtypes`, `:header`, `:nil_values`,
+  `:skip_rows`, `:skip_rows_after_header`, `:columns`, `:infer_schema_length`,
+  `:parse_dates`, `:eol_delimiter`, `:encoding` and dialect options of `from_csv/2`.
+
+  ## Examples
+
+      "sales.csv"
+      |> Explorer.DataFrame.stream_csv(chunk_size: 100_000)
+      |> Stream.map(&Explorer.DataFrame.n_rows/1)
+      |> Enum.sum()
+
+  """
+  @doc type: :io
+  @spec stream_csv(filename :: String.t() | fs_entry(), opts :: Keyword.t()) :: Enumerable.t()
+  def stream_csv(filename, opts \\ []) do
+    {backend_opts, opts} = Keyword.split(opts, [:backend])
+
+    opts =
+      Keyword.validate!(opts,
+        chunk_size: 1000,
+        delimiter: ",",
+        dtypes: [],
+        encoding: "utf8",
+        header: true,
+        nil_values: [],
+        skip_rows: 0,
+        skip_rows_after_header: 0,
+        columns: nil,
+        infer_schema_length: @default_infer_schema_length,
+        parse_dates: false,
+        eol_delimiter: nil,
+        quote_char: "\"",
+        comment_prefix: nil,
+        decimal_comma: false,
+        missing_is_null: true,
+        truncate_ragged_lines: false
+      )
+
+    backend = backend_from_options!(backend_opts)
+
+    backend.stream_csv(
+      stream_entry!(filename, :stream_csv),
+      check_dtypes!(opts[:dtypes]),
+      opts[:delimiter],
+      opts[:nil_values],
+      opts[:skip_rows],
+      opts[:skip_rows_after_header],
+      opts[:header],
+      opts[:encoding],
+      to_columns_for_io(opts[:columns]),
+      opts[:infer_schema_length],
+      opts[:parse_dates],
+      opts[:eol_delimiter],
+      csv_dialect(opts),
+      opts[:chunk_size]
+    )
+  end
+
   @doc """
   Writes a dataframe to a delimited file.
 
@@ -652,26 +880,63 @@ defmodule Explorer.DataFrame do
 
     * `:delimiter` - A single character used to separate fields within a record. (default: `","`)
 
+    * `:date_format` - A format string, such as `"%Y-%m-%d"`, for writing dates.
+      Uses the Polars default when `nil`. (default: `nil`)
+
+    * `:time_format` - A format string, such as `"%H:%M:%S"`, for writing times. (default: `nil`)
+
+    * `:datetime_format` - A format string, such as `"%Y-%m-%dT%H:%M:%S%.f"`,
+      for writing datetimes. (default: `nil`)
+
+    * `:float_precision` - The number of decimal places to write floats with.
+      When `nil`, floats are written with as many places as needed. (default: `nil`)
+
+    * `:nil_value` - The string written in place of `nil` values. (default: `""`)
+
+    * `:line_terminator` - The string written after every row. (default: `"\n"`)
+
+    * `:quote_style` - When to quote fields. One of `:necessary`, which quotes fields
+      that contain a quote, the delimiter or a line terminator, `:always`, `:non_numeric`,
+      which quotes all fields that are not numbers, or `:never`. (default: `:necessary`)
+
+    * `:compression` - The compression algorithm to use when writing files.
+      Where a compression level is available, this can be passed as a tuple,
+      such as `{:zstd, 3}`. Supported options are:
+
+        * `nil` (uncompressed, default)
+        * `:gzip` (with levels 1-9)
+        * `:zstd` (with levels -7-22)
+
     * `:config` - An optional struct, keyword list or map, normally associated with remote
       file systems. See [IO section](#module-io-operations) for more details. (default: `nil`)
 
     * `:streaming` - Tells the backend if it should use streaming, which means
       that the dataframe is not loaded to the memory at once, and instead it is
       written in chunks from a lazy dataframe.  Defaults to true for local filesystems,
-      ignored on all others.
+      ignored on all others. A compressed file is first written, uncompressed, to a
+      temporary file in the system's temporary directory.
 
   """
   @doc type: :io
   @spec to_csv(df :: DataFrame.t(), filename :: fs_entry() | String.t(), opts :: Keyword.t()) ::
           :ok | {:error, Exception.t()}
   def to_csv(df, filename, opts \\ []) do
-    opts = Keyword.validate!(opts, header: true, delimiter: ",", streaming: true, config: nil)
+    opts =
+      Keyword.validate!(
+        opts,
+        [header: true, delimiter: ",", compression: nil, streaming: true, config: nil] ++
+          @csv_write_defaults
+      )
+
+    compression = text_compression(opts[:compression], "CSV")
 
     with {:ok, entry} <- normalise_entry(filename, opts[:config]) do
       Shared.apply_dataframe(df, :to_csv, [
         entry,
         opts[:header],
         opts[:delimiter],
+        csv_write_options(opts),
+        compression,
         opts[:streaming]
       ])
     end
@@ -704,6 +969,9 @@ defmodule Explorer.DataFrame do
     * `:header` - Should the column names be written as the first line of the file? (default: `true`)
     * `:delimiter` - A single character used to separate fields within a record. (default: `","`)
 
+  It also accepts the formatting options of `to_csv/3`, such as `:date_format`,
+  `:float_precision` and `:quote_style`, as well as its `:compression` option.
+
   ## Examples
 
       iex> df = Explorer.Datasets.fossil_fuels() |> Explorer.DataFrame.head(2)
@@ -714,8 +982,20 @@ defmodule Explorer.DataFrame do
   @spec dump_csv(df :: DataFrame.t(), opts :: Keyword.t()) ::
           {:ok, String.t()} | {:error, Exception.t()}
   def dump_csv(df, opts \\ []) do
-    opts = Keyword.validate!(opts, header: true, delimiter: ",")
-    Shared.apply_dataframe(df, :dump_csv, [opts[:header], opts[:delimiter]], false)
+    opts =
+      Keyword.validate!(
+        opts,
+        [header: true, delimiter: ",", compression: nil] ++ @csv_write_defaults
+      )
+
+    compression = text_compression(opts[:compression], "CSV")
+
+    Shared.apply_dataframe(
+      df,
+      :dump_csv,
+      [opts[:header], opts[:delimiter], csv_write_options(opts), compression],
+      false
+    )
   end
 
   @doc """
@@ -756,7 +1036,12 @@ defmodule Explorer.DataFrame do
         columns: nil,
         infer_schema_length: @default_infer_schema_length,
         parse_dates: false,
-        eol_delimiter: nil
+        eol_delimiter: nil,
+        quote_char: "\"",
+        comment_prefix: nil,
+        decimal_comma: false,
+        missing_is_null: true,
+        truncate_ragged_lines: false
       )
 
     backend = backend_from_options!(backend_opts)
@@ -774,7 +1059,8 @@ defmodule Explorer.DataFrame do
       to_columns_for_io(opts[:columns]),
       opts[:infer_schema_length],
       opts[:parse_dates],
-      opts[:eol_delimiter]
+      opts[:eol_delimiter],
+      csv_dialect(opts)
     ]
 
     Shared.apply_init(backend, :load_csv, args, backend_opts)
@@ -796,7 +1082,8 @@ defmodule Explorer.DataFrame do
   Reads a parquet file into a dataframe.
 
   It accepts a filename that can be a local file, a "s3://" schema, or
-  a `FSS` entry like `FSS.S3.Entry`.
+  a `FSS` entry like `FSS.S3.Entry`. Lazy dataframes also accept a directory
+  or a glob pattern, like `"data/**/*.parquet"`, to read many files at once.
 
   ## Options
 
@@ -808,6 +1095,14 @@ defmodule Explorer.DataFrame do
     * `:rechunk` - Make sure that all columns are contiguous in memory
       by aggregating the chunks into a single array. (default: `false`)
 
+    * `:hive_partitioning` - Read the `key=value` segments of the file paths,
+      as in `year=2024/part-0.parquet`, into columns. Filters on these columns skip
+      the files that can't match. When `nil`, it is only enabled when reading a
+      directory. Only supported by lazy dataframes. (default: `nil`)
+
+    * `:include_file_paths` - The name of a column to add with the path of the file
+      each row was read from. Only supported by lazy dataframes. (default: `nil`)
+
     * `:config` - An optional struct, keyword list or map, normally associated with remote
       file systems. See [IO section](#module-io-operations) for more details. (default: `nil`)
 
@@ -828,7 +1123,9 @@ defmodule Explorer.DataFrame do
         max_rows: nil,
         columns: nil,
         config: nil,
-        rechunk: false
+        rechunk: false,
+        hive_partitioning: nil,
+        include_file_paths: nil
       )
 
     backend = backend_from_options!(backend_opts)
@@ -838,7 +1135,9 @@ defmodule Explorer.DataFrame do
         entry,
         opts[:max_rows],
         to_columns_for_io(opts[:columns]),
-        opts[:rechunk]
+        opts[:rechunk],
+        opts[:hive_partitioning],
+        opts[:include_file_paths]
       ]
 
       Shared.apply_init(backend, :from_parquet, args, backend_opts)
@@ -855,11 +1154,22 @@ defmodule Explorer.DataFrame do
   defp normalise_entry(%Local.Entry{} = entry, nil), do: {:ok, entry}
   defp normalise_entry(%HTTP.Entry{} = entry, nil), do: {:ok, entry}
   defp normalise_entry(%S3.Entry{config: %S3.Config{}} = entry, nil), do: {:ok, entry}
+  defp normalise_entry(%GCS.Entry{config: %GCS.Config{}} = entry, nil), do: {:ok, entry}
+  defp normalise_entry(%Azure.Entry{config: %Azure.Config{}} = entry, nil), do: {:ok, entry}
+  defp normalise_entry(%Memory.Entry{} = entry, nil), do: {:ok, entry}
 
   defp normalise_entry("s3://" <> _rest = entry, config) do
     S3.parse(entry, config: config)
   end
 
+  defp normalise_entry("gs://" <> _rest = entry, config) do
+    GCS.parse(entry, config: config)
+  end
+
+  defp normalise_entry("az://" <> _rest = entry, config) do
+    Azure.parse(entry, config: config)
+  end
+
   defp normalise_entry("file://" <> path, _config), do: {:ok, Local.from_path(path)}
 
   defp normalise_entry("http://" <> _rest = url, config) do
@@ -892,6 +1202,63 @@ defmodule Explorer.DataFrame do
     end
   end
 
+  @doc """
+  Streams a parquet file as dataframes of up to `:chunk_size` rows.
+
+  The file is read as the stream is consumed, and each dataframe
+  only reads the row groups that hold its rows, so files larger
+  than the available memory can be processed one chunk at a time.
+
+  Only local files are supported. Errors are raised while the
+  stream is consumed.
+
+  ## Options
+
+    * `:chunk_size` - The number of rows of each dataframe. The last
+      dataframe may have fewer rows. (default: `1000`)
+
+    * `:columns` - A list of column names or indexes to keep. If present,
+      only these columns are read into the dataframes. (default: `nil`)
+
+    * `:backend` - The Explorer backend to use. Defaults to the value returned by `Explorer.Backend.get/0`.
+
+  ## Examples
+
+      "sales.parquet"
+      |> Explorer.DataFrame.stream_parquet(chunk_size: 100_000, columns: ["amount"])
+      |> Stream.map(&Explorer.Series.sum(&1["amount"]))
+      |> Enum.sum()
+
+  """
+  @doc type: :io
+  @spec stream_parquet(filename :: String.t() | fs_entry(), opts :: Keyword.t()) ::
+          Enumerable.t()
+  def stream_parquet(filename, opts \\ []) do
+    {backend_opts, opts} = Keyword.split(opts, [:backend])
+    opts = Keyword.validate!(opts, chunk_size: 1000, columns: nil)
+    backend = backend_from_options!(backend_opts)
+
+    backend.stream_parquet(
+      stream_entry!(filename, :stream_parquet),
+      to_columns_for_io(opts[:columns]),
+      opts[:chunk_size]
+    )
+  end
+
+  # The streams read local files only.
+  defp stream_entry!(filename, fun) do
+    case normalise_entry(filename, nil) do
+      {:ok, %Local.Entry{} = entry} ->
+        entry
+
+      {:ok, entry} ->
+        raise ArgumentError, "#{fun} only reads local files, got: #{inspect(entry)}"
+
+      {:error, error} ->
+        raise error
+    end
+  end
+
   @doc """
   Writes a dataframe to a parquet file.
 
@@ -910,6 +1277,16 @@ defmodule Explorer.DataFrame do
         * `:zstd` (with levels -7-22)
         * `:lz4raw`.
 
+    * `:row_group_size` - The maximum number of rows in each row group.
+      If `nil`, the size is chosen by the backend. (default: `nil`)
+
+    * `:data_page_size` - The maximum size of each data page, in bytes.
+      If `nil`, the size is chosen by the backend. (default: `nil`)
+
+    * `:statistics` - The statistics written for each column chunk, which readers
+      use to skip row groups when filtering. A list with `:min_max`, `:null_count`
+      and `:distinct_count`. (default: `[:min_max, :null_count]`)
+
     * `:streaming` - Tells the backend if it should use streaming, which means
       that the dataframe is not loaded to the memory at once, and instead it is
       written in chunks from a lazy dataframe.
@@ -920,16 +1297,59 @@ defmodule Explorer.DataFrame do
     * `:config` - An optional struct, keyword list or map, normally associated with remote
       file systems. See [IO section](#module-io-operations) for more details. (default: `nil`)
 
+    * `:partition_by` - A list of columns to partition the dataframe by. When given,
+      `filename` is the root directory of a Hive-style layout, with one file per
+      combination of the values of the columns, such as `year=2024/month=3/part-0.parquet`.
+      The partition columns are not written to the files. Nil values are written to
+      the `__HIVE_DEFAULT_PARTITION__` directory. (default: `nil`)
+
   """
   @doc type: :io
   @spec to_parquet(df :: DataFrame.t(), filename :: String.t() | fs_entry(), opts :: Keyword.t()) ::
           :ok | {:error, Exception.t()}
   def to_parquet(%DataFrame{} = df, filename, opts \\ []) do
-    opts = Keyword.validate!(opts, compression: nil, streaming: true, config: nil)
+    opts =
+      Keyword.validate!(
+        opts,
+        [compression: nil, streaming: true, config: nil, partition_by: nil] ++
+          @parquet_write_defaults
+      )
+
     compression = parquet_compression(opts[:compression])
+    options = parquet_write_options(opts)
 
     with {:ok, entry} <- normalise_entry(filename, opts[:config]) do
-      Shared.apply_dataframe(df, :to_parquet, [entry, compression, opts[:streaming]])
+      case opts[:partition_by] do
+        nil ->
+          Shared.apply_dataframe(df, :to_parquet, [
+            entry,
+            compression,
+            options,
+            opts[:streaming]
+          ])
+
+        partition_by ->
+          with {:ok, partition_by} <- partition_columns(df, entry, partition_by) do
+            Shared.apply_dataframe(df, :to_parquet_partitioned, [
+              entry,
+              partition_by,
+              compression,
+              options
+            ])
+          end
+      end
+    end
+  end
+
+  # Partitioned writes need a directory (or a prefix in an object store).
+  defp partition_columns(_df, %HTTP.Entry{}, _partition_by) do
+    {:error, ArgumentError.exception("partitioned writes are not supported over HTTP")}
+  end
+
+  defp partition_columns(df, _entry, partition_by) do
+    case to_existing_columns(df, List.wrap(partition_by)) do
+      [] -> {:error, ArgumentError.exception(":partition_by must have at least one column")}
+      columns -> {:ok, columns}
     end
   end
 
@@ -990,15 +1410,26 @@ defmodule Explorer.DataFrame do
         * `:zstd` (with levels -7-22)
         * `:lz4raw`.
 
+    * `:row_group_size` - The maximum number of rows in each row group.
+      If `nil`, the size is chosen by the backend. (default: `nil`)
+
+    * `:data_page_size` - The maximum size of each data page, in bytes.
+      If `nil`, the size is chosen by the backend. (default: `nil`)
+
+    * `:statistics` - The statistics written for each column chunk, which readers
+      use to skip row groups when filtering. A list with `:min_max`, `:null_count`
+      and `:distinct_count`. (default: `[:min_max, :null_count]`)
+
   """
   @doc type: :io
   @spec dump_parquet(df :: DataFrame.t(), opts :: Keyword.t()) ::
           {:ok, binary()} | {:error, Exception.t()}
   def dump_parquet(df, opts \\ []) do
-    opts = Keyword.validate!(opts, compression: nil)
+    opts = Keyword.validate!(opts, [compression: nil] ++ @parquet_write_defaults)
     compression = parquet_compression(opts[:compression])
+    options = parquet_write_options(opts)
 
-    Shared.apply_dataframe(df, :dump_parquet, [compression], false)
+    Shared.apply_dataframe(df, :dump_parquet, [compression, options], false)
   end
 
   @doc """
@@ -1040,16 +1471,127 @@ defmodule Explorer.DataFrame do
   end
 
   @doc """
-  Reads an IPC file into a dataframe.
+  Reads the metadata of a Parquet file into a dataframe, without reading its data.
+
+  Only the footer of the file is read, which for remote file systems means
+  only the end of the object is downloaded. This makes it cheap to check the
+  files of a data lake before reading them.
 
   It accepts a filename that can be a local file, a "s3://" schema, or
   a `FSS` entry like `FSS.S3.Entry`.
 
+  The dataframe has a row per column chunk, that is, per column of each
+  row group, with these columns:
+
+    * `"num_rows"` and `"created_by"` - The number of rows of the file and the
+      application that wrote it. These are the same for all rows.
+
+    * `"row_group"`, `"row_group_num_rows"` and `"row_group_byte_size"` - The
+      index, number of rows and size in bytes of the row group.
+
+    * `"column"`, `"physical_type"` and `"logical_type"` - The path of the column
+      in the schema and its types in the Parquet file.
+
+    * `"compression"`, `"encodings"`, `"num_values"`, `"compressed_size"` and
+      `"uncompressed_size"` - How the column chunk is stored.
+
+    * `"null_count"`, `"distinct_count"`, `"min"` and `"max"` - The statistics of
+      the column chunk, if written. The min and max are strings of the physical
+      values. For example, the number of days since the epoch for a date column.
+
+  A file without row groups has a row per column of its schema, with
+  `nil` in the row group and column chunk columns.
+
+  ## Options
+
+    * `:config` - An optional struct, keyword list or map, normally associated with remote
+      file systems. See [IO section](#module-io-operations) for more details. (default: `nil`)
+
+    * `:backend` - The Explorer backend to use. Defaults to the value returned by `Explorer.Backend.get/0`.
+
+    * `:lazy` - force the results into the lazy version of the current backend.
+
+    * `:node` - The Erlang node to allocate the data frame on.
+  """
+  @doc type: :io
+  @spec parquet_metadata(filename :: String.t() | fs_entry(), opts :: Keyword.t()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  def parquet_metadata(filename, opts \\ []) do
+    {backend_opts, opts} = Keyword.split(opts, [:backend, :lazy, :node])
+    opts = Keyword.validate!(opts, config: nil)
+    backend = backend_from_options!(backend_opts)
+
+    with {:ok, entry} <- normalise_entry(filename, opts[:config]) do
+      Shared.apply_init(backend, :parquet_metadata, [entry], backend_opts)
+    end
+  end
+
+  @doc """
+  Similar to `parquet_metadata/2` but raises if there is a problem reading the metadata.
+  """
+  @doc type: :io
+  @spec parquet_metadata!(filename :: String.t() | fs_entry(), opts :: Keyword.t()) ::
+          DataFrame.t()
+  def parquet_metadata!(filename, opts \\ []) do
+    case parquet_metadata(filename, opts) do
+      {:ok, df} ->
+        df
+
+      {:error, %module{} = e} when module in [ArgumentError, RuntimeError] ->
+        raise module, "parquet_metadata failed: #{e.message}"
+
+      {:error, error} ->
+        raise "parquet_metadata failed: #{inspect(error)}"
+    end
+  end
+
+  @doc """
+  Reads the metadata of a binary representation of a Parquet file into a dataframe.
+
+  See `parquet_metadata/2` for the columns of the dataframe.
+  It accepts the `:backend`, `:lazy` and `:node` options of `parquet_metadata/2`.
+  """
+  @doc type: :io
+  @spec load_parquet_metadata(contents :: binary(), opts :: Keyword.t()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  def load_parquet_metadata(contents, opts \\ []) do
+    opts = Keyword.validate!(opts, [:backend, :lazy, :node])
+    backend = backend_from_options!(opts)
+    Shared.apply_init(backend, :load_parquet_metadata, [contents], opts)
+  end
+
+  @doc """
+  Similar to `load_parquet_metadata/2` but raises if there is a problem reading the metadata.
+  """
+  @doc type: :io
+  @spec load_parquet_metadata!(contents :: binary(), opts :: Keyword.t()) :: DataFrame.t()
+  def load_parquet_metadata!(contents, opts \\ []) do
+    case load_parquet_metadata(contents, opts) do
+      {:ok, df} -> df
+      {:error, error} -> raise "load_parquet_metadata failed: #{inspect(error)}"
+    end
+  end
+
+  @doc """
+  Reads an IPC file into a dataframe.
+
+  It accepts a filename that can be a local file, a "s3://" schema, or
+  a `FSS` entry like `FSS.S3.Entry`. Lazy dataframes also accept a directory
+  or a glob pattern, like `"data/**/*.ipc"`, to read many files at once.
+
   ## Options
 
     * `:columns` - List with the name or index of columns to be selected.
       Defaults to all columns.
 
+    * `:hive_partitioning` - Read the `key=value` segments of the file paths,
+      as in `year=2024/part-0.ipc`, into columns. Filters on these columns skip
+      the files that can't match. When `nil`, it is only enabled when reading a
+      directory. Only supported by lazy dataframes. (default: `nil`)
+
+    * `:include_file_paths` - The name of a column to add with the path of the file
+      each row was read from. Only supported by lazy dataframes. (default: `nil`)
+
     * `:config` - An optional struct, keyword list or map, normally associated with remote
       file systems. See [IO section](#module-io-operations) for more details. (default: `nil`)
 
@@ -1068,18 +1610,22 @@ defmodule Explorer.DataFrame do
     opts =
       Keyword.validate!(opts,
         columns: nil,
-        config: nil
+        config: nil,
+        hive_partitioning: nil,
+        include_file_paths: nil
       )
 
     backend = backend_from_options!(backend_opts)
 
     with {:ok, entry} <- normalise_entry(filename, opts[:config]) do
-      Shared.apply_init(
-        backend,
-        :from_ipc,
-        [entry, to_columns_for_io(opts[:columns])],
-        backend_opts
-      )
+      args = [
+        entry,
+        to_columns_for_io(opts[:columns]),
+        opts[:hive_partitioning],
+        opts[:include_file_paths]
+      ]
+
+      Shared.apply_init(backend, :from_ipc, args, backend_opts)
     end
   end
 
@@ -1128,16 +1674,30 @@ defmodule Explorer.DataFrame do
     * `:config` - An optional struct, keyword list or map, normally associated with remote
       file systems. See [IO section](#module-io-operations) for more details. (default: `nil`)
 
+    * `:partition_by` - A list of columns to partition the dataframe by, writing
+      a Hive-style layout of IPC files under `filename`. See `to_parquet/3`.
+      (default: `nil`)
+
   """
   @doc type: :io
   @spec to_ipc(df :: DataFrame.t(), filename :: String.t() | fs_entry(), opts :: Keyword.t()) ::
           :ok | {:error, Exception.t()}
   def to_ipc(df, filename, opts \\ []) do
-    opts = Keyword.validate!(opts, compression: nil, streaming: true, config: nil)
+    opts =
+      Keyword.validate!(opts, compression: nil, streaming: true, config: nil, partition_by: nil)
+
     compression = ipc_compression(opts[:compression])
 
     with {:ok, entry} <- normalise_entry(filename, opts[:config]) do
-      Shared.apply_dataframe(df, :to_ipc, [entry, compression, opts[:streaming]])
+      case opts[:partition_by] do
+        nil ->
+          Shared.apply_dataframe(df, :to_ipc, [entry, compression, opts[:streaming]])
+
+        partition_by ->
+          with {:ok, partition_by} <- partition_columns(df, entry, partition_by) do
+            Shared.apply_dataframe(df, :to_ipc_partitioned, [entry, partition_by, compression])
+          end
+      end
     end
   end
 
@@ -1301,6 +1861,42 @@ defmodule Explorer.DataFrame do
     end
   end
 
+  @doc """
+  Streams an IPC Streaming file as dataframes of up to `:chunk_size` rows.
+
+  The record batches of the file are read as the stream is consumed,
+  so files larger than the available memory can be processed one chunk
+  at a time.
+
+  Only local files are supported. Errors are raised while the
+  stream is consumed.
+
+  ## Options
+
+    * `:chunk_size` - The number of rows of each dataframe. The last
+      dataframe may have fewer rows. (default: `1000`)
+
+    * `:columns` - List with the name or index of columns to be selected.
+      Defaults to all columns.
+
+    * `:backend` - The Explorer backend to use. Defaults to the value returned by `Explorer.Backend.get/0`.
+
+  """
+  @doc type: :io
+  @spec stream_ipc_stream(filename :: String.t() | fs_entry(), opts :: Keyword.t()) ::
+          Enumerable.t()
+  def stream_ipc_stream(filename, opts \\ []) do
+    {backend_opts, opts} = Keyword.split(opts, [:backend])
+    opts = Keyword.validate!(opts, chunk_size: 1000, columns: nil)
+    backend = backend_from_options!(backend_opts)
+
+    backend.stream_ipc_stream(
+      stream_entry!(filename, :stream_ipc_stream),
+      to_columns_for_io(opts[:columns]),
+      opts[:chunk_size]
+    )
+  end
+
   @doc """
   Writes a dataframe to an IPC Stream file.
 
@@ -1317,6 +1913,14 @@ defmodule Explorer.DataFrame do
         * `:zstd`
         * `:lz4`.
 
+    * `:streaming` - Tells the backend if it should use streaming, which means
+      that the dataframe is not loaded to the memory at once, and instead it is
+      written in chunks from a lazy dataframe.
+
+      This option has no effect on eager - the default - dataframes.
+      It defaults to `true`. The result is first written, uncompressed, to
+      a temporary file in the system's temporary directory.
+
     * `:config` - An optional struct, keyword list or map, normally associated with remote
       file systems. See [IO section](#module-io-operations) for more details. (default: `nil`)
 
@@ -1325,11 +1929,11 @@ defmodule Explorer.DataFrame do
   @spec to_ipc_stream(df :: DataFrame.t(), filename :: String.t() | fs_entry()) ::
           :ok | {:error, Exception.t()}
   def to_ipc_stream(df, filename, opts \\ []) do
-    opts = Keyword.validate!(opts, compression: nil, config: nil)
+    opts = Keyword.validate!(opts, compression: nil, streaming: true, config: nil)
     compression = ipc_compression(opts[:compression])
 
     with {:ok, entry} <- normalise_entry(filename, opts[:config]) do
-      Shared.apply_dataframe(df, :to_ipc_stream, [entry, compression])
+      Shared.apply_dataframe(df, :to_ipc_stream, [entry, compression, opts[:streaming]])
     end
   end
 
@@ -1429,6 +2033,8 @@ defmodule Explorer.DataFrame do
   @doc """
   Read a file of JSON objects or lists separated by new lines
 
+  If the file is compressed with gzip or zstd, it is automatically decompressed.
+
   ## Options
 
     * `:batch_size` - Sets the batch size for reading rows.
@@ -1488,6 +2094,53 @@ defmodule Explorer.DataFrame do
     end
   end
 
+  @doc """
+  Streams a file of JSON objects separated by new lines as dataframes
+  of up to `:chunk_size` rows.
+
+  The file is read as the stream is consumed, so files larger than
+  the available memory can be processed one chunk at a time. The
+  dtypes are inferred from the first `:infer_schema_length` rows of
+  the file before any chunk is read, and all the chunks are read
+  with them. Reading a key that only appears after these rows raises,
+  so that no data is silently dropped.
+
+  Only local files are supported. Files compressed with gzip, zlib or
+  zstd are decompressed as they are read. Errors are raised while the
+  stream is consumed.
+
+  ## Options
+
+    * `:chunk_size` - The number of rows of each dataframe. The last
+      dataframe may have fewer rows. (default: `1000`)
+
+    * `:infer_schema_length` - Maximum number of rows read for schema inference.
+      Setting this to nil will scan the whole file before reading it (default: `1000`).
+
+    * `:backend` - The Explorer backend to use. Defaults to the value returned by `Explorer.Backend.get/0`.
+
+  """
+  @doc type: :io
+  @spec stream_ndjson(filename :: String.t() | fs_entry(), opts :: Keyword.t()) ::
+          Enumerable.t()
+  def stream_ndjson(filename, opts \\ []) do
+    {backend_opts, opts} = Keyword.split(opts, [:backend])
+
+    opts =
+      Keyword.validate!(opts,
+        chunk_size: 1000,
+        infer_schema_length: @default_infer_schema_length
+      )
+
+    backend = backend_from_options!(backend_opts)
+
+    backend.stream_ndjson(
+      stream_entry!(filename, :stream_ndjson),
+      opts[:infer_schema_length],
+      opts[:chunk_size]
+    )
+  end
+
   @doc """
   Writes a dataframe to a ndjson file.
 
@@ -1498,6 +2151,23 @@ defmodule Explorer.DataFrame do
 
   ## Options
 
+    * `:compression` - The compression algorithm to use when writing files.
+      Where a compression level is available, this can be passed as a tuple,
+      such as `{:zstd, 3}`. Supported options are:
+
+        * `nil` (uncompressed, default)
+        * `:gzip` (with levels 1-9)
+        * `:zstd` (with levels -7-22)
+
+    * `:streaming` - Tells the backend if it should use streaming, which means
+      that the dataframe is not loaded to the memory at once, and instead it is
+      written in chunks from a lazy dataframe.
+
+      This option has no effect on eager - the default - dataframes.
+      It defaults to `true`. A compressed file, or a file written to the
+      cloud, is first written, uncompressed, to a temporary file in the
+      system's temporary directory.
+
     * `:config` - An optional struct, keyword list or map, normally associated with remote
       file systems. See [IO section](#module-io-operations) for more details. (default: `nil`)
 
@@ -1506,10 +2176,11 @@ defmodule Explorer.DataFrame do
   @spec to_ndjson(df :: DataFrame.t(), filename :: String.t() | fs_entry(), opts :: Keyword.t()) ::
           :ok | {:error, Exception.t()}
   def to_ndjson(df, filename, opts \\ []) do
-    opts = Keyword.validate!(opts, config: nil)
+    opts = Keyword.validate!(opts, compression: nil, streaming: true, config: nil)
+    compression = text_compression(opts[:compression], "NDJSON")
 
     with {:ok, entry} <- normalise_entry(filename, opts[:config]) do
-      Shared.apply_dataframe(df, :to_ndjson, [entry])
+      Shared.apply_dataframe(df, :to_ndjson, [entry, compression, opts[:streaming]])
     end
   end
 
@@ -1532,11 +2203,134 @@ defmodule Explorer.DataFrame do
     end
   end
 
+  @doc """
+  Writes a stream of dataframes to a single file.
+
+  The dataframes are written one at a time, as the enumerable is
+  consumed, so the whole data is never loaded to the memory at once.
+  This makes it possible to write the stream returned by `stream_csv/2`
+  and friends to another format, for example. All the dataframes must
+  have the same columns and dtypes. Lazy dataframes are computed before
+  they are written.
+
+  The `format` is one of `:csv`, `:parquet`, `:ipc`, `:ipc_stream`
+  or `:ndjson`. Parquet files are written with one row group per
+  dataframe, unless `:row_group_size` is given. Writing to remote file
+  systems uploads the file in parts, and the file is complete once all
+  the dataframes are written.
+
+  ## Options
+
+    * `:compression` - The compression algorithm to use, with the same
+      algorithms and levels as the `to_*` function of the format. For example,
+      `to_parquet/3` for `:parquet`. (default: `nil`)
+
+    * `:config` - An optional struct, keyword list or map, normally associated with remote
+      file systems. See [IO section](#module-io-operations) for more details. (default: `nil`)
+
+    * `:backend` - The Explorer backend to use. Defaults to the value returned by `Explorer.Backend.get/0`.
+
+  CSV files also accept the `:header` and `:delimiter` options, as well as
+  the formatting options, of `to_csv/3`. Parquet files also accept the
+  `:row_group_size`, `:data_page_size` and `:statistics` options of
+  `to_parquet/3`.
+
+  ## Examples
+
+      Explorer.DataFrame.stream_csv("large.csv", chunk_size: 100_000)
+      |> Explorer.DataFrame.write_batches("large.parquet", :parquet)
+
+  """
+  @doc type: :io
+  @spec write_batches(
+          dfs :: Enumerable.t(),
+          filename :: String.t() | fs_entry(),
+          format :: :csv | :parquet | :ipc | :ipc_stream | :ndjson,
+          opts :: Keyword.t()
+        ) :: :ok | {:error, Exception.t()}
+  def write_batches(dfs, filename, format, opts \\ []) do
+    {backend_opts, opts} = Keyword.split(opts, [:backend])
+    opts = Keyword.validate!(opts, batches_write_defaults(format))
+    backend = backend_from_options!(backend_opts)
+
+    format =
+      case format do
+        :csv ->
+          compression = text_compression(opts[:compression], "CSV")
+          {:csv, opts[:header], opts[:delimiter], csv_write_options(opts), compression}
+
+        :parquet ->
+          options = parquet_write_options(opts)
+          {:parquet, parquet_compression(opts[:compression]), options}
+
+        :ipc ->
+          {:ipc, ipc_compression(opts[:compression])}
+
+        :ipc_stream ->
+          {:ipc_stream, ipc_compression(opts[:compression])}
+
+        :ndjson ->
+          {:ndjson, text_compression(opts[:compression], "NDJSON")}
+      end
+
+    with {:ok, entry} <- normalise_entry(filename, opts[:config]) do
+      backend.write_batches(Stream.map(dfs, &compute/1), entry, format)
+    end
+  end
+
+  defp batches_write_defaults(:csv),
+    do: [header: true, delimiter: ",", compression: nil, config: nil] ++ @csv_write_defaults
+
+  defp batches_write_defaults(:parquet),
+    do: [compression: nil, config: nil] ++ @parquet_write_defaults
+
+  defp batches_write_defaults(format) when format in [:ipc, :ipc_stream, :ndjson],
+    do: [compression: nil, config: nil]
+
+  defp batches_write_defaults(format) do
+    raise ArgumentError,
+          "expected format to be one of :csv, :parquet, :ipc, :ipc_stream or :ndjson, " <>
+            "got: #{inspect(format)}"
+  end
+
+  @doc """
+  Similar to `write_batches/4`, but raises in case of error.
+  """
+  @doc type: :io
+  @spec write_batches!(
+          dfs :: Enumerable.t(),
+          filename :: String.t() | fs_entry(),
+          format :: :csv | :parquet | :ipc | :ipc_stream | :ndjson,
+          opts :: Keyword.t()
+        ) :: :ok
+  def write_batches!(dfs, filename, format, opts \\ []) do
+    case write_batches(dfs, filename, format, opts) do
+      :ok ->
+        :ok
+
+      {:error, %module{} = e} when module in [ArgumentError, RuntimeError] ->
+        raise module, "write_batches failed: #{e.message}"
+
+      {:error, error} ->
+        raise "write_batches failed: #{inspect(error)}"
+    end
+  end
+
   @doc """
   Writes a dataframe to a binary representation of a NDJSON file.
 
   Groups are ignored if the dataframe is using any.
 
+  ## Options
+
+    * `:compression` - The compression algorithm to use when writing files.
+      Where a compression level is available, this can be passed as a tuple,
+      such as `{:zstd, 3}`. Supported options are:
+
+        * `nil` (uncompressed, default)
+        * `:gzip` (with levels 1-9)
+        * `:zstd` (with levels -7-22)
+
   ## Examples
 
       iex> df = Explorer.DataFrame.new(col_a: [1, 2], col_b: [5.1, 5.2])
@@ -1545,18 +2339,22 @@ defmodule Explorer.DataFrame do
 
   """
   @doc type: :io
-  @spec dump_ndjson(df :: DataFrame.t()) :: {:ok, binary()} | {:error, Exception.t()}
-  def dump_ndjson(df) do
-    Shared.apply_dataframe(df, :dump_ndjson, [], false)
+  @spec dump_ndjson(df :: DataFrame.t(), opts :: Keyword.t()) ::
+          {:ok, binary()} | {:error, Exception.t()}
+  def dump_ndjson(df, opts \\ []) do
+    opts = Keyword.validate!(opts, compression: nil)
+    compression = text_compression(opts[:compression], "NDJSON")
+
+    Shared.apply_dataframe(df, :dump_ndjson, [compression], false)
   end
 
   @doc """
   Similar to `dump_ndjson!/2`, but raises in case of error.
   """
   @doc type: :io
-  @spec dump_ndjson!(df :: DataFrame.t()) :: binary()
-  def dump_ndjson!(df) do
-    case dump_ndjson(df) do
+  @spec dump_ndjson!(df :: DataFrame.t(), opts :: Keyword.t()) :: binary()
+  def dump_ndjson!(df, opts \\ []) do
+    case dump_ndjson(df, opts) do
       {:ok, ndjson} -> ndjson
       {:error, error} -> raise "dump_ndjson failed: #{inspect(error)}"
     end
@@ -1565,6 +2363,8 @@ defmodule Explorer.DataFrame do
   @doc """
   Reads a representation of a NDJSON file into a dataframe.
 
+  If the NDJSON is compressed, it is automatically decompressed.
+
   Accepts the same options as `from_ndjson/2`.
   """
   @doc type: :io
@@ -2031,6 +2831,71 @@ defmodule Explorer.DataFrame do
     Shared.apply_dataframe(df, :to_rows_stream, [opts[:atom_keys], opts[:chunk_size]], false)
   end
 
+  @doc """
+  Exports the dataframe as Arrow C Data Interface structs.
+
+  This allows other NIFs in the same VM, such as ADBC drivers, to read the
+  dataframe without a copy. The dataframe is exported as a struct array, with
+  one field per column. The data is only copied when the columns have more
+  than one chunk, as their chunks are then concatenated into one.
+
+  It returns a map with the `:array` and `:schema` addresses, as integers,
+  of the `ArrowArray` and `ArrowSchema` structs, to be given to the other NIFs.
+  The structs are released once the `:owner` of the map is garbage collected,
+  so it must be kept referenced while the structs are used. As in the C Data
+  Interface, the consumer of the array moves it out of its address.
+
+  Groups are ignored if the dataframe is using any. See `from_arrow_c_data/2`
+  for the import of such structs.
+
+  ## Examples
+
+      c_data = Explorer.DataFrame.to_arrow_c_data(df)
+      SomeNIF.consume(c_data.array, c_data.schema)
+
+  """
+  @doc type: :conversion
+  @spec to_arrow_c_data(df :: DataFrame.t()) :: Series.arrow_c_data()
+  def to_arrow_c_data(df), do: Shared.apply_dataframe(df, :to_arrow_c_data, [], false)
+
+  @doc """
+  Imports a dataframe from Arrow C Data Interface structs, without copying their data.
+
+  It expects a map with the `:array` and `:schema` addresses, as integers,
+  of an `ArrowArray` with a struct type and its `ArrowSchema`, such as the
+  map returned by `to_arrow_c_data/1`. Each field of the struct becomes a
+  column of the dataframe.
+
+  The array is moved out of its address, so it can only be imported once,
+  and importing it again raises. The schema is only read. The addresses must
+  be of valid structs in the memory of the VM, otherwise the VM may crash.
+
+  ## Options
+
+    * `:backend` - The Explorer backend to use. Defaults to the value returned by `Explorer.Backend.get/0`.
+
+  ## Examples
+
+      iex> df = Explorer.DataFrame.new(floats: [1.0, 2.0], ints: [1, nil])
+      iex> c_data = Explorer.DataFrame.to_arrow_c_data(df)
+      iex> df = Explorer.DataFrame.from_arrow_c_data(c_data)
+      iex> Explorer.DataFrame.to_columns(df, atom_keys: true)
+      %{floats: [1.0, 2.0], ints: [1, nil]}
+
+  """
+  @doc type: :conversion
+  @spec from_arrow_c_data(
+          c_data :: %{array: non_neg_integer(), schema: non_neg_integer()},
+          opts :: Keyword.t()
+        ) :: DataFrame.t()
+  def from_arrow_c_data(%{array: array, schema: schema}, opts \\ [])
+      when is_integer(array) and is_integer(schema) do
+    opts = Keyword.validate!(opts, [:backend])
+    backend = backend_from_options!(opts)
+
+    backend.from_arrow_c_data(array, schema)
+  end
+
   # Introspection
 
   @doc """
@@ -0,0 +1,116 @@
+defmodule Explorer.FSS.Azure do
+  @moduledoc """
+  Entries for objects stored in Azure Blob Storage.
+
+  They are usually built from "az://" URLs, given to the IO functions
+  of `Explorer.DataFrame` along with the `:config` option:
+
+      Explorer.DataFrame.from_parquet("az://container/file.parquet",
+        config: [account: "my-account", access_key: "my-key"]
+      )
+
+  Setting the `:endpoint` reaches an emulator, such as
+  [Azurite](https://github.com/Azure/Azurite), instead of Azure.
+  Note that the account is part of the emulator's endpoint:
+
+      Explorer.DataFrame.from_parquet("az://container/file.parquet",
+        config: [
+          account: "devstoreaccount1",
+          access_key: "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==",
+          endpoint: "http://127.0.0.1:10000/devstoreaccount1"
+        ]
+      )
+
+  """
+
+  defmodule Config do
+    @moduledoc """
+    The configuration of an Azure Blob Storage container.
+
+    The fields are:
+
+      * `:account` - the name of the storage account.
+
+      * `:access_key` - the access key of the storage account.
+
+      * `:container` - the name of the container.
+
+      * `:endpoint` - the URL of the Blob service. Defaults to
+        "https://<account>.blob.core.windows.net".
+
+    """
+    defstruct [:account, :access_key, :container, :endpoint]
+
+    @type t :: %__MODULE__{
+            account: String.t() | nil,
+            access_key: String.t() | nil,
+            container: String.t() | nil,
+            endpoint: String.t() | nil
+          }
+  end
+
+  defmodule Entry do
+    @moduledoc """
+    An object, or blob, in an Azure Blob Storage container.
+    """
+    defstruct [:key, :config]
+
+    @type t :: %__MODULE__{key: String.t(), config: Config.t()}
+  end
+
+  @doc """
+  Parses a "az://container/key" URL into an entry.
+
+  ## Options
+
+    * `:config` - a `Explorer.FSS.Azure.Config` struct, or a keyword list or
+      map with its fields. The missing fields are read from the system env.
+      The container always comes from the URL.
+
+  """
+  @spec parse(String.t(), Keyword.t()) :: {:ok, Entry.t()} | {:error, Exception.t()}
+  def parse(url, opts \\ []) do
+    opts = Keyword.validate!(opts, config: nil)
+
+    case URI.parse(url) do
+      %URI{scheme: "az", host: container, path: "/" <> key} when container not in [nil, ""] ->
+        config = %{config(opts[:config]) | container: container}
+
+        if is_nil(config.account) do
+          {:error, ArgumentError.exception("the :account is required to access Azure")}
+        else
+          {:ok, %Entry{key: key, config: config}}
+        end
+
+      _ ->
+        {:error, ArgumentError.exception("expected az://<container>/<key> URL, got: #{url}")}
+    end
+  end
+
+  defp config(nil), do: config_from_system_env()
+  defp config(%Config{} = config), do: config
+
+  defp config(config) when is_list(config) or is_map(config),
+    do: struct!(config_from_system_env(), config)
+
+  defp config(other) do
+    raise ArgumentError,
+          "expected :config to be a %Explorer.FSS.Azure.Config{} struct, " <>
+            "a keyword list or a map, got: #{inspect(other)}"
+  end
+
+  @doc """
+  Builds a config from the system env.
+
+  It reads the `AZURE_STORAGE_ACCOUNT_NAME`, `AZURE_STORAGE_ACCOUNT_KEY`
+  and `AZURE_STORAGE_ENDPOINT` variables.
+  """
+  @spec config_from_system_env() :: Config.t()
+  def config_from_system_env do
+    %Config{
+      account: System.get_env("AZURE_STORAGE_ACCOUNT_NAME"),
+      access_key: System.get_env("AZURE_STORAGE_ACCOUNT_KEY"),
+      endpoint: System.get_env("AZURE_STORAGE_ENDPOINT")
+    }
+  end
+end
@@ -0,0 +1,107 @@
+defmodule Explorer.FSS.GCS do
+  @moduledoc """
+  Entries for objects stored in Google Cloud Storage.
+
+  They are usually built from "gs://" URLs, given to the IO functions
+  of `Explorer.DataFrame` along with the `:config` option:
+
+      Explorer.DataFrame.from_parquet("gs://bucket/file.parquet",
+        config: [service_account_key: File.read!("service-account.json")]
+      )
+
+  Setting the `:endpoint` reaches an emulator, such as
+  [fake-gcs-server](https://github.com/fsouza/fake-gcs-server),
+  instead of Google Cloud Storage:
+
+      Explorer.DataFrame.from_parquet("gs://bucket/file.parquet",
+        config: [endpoint: "http://localhost:4443"]
+      )
+
+  """
+
+  defmodule Config do
+    @moduledoc """
+    The configuration of a Google Cloud Storage bucket.
+
+    The fields are:
+
+      * `:bucket` - the name of the bucket.
+
+      * `:service_account_key` - the JSON key of the service account used
+        to sign the requests. When `nil`, the application default credentials
+        are used.
+
+      * `:endpoint` - the base URL of the API. When set without a
+        `:service_account_key`, the requests are not signed, which is
+        what the emulators expect.
+
+    """
+    defstruct [:bucket, :service_account_key, :endpoint]
+
+    @type t :: %__MODULE__{
+            bucket: String.t() | nil,
+            service_account_key: String.t() | nil,
+            endpoint: String.t() | nil
+          }
+  end
+
+  defmodule Entry do
+    @moduledoc """
+    An object in a Google Cloud Storage bucket.
+    """
+    defstruct [:key, :config]
+
+    @type t :: %__MODULE__{key: String.t(), config: Config.t()}
+  end
+
+  @doc """
+  Parses a "gs://bucket/key" URL into an entry.
+
+  ## Options
+
+    * `:config` - a `Explorer.FSS.GCS.Config` struct, or a keyword list or
+      map with its fields. The missing fields are read from the system env.
+      The bucket always comes from the URL.
+
+  """
+  @spec parse(String.t(), Keyword.t()) :: {:ok, Entry.t()} | {:error, Exception.t()}
+  def parse(url, opts \\ []) do
+    opts = Keyword.validate!(opts, config: nil)
+
+    case URI.parse(url) do
+      %URI{scheme: "gs", host: bucket, path: "/" <> key} when bucket not in [nil, ""] ->
+        config = %{config(opts[:config]) | bucket: bucket}
+        {:ok, %Entry{key: key, config: config}}
+
+      _ ->
+        {:error, ArgumentError.exception("expected gs://<bucket>/<key> URL, got: #{url}")}
+    end
+  end
+
+  defp config(nil), do: config_from_system_env()
+  defp config(%Config{} = config), do: config
+
+  defp config(config) when is_list(config) or is_map(config),
+    do: struct!(config_from_system_env(), config)
+
+  defp config(other) do
+    raise ArgumentError,
+          "expected :config to be a %Explorer.FSS.GCS.Config{} struct, " <>
+            "a keyword list or a map, got: #{inspect(other)}"
+  end
+
+  @doc """
+  Builds a config from the system env.
+
+  The service account key is read from `GOOGLE_SERVICE_ACCOUNT_KEY`, and
+  the endpoint from `STORAGE_EMULATOR_HOST`, which is also used by the
+  Google Cloud client libraries.
+  """
+  @spec config_from_system_env() :: Config.t()
+  def config_from_system_env do
+    %Config{
+      service_account_key: System.get_env("GOOGLE_SERVICE_ACCOUNT_KEY"),
+      endpoint: System.get_env("STORAGE_EMULATOR_HOST")
+    }
+  end
+end
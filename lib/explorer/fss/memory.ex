@@ -0,0 +1,43 @@
+defmodule Explorer.FSS.Memory do
+  @moduledoc """
+  Entries for objects kept in an in-memory store.
+
+  The store lives as long as it is referenced, which makes it
+  useful to test code that reads from and writes to object stores:
+
+      store = Explorer.FSS.Memory.new_store()
+      entry = Explorer.FSS.Memory.entry(store, "path/to/file.parquet")
+
+      :ok = Explorer.DataFrame.to_parquet(df, entry)
+      Explorer.DataFrame.from_parquet!(entry)
+
+  """
+
+  defmodule Entry do
+    @moduledoc """
+    An object in an in-memory store.
+    """
+    defstruct [:key, :store]
+
+    @type t :: %__MODULE__{key: String.t(), store: reference()}
+  end
+
+  @doc """
+  Creates a new and empty in-memory store.
+  """
+  @spec new_store() :: reference()
+  def new_store do
+    case Explorer.PolarsBackend.Native.memory_store_new() do
+      {:ok, store} -> store
+      {:error, error} -> raise RuntimeError, error
+    end
+  end
+
+  @doc """
+  Builds an entry for the object with the given key in the store.
+  """
+  @spec entry(reference(), String.t()) :: Entry.t()
+  def entry(store, key) when is_reference(store) and is_binary(key) do
+    %Entry{key: key, store: store}
+  end
+end
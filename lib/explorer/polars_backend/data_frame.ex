@@ -8,6 +8,9 @@ defmodule Explorer.PolarsBackend.DataFrame do
   alias Explorer.PolarsBackend.Shared
   alias Explorer.Series, as: Series
 
+  alias Explorer.FSS.Azure
+  alias Explorer.FSS.GCS
+  alias Explorer.FSS.Memory
   alias FSS.HTTP
   alias FSS.Local
   alias FSS.S3
@@ -20,6 +23,9 @@ defmodule Explorer.PolarsBackend.DataFrame do
 
   @behaviour Explorer.Backend.DataFrame
 
+  # The entries read and written by the "*_cloud" functions.
+  @cloud_entries [S3.Entry, GCS.Entry, Azure.Entry, Memory.Entry]
+
   # IO
 
   @compile {:no_warn_undefined, Adbc.Connection}
@@ -35,9 +41,16 @@ defmodule Explorer.PolarsBackend.DataFrame do
          do: Shared.create_dataframe(df)
   end
 
+  # Hive partitions and file paths only make sense when scanning many files.
+  @impl true
+  def from_csv(_, _, _, _, _, _, _, _, _, _, _, _, _, _, include_file_paths)
+      when include_file_paths != nil do
+    {:error, ArgumentError.exception(":include_file_paths is only supported by lazy dataframes")}
+  end
+
   @impl true
   def from_csv(
-        %module{} = entry,
+        %HTTP.Entry{} = entry,
         dtypes,
         delimiter,
         nil_values,
@@ -49,9 +62,10 @@ defmodule Explorer.PolarsBackend.DataFrame do
         columns,
         infer_schema_length,
         parse_dates,
-        eol_delimiter
-      )
-      when module in [S3.Entry, HTTP.Entry] do
+        eol_delimiter,
+        dialect,
+        include_file_paths
+      ) do
     path = Shared.build_path_for_entry(entry)
 
     with :ok <- Explorer.FSS.download(entry, path) do
@@ -71,7 +85,9 @@ defmodule Explorer.PolarsBackend.DataFrame do
           columns,
           infer_schema_length,
           parse_dates,
-          eol_delimiter
+          eol_delimiter,
+          dialect,
+          include_file_paths
         )
 
       File.rm(path)
@@ -81,7 +97,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
 
   @impl true
   def from_csv(
-        %Local.Entry{} = entry,
+        %module{} = entry,
         dtypes,
         <<delimiter::utf8>>,
         nil_values,
@@ -93,8 +109,11 @@ defmodule Explorer.PolarsBackend.DataFrame do
         columns,
         infer_schema_length,
         parse_dates,
-        eol_delimiter
-      ) do
+        eol_delimiter,
+        dialect,
+        _include_file_paths
+      )
+      when module in [Local.Entry | @cloud_entries] do
     infer_schema_length =
       if infer_schema_length == nil,
         do: max_rows,
@@ -102,9 +121,15 @@ defmodule Explorer.PolarsBackend.DataFrame do
 
     {columns, with_projection} = column_names_or_projection(columns)
 
+    {fun, source} =
+      case entry do
+        %Local.Entry{path: path} -> {:df_from_csv, path}
+        _ -> {:df_from_csv_cloud, Shared.cloud_entry(entry)}
+      end
+
     df =
-      Native.df_from_csv(
-        entry.path,
+      apply(Native, fun, [
+        source,
         infer_schema_length,
         header?,
         max_rows,
@@ -118,12 +143,13 @@ defmodule Explorer.PolarsBackend.DataFrame do
         encoding,
         nil_values,
         parse_dates,
-        char_byte(eol_delimiter)
-      )
+        char_byte(eol_delimiter),
+        Shared.csv_dialect(dialect)
+      ])
 
     case df do
       {:ok, df} -> Shared.create_dataframe(df)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, read_error(error)}
     end
   end
 
@@ -144,29 +170,68 @@ defmodule Explorer.PolarsBackend.DataFrame do
     end
   end
 
+  defp lazy_scan_error do
+    ArgumentError.exception(
+      ":hive_partitioning and :include_file_paths are only supported by lazy dataframes"
+    )
+  end
+
+  # Missing remote resources are reported as an argument error,
+  # as when they are downloaded by the file systems.
+  defp read_error("resource not found (404)" = error), do: ArgumentError.exception(error)
+  defp read_error(error), do: RuntimeError.exception(error)
+
   @impl true
-  def to_csv(%DataFrame{data: df}, %Local.Entry{} = entry, header?, delimiter, _streaming) do
+  def to_csv(
+        %DataFrame{data: df},
+        %Local.Entry{} = entry,
+        header?,
+        delimiter,
+        options,
+        compression,
+        _streaming
+      ) do
     <<delimiter::utf8>> = delimiter
+    compression = Shared.text_compression(compression)
 
-    case Native.df_to_csv(df, entry.path, header?, delimiter) do
+    case Native.df_to_csv(df, entry.path, header?, delimiter, options, compression) do
       {:ok, _} -> :ok
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
   end
 
   @impl true
-  def to_csv(%DataFrame{data: df}, %S3.Entry{} = entry, header?, delimiter, _streaming) do
+  def to_csv(
+        %DataFrame{data: df},
+        %module{} = entry,
+        header?,
+        delimiter,
+        options,
+        compression,
+        _streaming
+      )
+      when module in @cloud_entries do
     <<delimiter::utf8>> = delimiter
+    compression = Shared.text_compression(compression)
 
-    case Native.df_to_csv_cloud(df, entry, header?, delimiter) do
+    case Native.df_to_csv_cloud(
+           df,
+           Shared.cloud_entry(entry),
+           header?,
+           delimiter,
+           options,
+           compression
+         ) do
       {:ok, _} -> :ok
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
   end
 
   @impl true
-  def dump_csv(%DataFrame{} = df, header?, <<delimiter::utf8>>) do
-    case Native.df_dump_csv(df.data, header?, delimiter) do
+  def dump_csv(%DataFrame{} = df, header?, <<delimiter::utf8>>, options, compression) do
+    compression = Shared.text_compression(compression)
+
+    case Native.df_dump_csv(df.data, header?, delimiter, options, compression) do
       {:ok, string} -> {:ok, string}
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
@@ -186,7 +251,8 @@ defmodule Explorer.PolarsBackend.DataFrame do
         columns,
         infer_schema_length,
         parse_dates,
-        eol_delimiter
+        eol_delimiter,
+        dialect
       ) do
     infer_schema_length =
       if infer_schema_length == nil,
@@ -211,7 +277,8 @@ defmodule Explorer.PolarsBackend.DataFrame do
         encoding,
         nil_values,
         parse_dates,
-        char_byte(eol_delimiter)
+        char_byte(eol_delimiter),
+        Shared.csv_dialect(dialect)
       )
 
     case df do
@@ -225,8 +292,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
 
   @impl true
 
-  def from_ndjson(%module{} = entry, infer_schema_length, batch_size)
-      when module in [S3.Entry, HTTP.Entry] do
+  def from_ndjson(%HTTP.Entry{} = entry, infer_schema_length, batch_size) do
     path = Shared.build_path_for_entry(entry)
 
     with :ok <- Explorer.FSS.download(entry, path) do
@@ -248,24 +314,42 @@ defmodule Explorer.PolarsBackend.DataFrame do
   end
 
   @impl true
-  def to_ndjson(%DataFrame{data: df}, %Local.Entry{} = entry) do
-    case Native.df_to_ndjson(df, entry.path) do
+  def from_ndjson(%module{} = entry, infer_schema_length, batch_size)
+      when module in @cloud_entries do
+    case Native.df_from_ndjson_cloud(
+           Shared.cloud_entry(entry),
+           infer_schema_length,
+           batch_size
+         ) do
+      {:ok, df} -> Shared.create_dataframe(df)
+      {:error, error} -> {:error, read_error(error)}
+    end
+  end
+
+  @impl true
+  def to_ndjson(%DataFrame{data: df}, %Local.Entry{} = entry, compression, _streaming) do
+    case Native.df_to_ndjson(df, entry.path, Shared.text_compression(compression)) do
       {:ok, _} -> :ok
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
   end
 
   @impl true
-  def to_ndjson(%DataFrame{data: df}, %S3.Entry{} = entry) do
-    case Native.df_to_ndjson_cloud(df, entry) do
+  def to_ndjson(%DataFrame{data: df}, %module{} = entry, compression, _streaming)
+      when module in @cloud_entries do
+    case Native.df_to_ndjson_cloud(
+           df,
+           Shared.cloud_entry(entry),
+           Shared.text_compression(compression)
+         ) do
       {:ok, _} -> :ok
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
   end
 
   @impl true
-  def dump_ndjson(%DataFrame{} = df) do
-    case Native.df_dump_ndjson(df.data) do
+  def dump_ndjson(%DataFrame{} = df, compression) do
+    case Native.df_dump_ndjson(df.data, Shared.text_compression(compression)) do
       {:ok, string} -> {:ok, string}
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
@@ -279,31 +363,174 @@ defmodule Explorer.PolarsBackend.DataFrame do
     end
   end
 
+  # Streams
+
   @impl true
-  def from_parquet(%S3.Entry{} = entry, max_rows, columns, _rechunk) do
-    # We first read using a lazy dataframe, then we collect.
-    with {:ok, ldf} <- Native.lf_from_parquet_cloud(entry, max_rows, columns),
-         {:ok, df} <- Native.lf_compute(ldf) do
-      Shared.create_dataframe(df)
+  def stream_csv(
+        %Local.Entry{} = entry,
+        dtypes,
+        <<delimiter::utf8>>,
+        nil_values,
+        skip_rows,
+        skip_rows_after_header,
+        header?,
+        encoding,
+        columns,
+        infer_schema_length,
+        parse_dates,
+        eol_delimiter,
+        dialect,
+        chunk_size
+      ) do
+    {columns, with_projection} = column_names_or_projection(columns)
+
+    batched_stream(:csv_batched_reader_new, [
+      entry.path,
+      chunk_size,
+      infer_schema_length,
+      header?,
+      skip_rows,
+      skip_rows_after_header,
+      with_projection,
+      delimiter,
+      columns,
+      dtypes,
+      encoding,
+      nil_values,
+      parse_dates,
+      char_byte(eol_delimiter),
+      Shared.csv_dialect(dialect)
+    ])
+  end
+
+  @impl true
+  def stream_parquet(%Local.Entry{} = entry, columns, chunk_size) do
+    {columns, with_projection} = column_names_or_projection(columns)
+
+    batched_stream(:parquet_batched_reader_new, [
+      entry.path,
+      chunk_size,
+      columns,
+      with_projection
+    ])
+  end
+
+  @impl true
+  def stream_ipc_stream(%Local.Entry{} = entry, columns, chunk_size) do
+    {columns, projection} = column_names_or_projection(columns)
+
+    batched_stream(:ipc_stream_batched_reader_new, [entry.path, chunk_size, columns, projection])
+  end
+
+  @impl true
+  def stream_ndjson(%Local.Entry{} = entry, infer_schema_length, chunk_size) do
+    batched_stream(:ndjson_batched_reader_new, [entry.path, chunk_size, infer_schema_length])
+  end
+
+  # The reader is created when the stream starts, so the
+  # stream reads the file again each time it is enumerated.
+  defp batched_stream(fun, args) do
+    Stream.resource(
+      fn -> Shared.apply(fun, args) end,
+      fn reader ->
+        case Native.reader_next_batch(reader) do
+          {:ok, nil} -> {:halt, reader}
+          {:ok, df} -> {[Shared.create_dataframe!(df)], reader}
+          {:error, error} -> raise RuntimeError, error
+        end
+      end,
+      fn _reader -> :ok end
+    )
+  end
+
+  # The writer is closed once all the dataframes are written, which
+  # completes the file, or the upload of the object.
+  @impl true
+  def write_batches(dfs, %module{} = entry, format)
+      when module in [Local.Entry | @cloud_entries] do
+    case batched_writer_new(Shared.cloud_entry(entry), format) do
+      {:ok, writer} -> write_batches_with(writer, dfs)
+      {:error, error} -> {:error, RuntimeError.exception(error)}
     end
   end
 
+  # The file is removed, or the upload cancelled, when a batch fails
+  # or the enumerable raises, so no partial file is left behind.
+  defp write_batches_with(writer, dfs) do
+    with :ok <- write_batches_to(writer, dfs),
+         {:ok, _} <- Native.writer_close(writer) do
+      :ok
+    else
+      {:error, error} ->
+        Native.writer_abort(writer)
+        {:error, RuntimeError.exception(error)}
+    end
+  catch
+    kind, reason ->
+      Native.writer_abort(writer)
+      :erlang.raise(kind, reason, __STACKTRACE__)
+  end
+
+  defp batched_writer_new(entry, {:csv, header?, <<delimiter::utf8>>, options, compression}) do
+    compression = Shared.text_compression(compression)
+    Native.csv_writer_new(entry, header?, delimiter, options, compression)
+  end
+
+  defp batched_writer_new(entry, {:parquet, {compression, compression_level}, options}) do
+    compression = parquet_compression(compression, compression_level)
+    Native.parquet_writer_new(entry, compression, options)
+  end
+
+  defp batched_writer_new(entry, {:ipc, {compression, _level}}) do
+    Native.ipc_writer_new(entry, maybe_atom_to_string(compression))
+  end
+
+  defp batched_writer_new(entry, {:ipc_stream, {compression, _level}}) do
+    Native.ipc_stream_writer_new(entry, maybe_atom_to_string(compression))
+  end
+
+  defp batched_writer_new(entry, {:ndjson, compression}) do
+    Native.ndjson_writer_new(entry, Shared.text_compression(compression))
+  end
+
+  defp write_batches_to(writer, dfs) do
+    Enum.reduce_while(dfs, :ok, fn %DataFrame{data: df}, :ok ->
+      case Native.writer_write_batch(writer, df) do
+        {:ok, _} -> {:cont, :ok}
+        {:error, error} -> {:halt, {:error, error}}
+      end
+    end)
+  end
+
+  # The Parquet and IPC files are read with ranged requests, so only
+  # the footer and the selected columns of a Parquet file are fetched.
   @impl true
-  def from_parquet(%HTTP.Entry{} = entry, max_rows, columns, rechunk) do
-    path = Shared.build_path_for_entry(entry)
+  def from_parquet(_entry, _max_rows, _columns, _rechunk, hive_partitioning, include_file_paths)
+      when hive_partitioning != nil or include_file_paths != nil do
+    {:error, lazy_scan_error()}
+  end
 
-    with :ok <- Explorer.FSS.download(entry, path) do
-      entry = Local.from_path(path)
+  @impl true
+  def from_parquet(%HTTP.Entry{} = entry, max_rows, columns, rechunk, _, _) do
+    {columns, with_projection} = column_names_or_projection(columns)
 
-      result = from_parquet(entry, max_rows, columns, rechunk)
+    df =
+      Native.df_from_parquet_cloud(
+        Shared.cloud_entry(entry),
+        max_rows,
+        columns,
+        with_projection,
+        rechunk
+      )
 
-      File.rm(path)
-      result
+    case df do
+      {:ok, df} -> Shared.create_dataframe(df)
+      {:error, error} -> {:error, read_error(error)}
     end
   end
 
   @impl true
-  def from_parquet(%Local.Entry{} = entry, max_rows, columns, rechunk) do
+  def from_parquet(%Local.Entry{} = entry, max_rows, columns, rechunk, _, _) do
     {columns, with_projection} = column_names_or_projection(columns)
 
     df =
@@ -321,14 +548,40 @@ defmodule Explorer.PolarsBackend.DataFrame do
     end
   end
 
+  @impl true
+  def from_parquet(%module{} = entry, max_rows, columns, rechunk, _, _)
+      when module in @cloud_entries do
+    {columns, with_projection} = column_names_or_projection(columns)
+
+    df =
+      Native.df_from_parquet_cloud(
+        Shared.cloud_entry(entry),
+        max_rows,
+        columns,
+        with_projection,
+        rechunk
+      )
+
+    case df do
+      {:ok, df} -> Shared.create_dataframe(df)
+      {:error, error} -> {:error, read_error(error)}
+    end
+  end
+
   @impl true
   def to_parquet(
         %DataFrame{data: df},
         %Local.Entry{} = entry,
         {compression, compression_level},
+        options,
         _streaming
       ) do
-    case Native.df_to_parquet(df, entry.path, parquet_compression(compression, compression_level)) do
+    case Native.df_to_parquet(
+           df,
+           entry.path,
+           parquet_compression(compression, compression_level),
+           options
+         ) do
       {:ok, _} -> :ok
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
@@ -337,14 +590,58 @@ defmodule Explorer.PolarsBackend.DataFrame do
   @impl true
   def to_parquet(
         %DataFrame{data: df},
-        %S3.Entry{} = entry,
+        %module{} = entry,
         {compression, compression_level},
+        options,
         _streaming
-      ) do
+      )
+      when module in @cloud_entries do
     case Native.df_to_parquet_cloud(
            df,
-           entry,
-           parquet_compression(compression, compression_level)
+           Shared.cloud_entry(entry),
+           parquet_compression(compression, compression_level),
+           options
+         ) do
+      {:ok, _} -> :ok
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
+
+  @impl true
+  def to_parquet_partitioned(
+        %DataFrame{data: df},
+        %Local.Entry{} = entry,
+        partition_by,
+        {compression, compression_level},
+        options
+      ) do
+    case Native.df_to_parquet_partitioned(
+           df,
+           entry.path,
+           partition_by,
+           parquet_compression(compression, compression_level),
+           options
+         ) do
+      {:ok, _} -> :ok
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
+
+  @impl true
+  def to_parquet_partitioned(
+        %DataFrame{data: df},
+        %module{} = entry,
+        partition_by,
+        {compression, compression_level},
+        options
+      )
+      when module in @cloud_entries do
+    case Native.df_to_parquet_partitioned_cloud(
+           df,
+           Shared.cloud_entry(entry),
+           partition_by,
+           parquet_compression(compression, compression_level),
+           options
          ) do
       {:ok, _} -> :ok
       {:error, error} -> {:error, RuntimeError.exception(error)}
@@ -352,8 +649,12 @@ defmodule Explorer.PolarsBackend.DataFrame do
   end
 
   @impl true
-  def dump_parquet(%DataFrame{data: df}, {compression, compression_level}) do
-    case Native.df_dump_parquet(df, parquet_compression(compression, compression_level)) do
+  def dump_parquet(%DataFrame{data: df}, {compression, compression_level}, options) do
+    case Native.df_dump_parquet(
+           df,
+           parquet_compression(compression, compression_level),
+           options
+         ) do
       {:ok, string} -> {:ok, string}
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
@@ -376,21 +677,47 @@ defmodule Explorer.PolarsBackend.DataFrame do
   end
 
   @impl true
-  def from_ipc(%module{} = entry, columns) when module in [S3.Entry, HTTP.Entry] do
-    path = Shared.build_path_for_entry(entry)
+  def parquet_metadata(%Local.Entry{} = entry) do
+    case Native.df_parquet_metadata(entry.path) do
+      {:ok, df} -> Shared.create_dataframe(df)
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
 
-    with :ok <- Explorer.FSS.download(entry, path) do
-      entry = Local.from_path(path)
+  @impl true
+  def parquet_metadata(%module{} = entry) when module in [HTTP.Entry | @cloud_entries] do
+    case Native.df_parquet_metadata_cloud(Shared.cloud_entry(entry)) do
+      {:ok, df} -> Shared.create_dataframe(df)
+      {:error, error} -> {:error, read_error(error)}
+    end
+  end
 
-      result = from_ipc(entry, columns)
+  @impl true
+  def load_parquet_metadata(contents) when is_binary(contents) do
+    case Native.df_load_parquet_metadata(contents) do
+      {:ok, df} -> Shared.create_dataframe(df)
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
 
-      File.rm(path)
-      result
+  @impl true
+  def from_ipc(_entry, _columns, hive_partitioning, include_file_paths)
+      when hive_partitioning != nil or include_file_paths != nil do
+    {:error, lazy_scan_error()}
+  end
+
+  @impl true
+  def from_ipc(%HTTP.Entry{} = entry, columns, _, _) do
+    {columns, projection} = column_names_or_projection(columns)
+
+    case Native.df_from_ipc_cloud(Shared.cloud_entry(entry), columns, projection) do
+      {:ok, df} -> Shared.create_dataframe(df)
+      {:error, error} -> {:error, read_error(error)}
     end
   end
 
   @impl true
-  def from_ipc(%Local.Entry{} = entry, columns) do
+  def from_ipc(%Local.Entry{} = entry, columns, _, _) do
     {columns, projection} = column_names_or_projection(columns)
 
     case Native.df_from_ipc(entry.path, columns, projection) do
@@ -399,6 +726,16 @@ defmodule Explorer.PolarsBackend.DataFrame do
     end
   end
 
+  @impl true
+  def from_ipc(%module{} = entry, columns, _, _) when module in @cloud_entries do
+    {columns, projection} = column_names_or_projection(columns)
+
+    case Native.df_from_ipc_cloud(Shared.cloud_entry(entry), columns, projection) do
+      {:ok, df} -> Shared.create_dataframe(df)
+      {:error, error} -> {:error, read_error(error)}
+    end
+  end
+
   @impl true
   def to_ipc(%DataFrame{data: df}, %Local.Entry{} = entry, {compression, _level}, _streaming) do
     case Native.df_to_ipc(df, entry.path, maybe_atom_to_string(compression)) do
@@ -408,8 +745,45 @@ defmodule Explorer.PolarsBackend.DataFrame do
   end
 
   @impl true
-  def to_ipc(%DataFrame{data: df}, %S3.Entry{} = entry, {compression, _level}, _streaming) do
-    case Native.df_to_ipc_cloud(df, entry, maybe_atom_to_string(compression)) do
+  def to_ipc(%DataFrame{data: df}, %module{} = entry, {compression, _level}, _streaming)
+      when module in @cloud_entries do
+    case Native.df_to_ipc_cloud(
+           df,
+           Shared.cloud_entry(entry),
+           maybe_atom_to_string(compression)
+         ) do
+      {:ok, _} -> :ok
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
+
+  @impl true
+  def to_ipc_partitioned(
+        %DataFrame{data: df},
+        %Local.Entry{} = entry,
+        partition_by,
+        {compression, _level}
+      ) do
+    case Native.df_to_ipc_partitioned(
+           df,
+           entry.path,
+           partition_by,
+           maybe_atom_to_string(compression)
+         ) do
+      {:ok, _} -> :ok
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
+
+  @impl true
+  def to_ipc_partitioned(%DataFrame{data: df}, %module{} = entry, partition_by, {compression, _})
+      when module in @cloud_entries do
+    case Native.df_to_ipc_partitioned_cloud(
+           df,
+           Shared.cloud_entry(entry),
+           partition_by,
+           maybe_atom_to_string(compression)
+         ) do
       {:ok, _} -> :ok
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
@@ -434,7 +808,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   end
 
   @impl true
-  def from_ipc_stream(%module{} = entry, columns) when module in [S3.Entry, HTTP.Entry] do
+  def from_ipc_stream(%HTTP.Entry{} = entry, columns) do
     path = Shared.build_path_for_entry(entry)
 
     with :ok <- Explorer.FSS.download(entry, path) do
@@ -458,7 +832,22 @@ defmodule Explorer.PolarsBackend.DataFrame do
   end
 
   @impl true
-  def to_ipc_stream(%DataFrame{data: df}, %Local.Entry{} = entry, {compression, _level}) do
+  def from_ipc_stream(%module{} = entry, columns) when module in @cloud_entries do
+    {columns, projection} = column_names_or_projection(columns)
+
+    case Native.df_from_ipc_stream_cloud(Shared.cloud_entry(entry), columns, projection) do
+      {:ok, df} -> Shared.create_dataframe(df)
+      {:error, error} -> {:error, read_error(error)}
+    end
+  end
+
+  @impl true
+  def to_ipc_stream(
+        %DataFrame{data: df},
+        %Local.Entry{} = entry,
+        {compression, _level},
+        _streaming
+      ) do
     case Native.df_to_ipc_stream(df, entry.path, maybe_atom_to_string(compression)) do
       {:ok, _} -> :ok
       {:error, error} -> {:error, RuntimeError.exception(error)}
@@ -466,8 +855,18 @@ defmodule Explorer.PolarsBackend.DataFrame do
   end
 
   @impl true
-  def to_ipc_stream(%DataFrame{data: df}, %S3.Entry{} = entry, {compression, _level}) do
-    case Native.df_to_ipc_stream_cloud(df, entry, maybe_atom_to_string(compression)) do
+  def to_ipc_stream(
+        %DataFrame{data: df},
+        %module{} = entry,
+        {compression, _level},
+        _streaming
+      )
+      when module in @cloud_entries do
+    case Native.df_to_ipc_stream_cloud(
+           df,
+           Shared.cloud_entry(entry),
+           maybe_atom_to_string(compression)
+         ) do
       {:ok, _} -> :ok
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
@@ -598,6 +997,18 @@ defmodule Explorer.PolarsBackend.DataFrame do
     |> Stream.flat_map(&to_rows(&1, atom_keys?))
   end
 
+  @impl true
+  def to_arrow_c_data(%DataFrame{data: df}) do
+    {owner, array, schema} = Shared.apply(:df_to_arrow_c_data, [df])
+    %{array: array, schema: schema, owner: owner}
+  end
+
+  @impl true
+  def from_arrow_c_data(array, schema) do
+    Shared.apply(:df_from_arrow_c_data, [array, schema])
+    |> Shared.create_dataframe!()
+  end
+
   # Ownership
 
   @impl true
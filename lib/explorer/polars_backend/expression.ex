@@ -172,7 +172,8 @@ defmodule Explorer.PolarsBackend.Expression do
     concat: 1,
     column: 1,
     correlation: 4,
-    covariance: 3
+    covariance: 3,
+    draw: 4
   ]
 
   missing =
@@ -229,6 +230,19 @@ defmodule Explorer.PolarsBackend.Expression do
     Native.expr_covariance(to_expr(series1), to_expr(series2), ddof)
   end
 
+  # The series only sets the number of draws, which is the height of the frame.
+  # The groups, when given by a grouped dataframe, choose the stream of each group.
+  def to_expr(%LazySeries{op: :draw, args: [lazy_series, distribution, params, seed]}) do
+    to_expr(%LazySeries{op: :draw, args: [lazy_series, distribution, params, seed, []]})
+  end
+
+  def to_expr(%LazySeries{op: :draw, args: [_lazy_series, distribution, params, seed, groups]}) do
+    expr_params = Enum.map(params, &to_expr/1)
+    expr_groups = Enum.map(groups, &Native.expr_column/1)
+
+    apply(Native, :"expr_draw_from_#{distribution}", [seed, expr_groups | expr_params])
+  end
+
   def to_expr(%LazySeries{op: :format, args: [series_list]}) when is_list(series_list) do
     expr_list = Enum.map(series_list, &to_expr/1)
 
@@ -8,6 +8,9 @@ defmodule Explorer.PolarsBackend.LazyFrame do
   alias Explorer.PolarsBackend.Shared
   alias Explorer.PolarsBackend.DataFrame, as: Eager
 
+  alias Explorer.FSS.Azure
+  alias Explorer.FSS.GCS
+  alias Explorer.FSS.Memory
   alias FSS.Local
   alias FSS.S3
   alias FSS.HTTP
@@ -21,6 +24,10 @@ defmodule Explorer.PolarsBackend.LazyFrame do
 
   @behaviour Explorer.Backend.DataFrame
 
+  # The entries that Polars scans and sinks by itself. The in-memory
+  # ones are only reachable through the eager "*_cloud" functions.
+  @cloud_entries [S3.Entry, GCS.Entry, Azure.Entry]
+
   # Conversion
 
   @impl true
@@ -113,22 +120,95 @@ defmodule Explorer.PolarsBackend.LazyFrame do
 
   @impl true
   def from_csv(
-        %S3.Entry{},
-        _,
-        _,
-        _,
-        _,
-        _,
-        _,
-        _,
-        _,
-        _,
-        _,
-        _,
-        _
-      ) do
-    {:error,
-     ArgumentError.exception("reading CSV from AWS S3 is not supported for Lazy dataframes")}
+        %module{} = entry,
+        dtypes,
+        delimiter,
+        nil_values,
+        skip_rows,
+        skip_rows_after_header,
+        header?,
+        encoding,
+        max_rows,
+        columns,
+        infer_schema_length,
+        parse_dates,
+        eol_delimiter,
+        dialect,
+        include_file_paths
+      )
+      when module in [Memory.Entry, HTTP.Entry] do
+    result =
+      Eager.from_csv(
+        entry,
+        dtypes,
+        delimiter,
+        nil_values,
+        skip_rows,
+        skip_rows_after_header,
+        header?,
+        encoding,
+        max_rows,
+        columns,
+        infer_schema_length,
+        parse_dates,
+        eol_delimiter,
+        dialect,
+        include_file_paths
+      )
+
+    with {:ok, df} <- result do
+      {:ok, Eager.lazy(df)}
+    end
+  end
+
+  @impl true
+  def from_csv(
+        %module{} = entry,
+        dtypes,
+        <<delimiter::utf8>>,
+        nil_values,
+        skip_rows,
+        skip_rows_after_header,
+        header?,
+        encoding,
+        max_rows,
+        columns,
+        infer_schema_length,
+        parse_dates,
+        eol_delimiter,
+        dialect,
+        include_file_paths
+      )
+      when module in @cloud_entries do
+    infer_schema_length =
+      if infer_schema_length == nil,
+        do: max_rows,
+        else: infer_schema_length
+
+    result =
+      Native.lf_from_csv_cloud(
+        Shared.cloud_entry(entry),
+        columns,
+        infer_schema_length,
+        header?,
+        max_rows,
+        skip_rows,
+        skip_rows_after_header,
+        delimiter,
+        true,
+        dtypes,
+        encoding,
+        nil_values,
+        parse_dates,
+        char_byte(eol_delimiter),
+        Shared.csv_dialect(dialect),
+        include_file_paths
+      )
+
+    case result do
+      {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
   end
 
   @impl true
@@ -145,7 +225,9 @@ defmodule Explorer.PolarsBackend.LazyFrame do
         columns,
         infer_schema_length,
         parse_dates,
-        eol_delimiter
+        eol_delimiter,
+        dialect,
+        include_file_paths
       )
       when is_nil(columns) do
     infer_schema_length =
@@ -167,7 +249,9 @@ defmodule Explorer.PolarsBackend.LazyFrame do
         encoding,
         nil_values,
         parse_dates,
-        char_byte(eol_delimiter)
+        char_byte(eol_delimiter),
+        Shared.csv_dialect(dialect),
+        include_file_paths
       )
 
     case result do
@@ -190,6 +274,8 @@ defmodule Explorer.PolarsBackend.LazyFrame do
         _,
         _,
         _,
+        _,
+        _,
         _
       ) do
     {:error,
@@ -202,34 +288,113 @@ defmodule Explorer.PolarsBackend.LazyFrame do
   defp char_byte(nil), do: nil
   defp char_byte(<<char::utf8>>), do: char
 
+  defp store_name(%S3.Entry{}), do: "AWS S3"
+  defp store_name(%GCS.Entry{}), do: "Google Cloud Storage"
+  defp store_name(%Azure.Entry{}), do: "Azure Blob Storage"
+
   @impl true
-  def from_parquet(%S3.Entry{} = entry, max_rows, columns, _rechunk) do
-    case Native.lf_from_parquet_cloud(entry, max_rows, columns) do
+  def from_parquet(
+        %module{} = entry,
+        max_rows,
+        columns,
+        _rechunk,
+        hive_partitioning,
+        include_file_paths
+      )
+      when module in @cloud_entries do
+    entry = Shared.cloud_entry(entry)
+
+    case Native.lf_from_parquet_cloud(
+           entry,
+           max_rows,
+           columns,
+           hive_partitioning,
+           include_file_paths
+         ) do
       {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
   end
 
   @impl true
-  def from_parquet(%HTTP.Entry{url: url}, max_rows, columns, _rechunk) do
-    case Native.lf_from_parquet(url, max_rows, columns) do
+  def from_parquet(
+        %HTTP.Entry{} = entry,
+        max_rows,
+        columns,
+        _rechunk,
+        hive_partitioning,
+        include_file_paths
+      ) do
+    entry = Shared.cloud_entry(entry)
+
+    case Native.lf_from_parquet_cloud(
+           entry,
+           max_rows,
+           columns,
+           hive_partitioning,
+           include_file_paths
+         ) do
       {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
   end
 
   @impl true
-  def from_parquet(%Local.Entry{} = entry, max_rows, columns, _rechunk) do
-    case Native.lf_from_parquet(entry.path, max_rows, columns) do
+  def from_parquet(%Memory.Entry{} = entry, max_rows, columns, rechunk, hive, file_paths) do
+    result = Eager.from_parquet(entry, max_rows, columns, rechunk, hive, file_paths)
+
+    with {:ok, df} <- result do
+      {:ok, Eager.lazy(df)}
+    end
+  end
+
+  @impl true
+  def from_parquet(
+        %Local.Entry{} = entry,
+        max_rows,
+        columns,
+        _rechunk,
+        hive_partitioning,
+        include_file_paths
+      ) do
+    case Native.lf_from_parquet(
+           entry.path,
+           max_rows,
+           columns,
+           hive_partitioning,
+           include_file_paths
+         ) do
       {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
   end
 
   @impl true
-  def from_ndjson(%S3.Entry{}, _, _) do
-    {:error,
-     ArgumentError.exception("reading NDJSON from AWS S3 is not supported for Lazy dataframes")}
+  def from_ndjson(%module{} = entry, infer_schema_length, batch_size)
+      when module in @cloud_entries do
+    entry = Shared.cloud_entry(entry)
+
+    case Native.lf_from_ndjson_cloud(entry, nil, nil, infer_schema_length, batch_size) do
+      {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
+
+  @impl true
+  def from_ndjson(%HTTP.Entry{} = entry, infer_schema_length, batch_size) do
+    entry = Shared.cloud_entry(entry)
+
+    case Native.lf_from_ndjson_cloud(entry, nil, nil, infer_schema_length, batch_size) do
+      {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
+
+  @impl true
+  def from_ndjson(%Memory.Entry{} = entry, infer_schema_length, batch_size) do
+    with {:ok, df} <- Eager.from_ndjson(entry, infer_schema_length, batch_size) do
+      {:ok, Eager.lazy(df)}
+    end
   end
 
   @impl true
@@ -241,29 +406,67 @@ defmodule Explorer.PolarsBackend.LazyFrame do
   end
 
   @impl true
-  def from_ndjson(%HTTP.Entry{url: url}, infer_schema_length, batch_size) do
-    case Native.lf_from_ndjson(url, infer_schema_length, batch_size) do
+  def from_ipc(%module{} = entry, columns, hive_partitioning, include_file_paths)
+      when module in @cloud_entries do
+    if is_nil(columns) or Enum.all?(columns, &is_binary/1) do
+      case Native.lf_from_ipc_cloud(
+             Shared.cloud_entry(entry),
+             nil,
+             columns,
+             hive_partitioning,
+             include_file_paths
+           ) do
+        {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
+        {:error, error} -> {:error, RuntimeError.exception(error)}
+      end
+    else
+      {:error,
+       ArgumentError.exception(
+         "reading IPC from #{store_name(entry)} in a lazy dataframe " <>
+           "only supports column names in `columns`"
+       )}
+    end
+  end
+
+  @impl true
+  def from_ipc(
+        %HTTP.Entry{} = entry,
+        columns,
+        hive_partitioning,
+        include_file_paths
+      )
+      when is_nil(columns) do
+    case Native.lf_from_ipc_cloud(
+           Shared.cloud_entry(entry),
+           nil,
+           nil,
+           hive_partitioning,
+           include_file_paths
+         ) do
       {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
   end
 
   @impl true
-  def from_ipc(%S3.Entry{}, _) do
-    {:error,
-     ArgumentError.exception("reading IPC from AWS S3 is not supported for Lazy dataframes")}
+  def from_ipc(%module{} = entry, columns, hive_partitioning, include_file_paths)
+      when module in [Memory.Entry, HTTP.Entry] do
+    with {:ok, df} <- Eager.from_ipc(entry, columns, hive_partitioning, include_file_paths) do
+      {:ok, Eager.lazy(df)}
+    end
   end
 
   @impl true
-  def from_ipc(%Local.Entry{} = entry, columns) when is_nil(columns) do
-    case Native.lf_from_ipc(entry.path) do
+  def from_ipc(%Local.Entry{} = entry, columns, hive_partitioning, include_file_paths)
+      when is_nil(columns) do
+    case Native.lf_from_ipc(entry.path, hive_partitioning, include_file_paths) do
       {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
   end
 
   @impl true
-  def from_ipc(%Local.Entry{}, _columns) do
+  def from_ipc(%Local.Entry{}, _columns, _hive_partitioning, _include_file_paths) do
     {:error,
      ArgumentError.exception(
        "`columns` is not supported by Polars' lazy backend. " <>
@@ -272,15 +475,16 @@ defmodule Explorer.PolarsBackend.LazyFrame do
   end
 
   @impl true
-  def from_ipc_stream(%S3.Entry{}, _) do
+  def from_ipc_stream(%module{} = entry, _) when module in @cloud_entries do
     {:error,
      ArgumentError.exception(
-       "reading IPC Stream from AWS S3 is not supported for Lazy dataframes"
+       "reading IPC Stream from #{store_name(entry)} is not supported for Lazy dataframes"
      )}
   end
 
   @impl true
-  def from_ipc_stream(%Local.Entry{} = fs_entry, columns) do
+  def from_ipc_stream(%module{} = fs_entry, columns)
+      when module in [Local.Entry, Memory.Entry, HTTP.Entry] do
     with {:ok, df} <- Eager.from_ipc_stream(fs_entry, columns) do
       {:ok, Eager.lazy(df)}
     end
@@ -300,7 +504,8 @@ defmodule Explorer.PolarsBackend.LazyFrame do
         columns,
         infer_schema_length,
         parse_dates,
-        eol_delimiter
+        eol_delimiter,
+        dialect
       ) do
     with {:ok, df} <-
            Eager.load_csv(
@@ -316,7 +521,8 @@ defmodule Explorer.PolarsBackend.LazyFrame do
              columns,
              infer_schema_length,
              parse_dates,
-             eol_delimiter
+             eol_delimiter,
+             dialect
            ) do
       {:ok, Eager.lazy(df)}
     end
@@ -329,6 +535,20 @@ defmodule Explorer.PolarsBackend.LazyFrame do
     end
   end
 
+  @impl true
+  def parquet_metadata(entry) do
+    with {:ok, df} <- Eager.parquet_metadata(entry) do
+      {:ok, Eager.lazy(df)}
+    end
+  end
+
+  @impl true
+  def load_parquet_metadata(contents) do
+    with {:ok, df} <- Eager.load_parquet_metadata(contents) do
+      {:ok, Eager.lazy(df)}
+    end
+  end
+
   @impl true
   def load_ndjson(contents, infer_schema_length, batch_size) do
     with {:ok, df} <- Eager.load_ndjson(contents, infer_schema_length, batch_size) do
@@ -351,28 +571,54 @@ defmodule Explorer.PolarsBackend.LazyFrame do
   end
 
   @impl true
-  def to_csv(%DF{} = ldf, %Local.Entry{} = entry, header?, delimiter, streaming) do
+  def to_csv(
+        %DF{} = ldf,
+        %Local.Entry{} = entry,
+        header?,
+        delimiter,
+        options,
+        compression,
+        streaming
+      ) do
     <<delimiter::utf8>> = delimiter
 
-    case Native.lf_to_csv(ldf.data, entry.path, header?, delimiter, streaming) do
+    case Native.lf_to_csv(
+           ldf.data,
+           entry.path,
+           header?,
+           delimiter,
+           options,
+           Shared.text_compression(compression),
+           streaming
+         ) do
       {:ok, _} -> :ok
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
   end
 
   @impl true
-  def to_csv(%DF{} = ldf, %S3.Entry{} = entry, header?, delimiter, _streaming) do
+  def to_csv(
+        %DF{} = ldf,
+        %module{} = entry,
+        header?,
+        delimiter,
+        options,
+        compression,
+        _streaming
+      )
+      when module in [Memory.Entry | @cloud_entries] do
     eager_df = compute(ldf)
 
-    Eager.to_csv(eager_df, entry, header?, delimiter, false)
+    Eager.to_csv(eager_df, entry, header?, delimiter, options, compression, false)
   end
 
   @impl true
-  def to_parquet(%DF{} = ldf, %Local.Entry{} = entry, {compression, level}, streaming) do
+  def to_parquet(%DF{} = ldf, %Local.Entry{} = entry, {compression, level}, options, streaming) do
     case Native.lf_to_parquet(
            ldf.data,
            entry.path,
            Shared.parquet_compression(compression, level),
+           options,
            streaming
          ) do
       {:ok, _} -> :ok
@@ -381,11 +627,62 @@ defmodule Explorer.PolarsBackend.LazyFrame do
   end
 
   @impl true
-  def to_parquet(%DF{} = ldf, %S3.Entry{} = entry, {compression, level}, _streaming = true) do
+  def to_parquet(%DF{} = ldf, %module{} = entry, {compression, level}, options, _streaming = true)
+      when module in @cloud_entries do
     case Native.lf_to_parquet_cloud(
            ldf.data,
-           entry,
-           Shared.parquet_compression(compression, level)
+           Shared.cloud_entry(entry),
+           Shared.parquet_compression(compression, level),
+           options
+         ) do
+      {:ok, _} -> :ok
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
+
+  @impl true
+  def to_parquet(%DF{} = ldf, %module{} = entry, compression, options, _streaming)
+      when module in [Memory.Entry | @cloud_entries] do
+    eager_df = compute(ldf)
+
+    Eager.to_parquet(eager_df, entry, compression, options, false)
+  end
+
+  @impl true
+  def to_parquet_partitioned(
+        %DF{} = ldf,
+        %Local.Entry{} = entry,
+        partition_by,
+        {compression, level},
+        options
+      ) do
+    case Native.lf_to_parquet_partitioned(
+           ldf.data,
+           entry.path,
+           partition_by,
+           Shared.parquet_compression(compression, level),
+           options
+         ) do
+      {:ok, _} -> :ok
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
+
+  @impl true
+  def to_parquet_partitioned(
+        %DF{} = ldf,
+        %module{} = entry,
+        partition_by,
+        {compression, level},
+        options
+      )
+      when module in @cloud_entries do
+    case Native.lf_to_parquet_partitioned_cloud(
+           ldf.data,
+           Shared.cloud_entry(entry),
+           partition_by,
+           Shared.parquet_compression(compression, level),
+           options
          ) do
       {:ok, _} -> :ok
       {:error, error} -> {:error, RuntimeError.exception(error)}
@@ -393,26 +690,33 @@ defmodule Explorer.PolarsBackend.LazyFrame do
   end
 
   @impl true
-  def to_parquet(%DF{} = ldf, %S3.Entry{} = entry, compression, _streaming = false) do
+  def to_parquet_partitioned(
+        %DF{} = ldf,
+        %Memory.Entry{} = entry,
+        partition_by,
+        compression,
+        options
+      ) do
     eager_df = compute(ldf)
 
-    Eager.to_parquet(eager_df, entry, compression, false)
+    Eager.to_parquet_partitioned(eager_df, entry, partition_by, compression, options)
   end
 
   @impl true
   def to_ipc(%DF{} = ldf, %Local.Entry{} = entry, {compression, _level}, streaming) do
-    case Native.lf_to_ipc(ldf.data, entry.path, Atom.to_string(compression), streaming) do
+    case Native.lf_to_ipc(ldf.data, entry.path, maybe_atom_to_string(compression), streaming) do
       {:ok, _} -> :ok
       {:error, error} -> {:error, RuntimeError.exception(error)}
     end
   end
 
   @impl true
-  def to_ipc(%DF{} = ldf, %S3.Entry{} = entry, {compression, _level}, _streaming = true) do
+  def to_ipc(%DF{} = ldf, %module{} = entry, {compression, _level}, _streaming = true)
+      when module in @cloud_entries do
     case Native.lf_to_ipc_cloud(
            ldf.data,
-           entry,
-           Atom.to_string(compression)
+           Shared.cloud_entry(entry),
+           maybe_atom_to_string(compression)
          ) do
       {:ok, _} -> :ok
       {:error, error} -> {:error, RuntimeError.exception(error)}
@@ -420,12 +724,99 @@ defmodule Explorer.PolarsBackend.LazyFrame do
   end
 
   @impl true
-  def to_ipc(%DF{} = ldf, %S3.Entry{} = entry, compression, _streaming = false) do
+  def to_ipc(%DF{} = ldf, %module{} = entry, compression, _streaming)
+      when module in [Memory.Entry | @cloud_entries] do
     eager_df = compute(ldf)
 
     Eager.to_ipc(eager_df, entry, compression, false)
   end
 
+  @impl true
+  def to_ipc_partitioned(%DF{} = ldf, %Local.Entry{} = entry, partition_by, {compression, _}) do
+    case Native.lf_to_ipc_partitioned(
+           ldf.data,
+           entry.path,
+           partition_by,
+           maybe_atom_to_string(compression)
+         ) do
+      {:ok, _} -> :ok
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
+
+  @impl true
+  def to_ipc_partitioned(%DF{} = ldf, %module{} = entry, partition_by, {compression, _})
+      when module in @cloud_entries do
+    case Native.lf_to_ipc_partitioned_cloud(
+           ldf.data,
+           Shared.cloud_entry(entry),
+           partition_by,
+           maybe_atom_to_string(compression)
+         ) do
+      {:ok, _} -> :ok
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
+
+  @impl true
+  def to_ipc_partitioned(%DF{} = ldf, %Memory.Entry{} = entry, partition_by, compression) do
+    eager_df = compute(ldf)
+
+    Eager.to_ipc_partitioned(eager_df, entry, partition_by, compression)
+  end
+
+  @impl true
+  def to_ipc_stream(%DF{} = ldf, %Local.Entry{} = entry, {compression, _level}, streaming) do
+    case Native.lf_to_ipc_stream(
+           ldf.data,
+           entry.path,
+           maybe_atom_to_string(compression),
+           streaming
+         ) do
+      {:ok, _} -> :ok
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
+
+  @impl true
+  def to_ipc_stream(%DF{} = ldf, %module{} = entry, {compression, _level}, streaming)
+      when module in [Memory.Entry | @cloud_entries] do
+    case Native.lf_to_ipc_stream_cloud(
+           ldf.data,
+           Shared.cloud_entry(entry),
+           maybe_atom_to_string(compression),
+           streaming
+         ) do
+      {:ok, _} -> :ok
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
+
+  @impl true
+  def to_ndjson(%DF{} = ldf, %Local.Entry{} = entry, compression, streaming) do
+    compression = Shared.text_compression(compression)
+
+    case Native.lf_to_ndjson(ldf.data, entry.path, compression, streaming) do
+      {:ok, _} -> :ok
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
+
+  @impl true
+  def to_ndjson(%DF{} = ldf, %module{} = entry, compression, streaming)
+      when module in [Memory.Entry | @cloud_entries] do
+    entry = Shared.cloud_entry(entry)
+    compression = Shared.text_compression(compression)
+
+    case Native.lf_to_ndjson_cloud(ldf.data, entry, compression, streaming) do
+      {:ok, _} -> :ok
+      {:error, error} -> {:error, RuntimeError.exception(error)}
+    end
+  end
+
+  defp maybe_atom_to_string(nil), do: nil
+  defp maybe_atom_to_string(atom) when is_atom(atom), do: Atom.to_string(atom)
+
   @impl true
   def filter_with(df, out_df, %LazySeries{} = lseries) do
     expression =
@@ -433,6 +824,7 @@ defmodule Explorer.PolarsBackend.LazyFrame do
         to_expr(lseries)
       else
         lseries
+        |> put_draw_groups(df.groups)
         |> to_expr()
         |> Native.expr_over(groups_exprs(df.groups))
       end
@@ -504,6 +896,7 @@ defmodule Explorer.PolarsBackend.LazyFrame do
     exprs =
       for {name, lazy_series} <- column_pairs do
         lazy_series
+        |> put_draw_groups(df.groups)
         |> to_expr()
         |> then(maybe_over_groups_fun)
         |> alias_expr(name)
@@ -512,6 +905,25 @@ defmodule Explorer.PolarsBackend.LazyFrame do
     Shared.apply_dataframe(df, out_df, :lf_mutate_with, [exprs])
   end
 
+  # The draws of a grouped dataframe take their stream from the keys of
+  # each group, so they don't depend on the order the groups are drawn in.
+  defp put_draw_groups(lazy_series, []), do: lazy_series
+
+  defp put_draw_groups(%LazySeries{op: :draw, args: [series, dist, params, seed]} = ls, groups) do
+    params = Enum.map(params, &put_draw_groups(&1, groups))
+    %{ls | args: [put_draw_groups(series, groups), dist, params, seed, groups]}
+  end
+
+  defp put_draw_groups(%LazySeries{args: args} = lazy_series, groups) do
+    %{lazy_series | args: Enum.map(args, &put_draw_groups(&1, groups))}
+  end
+
+  defp put_draw_groups(args, groups) when is_list(args) do
+    Enum.map(args, &put_draw_groups(&1, groups))
+  end
+
+  defp put_draw_groups(arg, _groups), do: arg
+
   @impl true
   def rename(%DF{} = df, %DF{} = out_df, pairs),
     do: Shared.apply_dataframe(df, out_df, :lf_rename_columns, [pairs])
@@ -551,7 +963,7 @@ defmodule Explorer.PolarsBackend.LazyFrame do
   def summarise_with(%DF{groups: groups} = df, %DF{} = out_df, column_pairs) do
     exprs =
       for {name, lazy_series} <- column_pairs do
-        original_expr = to_expr(lazy_series)
+        original_expr = lazy_series |> put_draw_groups(groups) |> to_expr()
         alias_expr(original_expr, name)
       end
 
@@ -639,11 +1051,12 @@ defmodule Explorer.PolarsBackend.LazyFrame do
     covariance: 3,
     nil_count: 1,
     dummies: 3,
-    dump_csv: 3,
+    dump_csv: 5,
     dump_ipc: 2,
     dump_ipc_stream: 2,
-    dump_ndjson: 1,
-    dump_parquet: 2,
+    dump_ndjson: 2,
+    dump_parquet: 3,
+    from_arrow_c_data: 2,
     mask: 2,
     n_rows: 1,
     pivot_wider: 5,
@@ -651,11 +1064,15 @@ defmodule Explorer.PolarsBackend.LazyFrame do
     put: 4,
     sample: 5,
     slice: 2,
-    to_ipc_stream: 3,
-    to_ndjson: 2,
+    stream_csv: 14,
+    stream_ipc_stream: 3,
+    stream_ndjson: 3,
+    stream_parquet: 3,
+    to_arrow_c_data: 1,
     to_rows: 2,
     to_rows_stream: 3,
-    transpose: 4
+    transpose: 4,
+    write_batches: 3
   ]
 
   for {fun, arity} <- not_available_funs do
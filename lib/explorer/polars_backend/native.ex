@@ -58,6 +58,8 @@ defmodule Explorer.PolarsBackend.Native do
   defstruct [:inner]
 
   def df_from_arrow_stream_pointer(_stream_ptr), do: err()
+  def df_to_arrow_c_data(_df), do: err()
+  def df_from_arrow_c_data(_array_ptr, _schema_ptr), do: err()
 
   def df_sort_by(_df, _by, _reverse, _maintain_order?, _multithreaded?, _nulls_last?, _groups),
     do: err()
@@ -76,9 +78,9 @@ defmodule Explorer.PolarsBackend.Native do
   def df_concat_columns(_dfs), do: err()
   def df_drop(_df, _name), do: err()
   def df_dtypes(_df), do: err()
-  def df_dump_csv(_df, _has_headers, _delimiter), do: err()
-  def df_dump_ndjson(_df), do: err()
-  def df_dump_parquet(_df, _compression), do: err()
+  def df_dump_csv(_df, _has_headers, _delimiter, _options, _compression), do: err()
+  def df_dump_ndjson(_df, _compression), do: err()
+  def df_dump_parquet(_df, _compression, _options), do: err()
   def df_dump_ipc(_df, _compression), do: err()
   def df_dump_ipc_stream(_df, _compression), do: err()
 
@@ -97,13 +99,57 @@ defmodule Explorer.PolarsBackend.Native do
         _encoding,
         _nil_vals,
         _parse_dates,
-        _eol_delimiter
+        _eol_delimiter,
+        _dialect
+      ),
+      do: err()
+
+  def df_from_csv_cloud(
+        _ex_entry,
+        _infer_schema_length,
+        _has_header,
+        _stop_after_n_rows,
+        _skip_rows,
+        _skip_rows_after_header,
+        _projection,
+        _sep,
+        _rechunk,
+        _columns,
+        _dtypes,
+        _encoding,
+        _nil_vals,
+        _parse_dates,
+        _eol_delimiter,
+        _dialect
+      ),
+      do: err()
+
+  def lf_from_csv_cloud(
+        _ex_entry,
+        _maybe_columns,
+        _infer_schema_length,
+        _has_header,
+        _stop_after_n_rows,
+        _skip_rows,
+        _skip_rows_after_header,
+        _sep,
+        _rechunk,
+        _dtypes,
+        _encoding,
+        _nil_vals,
+        _parse_dates,
+        _eol_delimiter,
+        _dialect,
+        _include_file_paths
       ),
       do: err()
 
   def df_from_ipc(_filename, _columns, _projection), do: err()
+  def df_from_ipc_cloud(_ex_entry, _columns, _projection), do: err()
   def df_from_ipc_stream(_filename, _columns, _projection), do: err()
+  def df_from_ipc_stream_cloud(_ex_entry, _columns, _projection), do: err()
   def df_from_ndjson(_filename, _infer_schema_length, _batch_size), do: err()
+  def df_from_ndjson_cloud(_ex_entry, _infer_schema_length, _batch_size), do: err()
 
   def df_from_parquet(
         _filename,
@@ -114,6 +160,15 @@ defmodule Explorer.PolarsBackend.Native do
       ),
       do: err()
 
+  def df_from_parquet_cloud(
+        _ex_entry,
+        _stop_after_n_rows,
+        _columns,
+        _projection,
+        _rechunk
+      ),
+      do: err()
+
   def df_from_series(_columns), do: err()
   def df_group_indices(_df, _column_names), do: err()
   def df_groups(_df, _column_names), do: err()
@@ -133,7 +188,8 @@ defmodule Explorer.PolarsBackend.Native do
         _encoding,
         _nil_vals,
         _parse_dates,
-        _eol_delimiter
+        _eol_delimiter,
+        _dialect
       ),
       do: err()
 
@@ -141,6 +197,7 @@ defmodule Explorer.PolarsBackend.Native do
   def df_load_ipc_stream(_binary, _columns, _projection), do: err()
   def df_load_ndjson(_binary, _infer_schema_length, _batch_size), do: err()
   def df_load_parquet(_binary), do: err()
+  def df_load_parquet_metadata(_binary), do: err()
 
   def df_mask(_df, _mask), do: err()
   def df_n_rows(_df), do: err()
@@ -156,18 +213,29 @@ defmodule Explorer.PolarsBackend.Native do
   def df_slice_by_indices(_df, _indices, _groups), do: err()
   def df_slice_by_series(_df, _series, _groups), do: err()
   def df_transpose(_df, _keep_names_as, _new_col_names), do: err()
-  def df_to_csv(_df, _filename, _has_headers, _delimiter), do: err()
-  def df_to_csv_cloud(_df, _ex_entry, _has_headers, _delimiter), do: err()
+  def df_to_csv(_df, _filename, _has_headers, _delimiter, _options, _compression), do: err()
+  def df_to_csv_cloud(_df, _ex_entry, _has_headers, _delimiter, _options, _compression),
+    do: err()
   def df_to_dummies(_df, _columns), do: err()
   def df_to_ipc(_df, _filename, _compression), do: err()
   def df_to_ipc_cloud(_df, _ex_entry, _compression), do: err()
+  def df_to_ipc_partitioned(_df, _dirname, _partition_by, _compression), do: err()
+  def df_to_ipc_partitioned_cloud(_df, _ex_entry, _partition_by, _compression), do: err()
   def df_to_ipc_stream(_df, _filename, _compression), do: err()
   def df_to_ipc_stream_cloud(_df, _ex_entry, _compression), do: err()
   def df_lazy(_df), do: err()
-  def df_to_ndjson(_df, _filename), do: err()
-  def df_to_ndjson_cloud(_df, _ex_entry), do: err()
-  def df_to_parquet(_df, _filename, _compression), do: err()
-  def df_to_parquet_cloud(_df, _ex_entry, _compression), do: err()
+  def df_to_ndjson(_df, _filename, _compression), do: err()
+  def df_to_ndjson_cloud(_df, _ex_entry, _compression), do: err()
+  def df_parquet_metadata(_filename), do: err()
+  def df_parquet_metadata_cloud(_ex_entry), do: err()
+  def df_to_parquet(_df, _filename, _compression, _options), do: err()
+  def df_to_parquet_cloud(_df, _ex_entry, _compression, _options), do: err()
+  def df_to_parquet_partitioned(_df, _dirname, _partition_by, _compression, _options),
+    do: err()
+
+  def df_to_parquet_partitioned_cloud(_df, _ex_entry, _partition_by, _compression, _options),
+    do: err()
+
   def df_width(_df), do: err()
   def df_nil_count(_df), do: err()
   def df_re_dtype(_pattern), do: err()
@@ -183,11 +251,11 @@ defmodule Explorer.PolarsBackend.Native do
   def df_draw_from_erlang(_seed, _shape, _rate, _nr_of_draws), do: err()
   def df_draw_from_exp(_seed, _rate, _nr_of_draws), do: err()
   def df_draw_from_fisher_snedecor(_seed, _freedom_1, _freedom_2, _nr_of_draws), do: err()
-  def df_draw_from_gamma(_seed, _mean, _variance, _nr_of_draws), do: err()
+  def df_draw_from_gamma(_seed, _shape, _rate, _nr_of_draws), do: err()
   def df_draw_from_gumbel(_seed, _location, _scale, _nr_of_draws), do: err()
   def df_draw_from_inverse_gamma(_seed, _shape, _rate, _nr_of_draws), do: err()
   def df_draw_from_laplace(_seed, _location, _scale, _nr_of_draws), do: err()
-  def df_draw_from_log_normal(_seed, _mean, _variance, _nr_of_draws), do: err()
+  def df_draw_from_log_normal(_seed, _location, _scale, _nr_of_draws), do: err()
   def df_draw_from_negative_binomial(_seed, _r, _p, _nr_of_draws), do: err()
   def df_draw_from_normal(_seed, _mu, _sigma, _nr_of_draws), do: err()
   def df_draw_from_pareto(_seed, _scale, _shape, _nr_of_draws), do: err()
@@ -196,8 +264,22 @@ defmodule Explorer.PolarsBackend.Native do
   def df_draw_from_uniform(_seed, _min, _max, _nr_of_draws), do: err()
   def df_draw_from_weibull(_seed, _shape, _scale, _nr_of_draws), do: err()
 
-  # # Multivariate distributions
-  # def df_draw_from_dirichlet(_seed, _alphas, _nr_of_draws), do: err()
+  # Discrete distributions
+  def df_draw_from_bernoulli(_seed, _p, _nr_of_draws), do: err()
+  def df_draw_from_binomial(_seed, _p, _n, _nr_of_draws), do: err()
+  def df_draw_from_categorical(_seed, _prob_mass, _nr_of_draws), do: err()
+  def df_draw_from_discrete_uniform(_seed, _min, _max, _nr_of_draws), do: err()
+  def df_draw_from_geometric(_seed, _p, _nr_of_draws), do: err()
+
+  def df_draw_from_hypergeometric(_seed, _pop_size, _success_states, _sample_size, _nr_of_draws),
+    do: err()
+
+  def df_draw_from_poisson(_seed, _lambda, _nr_of_draws), do: err()
+
+  # Multivariate distributions
+  def df_draw_from_dirichlet(_seed, _alphas, _nr_of_draws), do: err()
+  def df_draw_from_multinomial(_seed, _p, _n, _nr_of_draws), do: err()
+  def df_draw_from_multivariate_normal(_seed, _mean, _cov, _nr_of_draws), do: err()
 
   # Expressions (for lazy queries)
   @multi_arity_expressions [slice: 2, slice: 3, log: 1, log: 2]
@@ -233,6 +315,34 @@ defmodule Explorer.PolarsBackend.Native do
   def expr_string(_string), do: err()
   def expr_struct(_map), do: err()
 
+  # Random draws (one per row)
+  def expr_draw_from_beta(_seed, _groups, _a, _b), do: err()
+  def expr_draw_from_cauchy(_seed, _groups, _median, _scale), do: err()
+  def expr_draw_from_chi(_seed, _groups, _freedom), do: err()
+  def expr_draw_from_chi_squared(_seed, _groups, _freedom), do: err()
+  def expr_draw_from_erlang(_seed, _groups, _shape, _rate), do: err()
+  def expr_draw_from_exp(_seed, _groups, _rate), do: err()
+  def expr_draw_from_fisher_snedecor(_seed, _groups, _freedom_1, _freedom_2), do: err()
+  def expr_draw_from_gamma(_seed, _groups, _shape, _rate), do: err()
+  def expr_draw_from_gumbel(_seed, _groups, _location, _scale), do: err()
+  def expr_draw_from_inverse_gamma(_seed, _groups, _shape, _rate), do: err()
+  def expr_draw_from_laplace(_seed, _groups, _location, _scale), do: err()
+  def expr_draw_from_log_normal(_seed, _groups, _location, _scale), do: err()
+  def expr_draw_from_normal(_seed, _groups, _mu, _sigma), do: err()
+  def expr_draw_from_pareto(_seed, _groups, _scale, _shape), do: err()
+  def expr_draw_from_students_t(_seed, _groups, _location, _scale, _freedom), do: err()
+  def expr_draw_from_triangular(_seed, _groups, _min, _max, _mode), do: err()
+  def expr_draw_from_uniform(_seed, _groups, _min, _max), do: err()
+  def expr_draw_from_weibull(_seed, _groups, _shape, _scale), do: err()
+  def expr_draw_from_bernoulli(_seed, _groups, _p), do: err()
+  def expr_draw_from_binomial(_seed, _groups, _p, _n), do: err()
+  def expr_draw_from_discrete_uniform(_seed, _groups, _min, _max), do: err()
+  def expr_draw_from_geometric(_seed, _groups, _p), do: err()
+  def expr_draw_from_hypergeometric(_seed, _groups, _pop_size, _success_states, _sample_size),
+    do: err()
+  def expr_draw_from_negative_binomial(_seed, _groups, _r, _p), do: err()
+  def expr_draw_from_poisson(_seed, _groups, _lambda), do: err()
+
   # LazyFrame
   def lf_compute(_df), do: err()
   def lf_describe_plan(_df, _optimized), do: err()
@@ -246,10 +356,45 @@ defmodule Explorer.PolarsBackend.Native do
   def lf_slice(_df, _offset, _length, _groups), do: err()
   def lf_explode(_df, _columns), do: err()
   def lf_unnest(_df, _columns), do: err()
-  def lf_from_ipc(_filename), do: err()
+  def lf_from_ipc(_filename, _hive_partitioning, _include_file_paths), do: err()
+
+  def lf_from_ipc_cloud(
+        _ex_entry,
+        _stop_after_n_rows,
+        _maybe_columns,
+        _hive_partitioning,
+        _include_file_paths
+      ),
+      do: err()
+
   def lf_from_ndjson(_filename, _infer_schema_length, _batch_size), do: err()
-  def lf_from_parquet(_filename, _stop_after_n_rows, _maybe_columns), do: err()
-  def lf_from_parquet_cloud(_ex_s3_entry, _stop_after_n_rows, _maybe_columns), do: err()
+
+  def lf_from_ndjson_cloud(
+        _ex_entry,
+        _stop_after_n_rows,
+        _maybe_columns,
+        _infer_schema_length,
+        _batch_size
+      ),
+      do: err()
+
+  def lf_from_parquet(
+        _filename,
+        _stop_after_n_rows,
+        _maybe_columns,
+        _hive_partitioning,
+        _include_file_paths
+      ),
+      do: err()
+
+  def lf_from_parquet_cloud(
+        _ex_s3_entry,
+        _stop_after_n_rows,
+        _maybe_columns,
+        _hive_partitioning,
+        _include_file_paths
+      ),
+      do: err()
 
   def lf_from_csv(
         _filename,
@@ -264,7 +409,9 @@ defmodule Explorer.PolarsBackend.Native do
         _encoding,
         _nil_vals,
         _parse_dates,
-        _eol_delimiter
+        _eol_delimiter,
+        _dialect,
+        _include_file_paths
       ),
       do: err()
 
@@ -296,11 +443,24 @@ defmodule Explorer.PolarsBackend.Native do
   def lf_join(_df, _other, _left_on, _right_on, _how, _suffix), do: err()
   def lf_concat_rows(_dfs), do: err()
   def lf_concat_columns(_ldfs), do: err()
-  def lf_to_parquet(_df, _filename, _compression, _streaming), do: err()
-  def lf_to_parquet_cloud(_df, _filename, _compression), do: err()
+  def lf_to_parquet(_df, _filename, _compression, _options, _streaming), do: err()
+  def lf_to_parquet_cloud(_df, _filename, _compression, _options), do: err()
+  def lf_to_parquet_partitioned(_df, _dirname, _partition_by, _compression, _options),
+    do: err()
+
+  def lf_to_parquet_partitioned_cloud(_df, _ex_entry, _partition_by, _compression, _options),
+    do: err()
+
   def lf_to_ipc(_df, _filename, _compression, _streaming), do: err()
   def lf_to_ipc_cloud(_df, _cloud_entry, _compression), do: err()
-  def lf_to_csv(_df, _filename, _header, _delimiter, _streaming), do: err()
+  def lf_to_ipc_partitioned(_df, _dirname, _partition_by, _compression), do: err()
+  def lf_to_ipc_partitioned_cloud(_df, _ex_entry, _partition_by, _compression), do: err()
+  def lf_to_ipc_stream(_df, _filename, _compression, _streaming), do: err()
+  def lf_to_ipc_stream_cloud(_df, _ex_entry, _compression, _streaming), do: err()
+  def lf_to_ndjson(_df, _filename, _compression, _streaming), do: err()
+  def lf_to_ndjson_cloud(_df, _ex_entry, _compression, _streaming), do: err()
+  def lf_to_csv(_df, _filename, _header, _delimiter, _options, _compression, _streaming),
+    do: err()
   def lf_sql(_df, _sql_string, _table_name), do: err()
 
   # Series
@@ -442,6 +602,8 @@ defmodule Explorer.PolarsBackend.Native do
   def s_to_list(_s), do: err()
   def s_downcase(_s), do: err()
   def s_to_iovec(_s), do: err()
+  def s_to_arrow_c_data(_s), do: err()
+  def s_from_arrow_c_data(_array_ptr, _schema_ptr), do: err()
   def s_upcase(_s), do: err()
   def s_unordered_distinct(_s), do: err()
   def s_frequencies(_s), do: err()
@@ -510,58 +672,231 @@ defmodule Explorer.PolarsBackend.Native do
   def s_json_decode(_s, _dtype), do: err()
   def s_json_path_match(_s, _json_path), do: err()
 
+  # Non-parametric density estimation
+  def s_kde(_s, _points, _kernel, _bandwidth), do: err()
+  def s_ecdf(_s, _points), do: err()
+
+  # Hypothesis tests
+  def s_t_test(_left, _right, _equal_variance), do: err()
+  def s_mann_whitney_u(_left, _right), do: err()
+  def s_ks_one_sample(_cdf), do: err()
+  def s_ks_two_sample(_left, _right), do: err()
+  def s_chi_squared_gof(_observed, _expected), do: err()
+
+  def df_chi_squared_independence_test(_df, _row_column, _col_column, _counts_column),
+    do: err()
+
+  # Distribution fitting
+  def s_fit_beta(_s), do: err()
+  def s_fit_exp(_s), do: err()
+  def s_fit_gamma(_s), do: err()
+  def s_fit_log_normal(_s), do: err()
+  def s_fit_normal(_s), do: err()
+  def s_fit_poisson(_s), do: err()
+  def s_fit_weibull(_s), do: err()
+
   # Statistical functions
 
-  def s_beta_pdf(_s, _shape_a, _shape_b), do: err()
-  def s_beta_cdf(_s, _shape_a, _shape_b), do: err()
-  def s_beta_inverse_cdf(_s, _shape_a, _shape_b), do: err()
+  def s_beta_pdf(_s, _a, _b), do: err()
+  def s_beta_ln_pdf(_s, _a, _b), do: err()
+  def s_beta_cdf(_s, _a, _b), do: err()
+  def s_beta_sf(_s, _a, _b), do: err()
+  def s_beta_inverse_cdf(_s, _a, _b), do: err()
 
   def s_cauchy_pdf(_s, _location, _scale), do: err()
+  def s_cauchy_ln_pdf(_s, _location, _scale), do: err()
   def s_cauchy_cdf(_s, _location, _scale), do: err()
+  def s_cauchy_sf(_s, _location, _scale), do: err()
   def s_cauchy_inverse_cdf(_s, _location, _scale), do: err()
 
   def s_chi_pdf(_s, _freedom), do: err()
+  def s_chi_ln_pdf(_s, _freedom), do: err()
   def s_chi_cdf(_s, _freedom), do: err()
+  def s_chi_sf(_s, _freedom), do: err()
   def s_chi_inverse_cdf(_s, _freedom), do: err()
 
   def s_chi_squared_pdf(_s, _freedom), do: err()
+  def s_chi_squared_ln_pdf(_s, _freedom), do: err()
   def s_chi_squared_cdf(_s, _freedom), do: err()
+  def s_chi_squared_sf(_s, _freedom), do: err()
   def s_chi_squared_inverse_cdf(_s, _freedom), do: err()
 
   # The Dirac distribution doesn't have a PDF
   def s_dirac_cdf(_s, _v), do: err()
+  def s_dirac_sf(_s, _v), do: err()
   def s_dirac_inverse_cdf(_s, _v), do: err()
 
   def s_erlang_pdf(_s, _shape, _rate), do: err()
+  def s_erlang_ln_pdf(_s, _shape, _rate), do: err()
   def s_erlang_cdf(_s, _shape, _rate), do: err()
+  def s_erlang_sf(_s, _shape, _rate), do: err()
   def s_erlang_inverse_cdf(_s, _shape, _rate), do: err()
 
   def s_exp_pdf(_s, _rate), do: err()
+  def s_exp_ln_pdf(_s, _rate), do: err()
   def s_exp_cdf(_s, _rate), do: err()
+  def s_exp_sf(_s, _rate), do: err()
   def s_exp_inverse_cdf(_s, _rate), do: err()
 
   def s_fisher_snedecor_pdf(_s, _freedom_1, _freedom_2), do: err()
+  def s_fisher_snedecor_ln_pdf(_s, _freedom_1, _freedom_2), do: err()
   def s_fisher_snedecor_cdf(_s, _freedom_1, _freedom_2), do: err()
+  def s_fisher_snedecor_sf(_s, _freedom_1, _freedom_2), do: err()
   def s_fisher_snedecor_inverse_cdf(_s, _freedom_1, _freedom_2), do: err()
 
   def s_gamma_pdf(_s, _shape, _rate), do: err()
+  def s_gamma_ln_pdf(_s, _shape, _rate), do: err()
   def s_gamma_cdf(_s, _shape, _rate), do: err()
+  def s_gamma_sf(_s, _shape, _rate), do: err()
   def s_gamma_inverse_cdf(_s, _shape, _rate), do: err()
 
   def s_gumbel_pdf(_s, _location, _scale), do: err()
+  def s_gumbel_ln_pdf(_s, _location, _scale), do: err()
   def s_gumbel_cdf(_s, _location, _scale), do: err()
+  def s_gumbel_sf(_s, _location, _scale), do: err()
   def s_gumbel_inverse_cdf(_s, _location, _scale), do: err()
 
   def s_inverse_gamma_pdf(_s, _shape, _rate), do: err()
+  def s_inverse_gamma_ln_pdf(_s, _shape, _rate), do: err()
   def s_inverse_gamma_cdf(_s, _shape, _rate), do: err()
+  def s_inverse_gamma_sf(_s, _shape, _rate), do: err()
   def s_inverse_gamma_inverse_cdf(_s, _shape, _rate), do: err()
 
   def s_laplace_pdf(_s, _location, _scale), do: err()
+  def s_laplace_ln_pdf(_s, _location, _scale), do: err()
   def s_laplace_cdf(_s, _location, _scale), do: err()
+  def s_laplace_sf(_s, _location, _scale), do: err()
   def s_laplace_inverse_cdf(_s, _location, _scale), do: err()
 
+  def s_log_normal_pdf(_s, _location, _scale), do: err()
+  def s_log_normal_ln_pdf(_s, _location, _scale), do: err()
+  def s_log_normal_cdf(_s, _location, _scale), do: err()
+  def s_log_normal_sf(_s, _location, _scale), do: err()
+  def s_log_normal_inverse_cdf(_s, _location, _scale), do: err()
+
+  def s_normal_pdf(_s, _mu, _sigma), do: err()
+  def s_normal_ln_pdf(_s, _mu, _sigma), do: err()
+  def s_normal_cdf(_s, _mu, _sigma), do: err()
+  def s_normal_sf(_s, _mu, _sigma), do: err()
+  def s_normal_inverse_cdf(_s, _mu, _sigma), do: err()
+
+  def s_pareto_pdf(_s, _scale, _shape), do: err()
+  def s_pareto_ln_pdf(_s, _scale, _shape), do: err()
+  def s_pareto_cdf(_s, _scale, _shape), do: err()
+  def s_pareto_sf(_s, _scale, _shape), do: err()
+  def s_pareto_inverse_cdf(_s, _scale, _shape), do: err()
+
+  def s_students_t_pdf(_s, _location, _scale, _freedom), do: err()
+  def s_students_t_ln_pdf(_s, _location, _scale, _freedom), do: err()
+  def s_students_t_cdf(_s, _location, _scale, _freedom), do: err()
+  def s_students_t_sf(_s, _location, _scale, _freedom), do: err()
+  def s_students_t_inverse_cdf(_s, _location, _scale, _freedom), do: err()
+
+  def s_triangular_pdf(_s, _min, _max, _mode), do: err()
+  def s_triangular_ln_pdf(_s, _min, _max, _mode), do: err()
+  def s_triangular_cdf(_s, _min, _max, _mode), do: err()
+  def s_triangular_sf(_s, _min, _max, _mode), do: err()
+  def s_triangular_inverse_cdf(_s, _min, _max, _mode), do: err()
+
+  def s_uniform_pdf(_s, _min, _max), do: err()
+  def s_uniform_ln_pdf(_s, _min, _max), do: err()
+  def s_uniform_cdf(_s, _min, _max), do: err()
+  def s_uniform_sf(_s, _min, _max), do: err()
+  def s_uniform_inverse_cdf(_s, _min, _max), do: err()
+
+  def s_weibull_pdf(_s, _shape, _scale), do: err()
+  def s_weibull_ln_pdf(_s, _shape, _scale), do: err()
+  def s_weibull_cdf(_s, _shape, _scale), do: err()
+  def s_weibull_sf(_s, _shape, _scale), do: err()
+  def s_weibull_inverse_cdf(_s, _shape, _scale), do: err()
+
+  # Discrete distributions
+
+  def s_bernoulli_pmf(_s, _p), do: err()
+  def s_bernoulli_ln_pmf(_s, _p), do: err()
+  def s_bernoulli_cdf(_s, _p), do: err()
+  def s_bernoulli_sf(_s, _p), do: err()
+  def s_bernoulli_inverse_cdf(_s, _p), do: err()
+
+  def s_binomial_pmf(_s, _p, _n), do: err()
+  def s_binomial_ln_pmf(_s, _p, _n), do: err()
+  def s_binomial_cdf(_s, _p, _n), do: err()
+  def s_binomial_sf(_s, _p, _n), do: err()
+  def s_binomial_inverse_cdf(_s, _p, _n), do: err()
+
+  def s_categorical_pmf(_s, _prob_mass), do: err()
+  def s_categorical_ln_pmf(_s, _prob_mass), do: err()
+  def s_categorical_cdf(_s, _prob_mass), do: err()
+  def s_categorical_sf(_s, _prob_mass), do: err()
+  def s_categorical_inverse_cdf(_s, _prob_mass), do: err()
+
+  def s_discrete_uniform_pmf(_s, _min, _max), do: err()
+  def s_discrete_uniform_ln_pmf(_s, _min, _max), do: err()
+  def s_discrete_uniform_cdf(_s, _min, _max), do: err()
+  def s_discrete_uniform_sf(_s, _min, _max), do: err()
+  def s_discrete_uniform_inverse_cdf(_s, _min, _max), do: err()
+
+  def s_geometric_pmf(_s, _p), do: err()
+  def s_geometric_ln_pmf(_s, _p), do: err()
+  def s_geometric_cdf(_s, _p), do: err()
+  def s_geometric_sf(_s, _p), do: err()
+  def s_geometric_inverse_cdf(_s, _p), do: err()
+
+  def s_hypergeometric_pmf(_s, _pop_size, _success_states, _sample_size), do: err()
+  def s_hypergeometric_ln_pmf(_s, _pop_size, _success_states, _sample_size), do: err()
+  def s_hypergeometric_cdf(_s, _pop_size, _success_states, _sample_size), do: err()
+  def s_hypergeometric_sf(_s, _pop_size, _success_states, _sample_size), do: err()
+  def s_hypergeometric_inverse_cdf(_s, _pop_size, _success_states, _sample_size), do: err()
+
+  def s_negative_binomial_pmf(_s, _r, _p), do: err()
+  def s_negative_binomial_ln_pmf(_s, _r, _p), do: err()
+  def s_negative_binomial_cdf(_s, _r, _p), do: err()
+  def s_negative_binomial_sf(_s, _r, _p), do: err()
+  def s_negative_binomial_inverse_cdf(_s, _r, _p), do: err()
+
+  def s_poisson_pmf(_s, _lambda), do: err()
+  def s_poisson_ln_pmf(_s, _lambda), do: err()
+  def s_poisson_cdf(_s, _lambda), do: err()
+  def s_poisson_sf(_s, _lambda), do: err()
+  def s_poisson_inverse_cdf(_s, _lambda), do: err()
+
   def message_on_gc(_pid, _payload), do: err()
   def is_message_on_gc(_term), do: err()
 
+  def memory_store_new(), do: err()
+
+  def csv_batched_reader_new(
+        _filename,
+        _batch_size,
+        _infer_schema_length,
+        _has_header,
+        _skip_rows,
+        _skip_rows_after_header,
+        _projection,
+        _delimiter,
+        _column_names,
+        _dtypes,
+        _encoding,
+        _null_vals,
+        _parse_dates,
+        _eol_delimiter,
+        _dialect
+      ),
+      do: err()
+
+  def ndjson_batched_reader_new(_filename, _batch_size, _infer_schema_length), do: err()
+  def parquet_batched_reader_new(_filename, _batch_size, _columns, _projection), do: err()
+  def ipc_stream_batched_reader_new(_filename, _batch_size, _columns, _projection), do: err()
+  def reader_next_batch(_reader), do: err()
+
+  def parquet_writer_new(_entry, _compression, _options), do: err()
+  def ipc_writer_new(_entry, _compression), do: err()
+  def ipc_stream_writer_new(_entry, _compression), do: err()
+  def csv_writer_new(_entry, _include_headers, _delimiter, _options, _compression), do: err()
+  def ndjson_writer_new(_entry, _compression), do: err()
+  def writer_write_batch(_writer, _df), do: err()
+  def writer_close(_writer), do: err()
+  def writer_abort(_writer), do: err()
+
   defp err, do: :erlang.nif_error(:nif_not_loaded)
 end
@@ -38,6 +38,18 @@ defmodule Explorer.PolarsBackend.Series do
   @impl true
   def to_iovec(series), do: Shared.apply_series(series, :s_to_iovec)
 
+  @impl true
+  def to_arrow_c_data(series) do
+    {owner, array, schema} = Shared.apply_series(series, :s_to_arrow_c_data)
+    %{array: array, schema: schema, owner: owner}
+  end
+
+  @impl true
+  def from_arrow_c_data(array, schema) do
+    Shared.apply(:s_from_arrow_c_data, [array, schema])
+    |> Shared.create_series()
+  end
+
   @impl true
   def cast(%Series{dtype: :string} = series, {:naive_datetime, precision}),
     do: Shared.apply_series(series, :s_strptime, [nil, precision])
@@ -121,6 +133,35 @@ defmodule Explorer.PolarsBackend.Series do
     Shared.apply_series(series, :s_sample_frac, [frac, replacement, shuffle, seed])
   end
 
+  @impl true
+  def draw(series, distribution, params, out_dtype, seed) do
+    series_lazy = Explorer.Backend.LazySeries.unbacked(:column, ["series"], series.dtype)
+
+    {draw_params, df_args} =
+      params
+      |> Enum.with_index()
+      |> Enum.map_reduce([{"series", series}], fn
+        {%Series{} = param, index}, df_args ->
+          name = "param_#{index}"
+          column = Explorer.Backend.LazySeries.unbacked(:column, [name], param.dtype)
+          {column, [{name, param} | df_args]}
+
+        {param, _index}, df_args ->
+          {param, df_args}
+      end)
+
+    df = Explorer.PolarsBackend.DataFrame.from_series(Enum.reverse(df_args))
+    draw_args = [series_lazy, distribution, draw_params, seed]
+    draw = Explorer.Backend.LazySeries.unbacked(:draw, draw_args, out_dtype)
+
+    out_dtypes = Map.put(df.dtypes, "draw", out_dtype)
+    out_names = df.names ++ ["draw"]
+    out_df = %{df | dtypes: out_dtypes, names: out_names}
+
+    Explorer.PolarsBackend.DataFrame.mutate_with(df, out_df, [{"draw", draw}])
+    |> Explorer.PolarsBackend.DataFrame.pull("draw")
+  end
+
   @impl true
   def rank(series, method, descending, seed) do
     Shared.apply_series(series, :s_rank, [method, descending, seed])
@@ -265,6 +265,30 @@ defmodule Explorer.PolarsBackend.Shared do
 
   def parquet_compression(algorithm, _) when algorithm in ~w(snappy lz4raw)a, do: algorithm
 
+  def text_compression({nil, _}), do: nil
+  def text_compression({algorithm, level}) when algorithm in ~w(gzip zstd)a,
+    do: {algorithm, level}
+
+  @doc """
+  The CSV dialect as expected by the readers, with the quote char as a byte.
+  """
+  def csv_dialect(%{quote_char: quote_char} = dialect) do
+    case quote_char do
+      nil -> dialect
+      <<char::utf8>> -> %{dialect | quote_char: char}
+    end
+  end
+
+  @doc """
+  Tags the entry with its object store, as expected by the "*_cloud" functions.
+  """
+  def cloud_entry(%FSS.S3.Entry{} = entry), do: {:s3, entry}
+  def cloud_entry(%Explorer.FSS.GCS.Entry{} = entry), do: {:gcs, entry}
+  def cloud_entry(%Explorer.FSS.Azure.Entry{} = entry), do: {:azure, entry}
+  def cloud_entry(%FSS.HTTP.Entry{} = entry), do: {:http, entry}
+  def cloud_entry(%FSS.Local.Entry{} = entry), do: {:local, entry}
+  def cloud_entry(%Explorer.FSS.Memory.Entry{} = entry), do: {:memory, entry}
+
   @doc """
   Builds and returns a path for a new file.
 
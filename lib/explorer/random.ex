@@ -1,6 +1,10 @@
 defmodule Explorer.Random do
   @moduledoc """
   Functions to efficiently generate random numbers from random number distributions.
+
+  The functions return `{:ok, dataframe}` with the draws, or `{:error, exception}`
+  if the parameters are invalid for the distribution. Their `!` variants return
+  the dataframe and raise in case of error.
   """
   import Explorer.RandomDistBuilder, only: [defdraw: 1]
   alias Explorer.DataFrame
@@ -10,21 +14,25 @@ defmodule Explorer.Random do
   @doc """
   Draw `nr_of_draws` values from a Normal distribution.
 
-  Returns a dataframe with the following columns:
+  The dataframe has the following columns:
     - `:draw` (type: `:u64`) - the number of the draw
     - `:x` (type: `:f64`) - the values
   """
-  @spec draw_from_normal(float(), float(), integer()) :: DataFrame.t()
+  @spec draw_from_normal(float(), float(), integer()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  @spec draw_from_normal!(float(), float(), integer()) :: DataFrame.t()
   defdraw draw_from_normal({mu, :float}, {sigma, :float}, nr_of_draws)
 
   @doc """
   Draw `nr_of_draws` values from a Beta distribution.
 
-  Returns a dataframe with the following columns:
+  The dataframe has the following columns:
     - `:draw` (type: `:u64`) - the number of the draw
     - `:x` (type: `:f64`) - the values
   """
-  @spec draw_from_beta(float(), float(), integer()) :: DataFrame.t()
+  @spec draw_from_beta(float(), float(), integer()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  @spec draw_from_beta!(float(), float(), integer()) :: DataFrame.t()
   defdraw draw_from_beta(a, b, nr_of_draws)
 
 
@@ -41,54 +49,164 @@ defmodule Explorer.Random do
   @doc """
   Draw `nr_of_draws` values from a Cauchy distribution.
 
-  Returns a dataframe with the following columns:
+  The dataframe has the following columns:
     - `:draw` (type: `:u64`) - the number of the draw
     - `:x` (type: `:f64`) - the values
   """
-  @spec draw_from_cauchy(float(), float(), integer()) :: DataFrame.t()
+  @spec draw_from_cauchy(float(), float(), integer()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  @spec draw_from_cauchy!(float(), float(), integer()) :: DataFrame.t()
   defdraw draw_from_cauchy(median, scale, nr_of_draws)
 
   @doc """
   Draw `nr_of_draws` values from a Geometric distribution.
 
-  Returns a dataframe with the following columns:
+  The dataframe has the following columns:
     - `:draw` (type: `:u64`) - the number of the draw
-    - `:x` (type: `:f64`) - the values
+    - `:x` (type: `:u64`) - the values
   """
-  @spec draw_from_geometric(float(), integer()) :: DataFrame.t()
-  defdraw draw_from_geometric(p, nr_of_draws)
+  @spec draw_from_geometric(float(), integer()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  @spec draw_from_geometric!(float(), integer()) :: DataFrame.t()
+  defdraw draw_from_geometric({p, :float}, nr_of_draws)
 
   @doc """
   Draw `nr_of_draws` values from a Hypergeometric distribution.
 
-  Returns a dataframe with the following columns:
+  The dataframe has the following columns:
     - `:draw` (type: `:u64`) - the number of the draw
-    - `:x` (type: `:f64`) - the values
+    - `:x` (type: `:u64`) - the values
   """
-  @spec draw_from_hypergeometric(float(), float(), float(), integer()) :: DataFrame.t()
+  @spec draw_from_hypergeometric(integer(), integer(), integer(), integer()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  @spec draw_from_hypergeometric!(integer(), integer(), integer(), integer()) :: DataFrame.t()
   defdraw draw_from_hypergeometric(pop_size, success_states, sample_size, nr_of_draws)
 
   @doc """
-  Draw `nr_of_draws` values from a LogNormal distribution.
+  Draw `nr_of_draws` values from a Poisson distribution.
+
+  The dataframe has the following columns:
+    - `:draw` (type: `:u64`) - the number of the draw
+    - `:x` (type: `:u64`) - the values
+  """
+  @spec draw_from_poisson(float(), integer()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  @spec draw_from_poisson!(float(), integer()) :: DataFrame.t()
+  defdraw draw_from_poisson({lambda, :float}, nr_of_draws)
+
+  @doc """
+  Draw `nr_of_draws` values from a Binomial distribution
+  with probability `p` and `n` trials.
+
+  The dataframe has the following columns:
+    - `:draw` (type: `:u64`) - the number of the draw
+    - `:x` (type: `:u64`) - the values
+  """
+  @spec draw_from_binomial(float(), integer(), integer()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  @spec draw_from_binomial!(float(), integer(), integer()) :: DataFrame.t()
+  defdraw draw_from_binomial({p, :float}, n, nr_of_draws)
+
+  @doc """
+  Draw `nr_of_draws` values from a Bernoulli distribution.
+
+  The dataframe has the following columns:
+    - `:draw` (type: `:u64`) - the number of the draw
+    - `:x` (type: `:u64`) - the values (either `0` or `1`)
+  """
+  @spec draw_from_bernoulli(float(), integer()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  @spec draw_from_bernoulli!(float(), integer()) :: DataFrame.t()
+  defdraw draw_from_bernoulli({p, :float}, nr_of_draws)
+
+  @doc """
+  Draw `nr_of_draws` values from a Categorical distribution.
+  The `prob_mass` list doesn't need to be normalized.
+
+  The dataframe has the following columns:
+    - `:draw` (type: `:u64`) - the number of the draw
+    - `:x` (type: `:u64`) - the index of the category (starting at `0`)
+  """
+  @spec draw_from_categorical(list(float()), integer()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  @spec draw_from_categorical!(list(float()), integer()) :: DataFrame.t()
+  defdraw draw_from_categorical({prob_mass, :list_of_floats}, nr_of_draws)
+
+  @doc """
+  Draw `nr_of_draws` values from a Discrete Uniform distribution
+  between `min` and `max` (inclusive).
 
-  Returns a dataframe with the following columns:
+  The dataframe has the following columns:
+    - `:draw` (type: `:u64`) - the number of the draw
+    - `:x` (type: `:s64`) - the values
+  """
+  @spec draw_from_discrete_uniform(integer(), integer(), integer()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  @spec draw_from_discrete_uniform!(integer(), integer(), integer()) :: DataFrame.t()
+  defdraw draw_from_discrete_uniform(min, max, nr_of_draws)
+
+  @doc """
+  Draw `nr_of_draws` values from a LogNormal distribution, where `location`
+  and `scale` are the mean and the standard deviation of the logarithm of the values.
+
+  The dataframe has the following columns:
     - `:draw` (type: `:u64`) - the number of the draw
     - `:x` (type: `:f64`) - the values
   """
-  @spec draw_from_log_normal(float(), float(), integer()) :: DataFrame.t()
-  defdraw draw_from_log_normal(mean, variance, nr_of_draws)
+  @spec draw_from_log_normal(float(), float(), integer()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  @spec draw_from_log_normal!(float(), float(), integer()) :: DataFrame.t()
+  defdraw draw_from_log_normal(location, scale, nr_of_draws)
 
-  # @doc """
-  # Draw `nr_of_draws` values from a Dirichlet distribution with parameters `alpha`.
+  @doc """
+  Draw `nr_of_draws` values from a Dirichlet distribution with parameters `alphas`.
 
-  # Returns a dataframe with the following columns:
-  #   - `:draw` (type: `:u64`) - the number of the draw
-  #   - `:x1`, `:x2`, ..., `:"x#\{k}"`
-  #     (total of `k` columns, where `k = length(alpha), type: `:f64`) -
-  #     the generated values for each draw
-  # """
-  # @spec draw_from_dirichlet(list(float()), integer()) :: DataFrame.t()
-  # defdraw draw_from_dirichlet(alphas, nr_of_draws)
+  The dataframe has the following columns:
+    - `:draw` (type: `:u64`) - the number of the draw
+    - `:x_0`, `:x_1`, ..., `:"x_#\{k - 1}"`
+      (total of `k` columns, where `k = length(alphas)`, type: `:f64`) -
+      the generated values for each draw
+  """
+  @spec draw_from_dirichlet(list(float()), integer()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  @spec draw_from_dirichlet!(list(float()), integer()) :: DataFrame.t()
+  defdraw draw_from_dirichlet({alphas, :list_of_floats}, nr_of_draws)
+
+  @doc """
+  Draw `nr_of_draws` values from a Multinomial distribution
+  with `n` trials and event probabilities `p`.
+
+  The dataframe has the following columns:
+    - `:draw` (type: `:u64`) - the number of the draw
+    - `:x_0`, `:x_1`, ..., `:"x_#\{k - 1}"`
+      (total of `k` columns, where `k = length(p)`, type: `:u64`) -
+      the number of occurrences of each event
+  """
+  @spec draw_from_multinomial(list(float()), integer(), integer()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  @spec draw_from_multinomial!(list(float()), integer(), integer()) :: DataFrame.t()
+  defdraw draw_from_multinomial({p, :list_of_floats}, n, nr_of_draws)
+
+  @doc """
+  Draw `nr_of_draws` values from a Multivariate Normal distribution.
+  The covariance matrix `cov` is given as a list of rows and must be
+  symmetric and positive-definite.
+
+  The dataframe has the following columns:
+    - `:draw` (type: `:u64`) - the number of the draw
+    - `:x_0`, `:x_1`, ..., `:"x_#\{k - 1}"`
+      (total of `k` columns, where `k = length(mean)`, type: `:f64`) -
+      the generated values for each draw
+  """
+  @spec draw_from_multivariate_normal(list(float()), list(list(float())), integer()) ::
+          {:ok, DataFrame.t()} | {:error, Exception.t()}
+  @spec draw_from_multivariate_normal!(list(float()), list(list(float())), integer()) ::
+          DataFrame.t()
+  defdraw draw_from_multivariate_normal(
+            {mean, :list_of_floats},
+            {cov, :matrix_of_floats},
+            nr_of_draws
+          )
 
   # @doc """
   # Draw `nr_of_draws` 3D points uniformly from a Unit Sphere.
@@ -4,13 +4,15 @@ defmodule Explorer.RandomDistBuilder do
   # A utility to make it easier to bind native distributions.
   # This macro takes care of generating the random seed that will
   # be given to the rust functions so that things can be made reproducible.
+  # It defines the function, which returns `{:ok, df}` or `{:error, exception}`,
+  # and its bang variant, which raises instead.
   defmacro defdraw(call) do
     {f, args} = Macro.decompose_call(call)
 
     arg_variables =
       for arg <- args do
         case arg do
-          {arg_var, arg_type} when arg_type in [:list_of_floats, :float, :integer] ->
+          {arg_var, arg_type} when arg_type in [:matrix_of_floats, :list_of_floats, :float, :integer] ->
             arg_var
 
           {arg_var, _} ->
@@ -22,12 +24,21 @@ defmodule Explorer.RandomDistBuilder do
       end
 
     df_func_name = :"df_#{f}"
+    bang_f = :"#{f}!"
     seed = Macro.var(:seed, __MODULE__)
     df_args = [seed | arg_variables]
 
     type_conversions =
       for {arg_var, arg_type} <- args do
         case arg_type do
+          :matrix_of_floats ->
+            [
+              quote do
+                unquote(arg_var) =
+                  Enum.map(unquote(arg_var), fn row -> Enum.map(row, &:erlang.float/1) end)
+              end
+            ]
+
           :list_of_floats ->
             [
               quote do
@@ -56,14 +67,24 @@ defmodule Explorer.RandomDistBuilder do
         # Convert some of the numbers to floats
         unquote_splicing(type_conversions)
 
-        {:ok, polars_df} =
-          apply(
-            Explorer.PolarsBackend.Native,
-            unquote(df_func_name),
-            unquote(df_args)
-          )
+        case apply(Explorer.PolarsBackend.Native, unquote(df_func_name), unquote(df_args)) do
+          {:ok, polars_df} ->
+            {:ok, Explorer.PolarsBackend.Shared.create_dataframe!(polars_df)}
 
-        Explorer.PolarsBackend.Shared.create_dataframe!(polars_df)
+          # Invalid distribution parameters
+          {:error, error} ->
+            {:error, ArgumentError.exception(error)}
+        end
+      end
+
+      @doc """
+      Similar to `#{unquote(f)}/#{unquote(length(args))}`, but raises in case of error.
+      """
+      def unquote(bang_f)(unquote_splicing(arg_variables)) do
+        case unquote(f)(unquote_splicing(arg_variables)) do
+          {:ok, df} -> df
+          {:error, error} -> raise error
+        end
       end
     end
   end
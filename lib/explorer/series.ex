@@ -192,6 +192,14 @@ defmodule Explorer.Series do
   @type lazy_t :: %Series{data: Explorer.Backend.LazySeries.t(), dtype: dtype()}
 
   @type non_finite :: :nan | :infinity | :neg_infinity
+
+  @typedoc """
+  The addresses of Arrow C Data Interface structs, as exported by `to_arrow_c_data/1`.
+
+  The structs are owned by `:owner`, which must be kept referenced while they are used.
+  """
+  @type arrow_c_data :: %{array: non_neg_integer(), schema: non_neg_integer(), owner: term()}
+
   @type inferable_scalar ::
           number()
           | non_finite()
@@ -958,6 +966,69 @@ defmodule Explorer.Series do
   @spec to_binary(series :: Series.t()) :: binary
   def to_binary(series), do: series |> to_iovec() |> IO.iodata_to_binary()
 
+  @doc """
+  Exports the series as Arrow C Data Interface structs.
+
+  This allows other NIFs in the same VM, such as ADBC drivers, to read the
+  series without a copy, unlike `to_list/1` or `to_iovec/1`. The data is
+  only copied when the series has more than one chunk, as its chunks are
+  then concatenated into one.
+
+  It returns a map with the `:array` and `:schema` addresses, as integers,
+  of the `ArrowArray` and `ArrowSchema` structs, to be given to the other NIFs.
+  The structs are released once the `:owner` of the map is garbage collected,
+  so it must be kept referenced while the structs are used. As in the C Data
+  Interface, the consumer of the array moves it out of its address.
+
+  See `from_arrow_c_data/2` for the import of such structs.
+
+  ## Examples
+
+      c_data = Explorer.Series.to_arrow_c_data(series)
+      SomeNIF.consume(c_data.array, c_data.schema)
+
+  """
+  @doc type: :conversion
+  @spec to_arrow_c_data(series :: Series.t()) :: arrow_c_data()
+  def to_arrow_c_data(series), do: apply_series(series, :to_arrow_c_data, [], false)
+
+  @doc """
+  Imports a series from Arrow C Data Interface structs, without copying their data.
+
+  It expects a map with the `:array` and `:schema` addresses, as integers,
+  of an `ArrowArray` and its `ArrowSchema`, such as the map returned by
+  `to_arrow_c_data/1`. The name of the series is the name of the schema.
+
+  The array is moved out of its address, so it can only be imported once,
+  and importing it again raises. The schema is only read. The addresses must
+  be of valid structs in the memory of the VM, otherwise the VM may crash.
+
+  ## Options
+
+    * `:backend` - The backend to allocate the series on.
+
+  ## Examples
+
+      iex> series = Explorer.Series.from_list([1, 2, nil])
+      iex> c_data = Explorer.Series.to_arrow_c_data(series)
+      iex> series = Explorer.Series.from_arrow_c_data(c_data)
+      iex> Explorer.Series.to_list(series)
+      [1, 2, nil]
+
+  """
+  @doc type: :conversion
+  @spec from_arrow_c_data(
+          c_data :: %{array: non_neg_integer(), schema: non_neg_integer()},
+          opts :: Keyword.t()
+        ) :: Series.t()
+  def from_arrow_c_data(%{array: array, schema: schema}, opts \\ [])
+      when K.and(is_integer(array), is_integer(schema)) do
+    opts = Keyword.validate!(opts, [:backend])
+    backend = backend_from_options!(opts)
+
+    backend.from_arrow_c_data(array, schema)
+  end
+
   @doc """
   Converts a series to a `t:Nx.Tensor.t/0`.
 
@@ -1672,6 +1743,152 @@ defmodule Explorer.Series do
     sample(series, 1.0, seed: opts[:seed], shuffle: true)
   end
 
+  # The parameters of each distribution, in the order the backend takes them,
+  # and the dtype of the draws.
+  @draw_distributions %{
+    beta: {[:a, :b], {:f, 64}},
+    cauchy: {[:median, :scale], {:f, 64}},
+    chi: {[:freedom], {:f, 64}},
+    chi_squared: {[:freedom], {:f, 64}},
+    erlang: {[:shape, :rate], {:f, 64}},
+    exp: {[:rate], {:f, 64}},
+    fisher_snedecor: {[:freedom_1, :freedom_2], {:f, 64}},
+    gamma: {[:shape, :rate], {:f, 64}},
+    gumbel: {[:location, :scale], {:f, 64}},
+    inverse_gamma: {[:shape, :rate], {:f, 64}},
+    laplace: {[:location, :scale], {:f, 64}},
+    log_normal: {[:location, :scale], {:f, 64}},
+    normal: {[:mu, :sigma], {:f, 64}},
+    pareto: {[:scale, :shape], {:f, 64}},
+    students_t: {[:location, :scale, :freedom], {:f, 64}},
+    triangular: {[:min, :max, :mode], {:f, 64}},
+    uniform: {[:min, :max], {:f, 64}},
+    weibull: {[:shape, :scale], {:f, 64}},
+    bernoulli: {[:p], {:u, 64}},
+    binomial: {[:p, :n], {:u, 64}},
+    discrete_uniform: {[:min, :max], {:s, 64}},
+    geometric: {[:p], {:u, 64}},
+    hypergeometric: {[:pop_size, :success_states, :sample_size], {:u, 64}},
+    negative_binomial: {[:r, :p], {:u, 64}},
+    poisson: {[:lambda], {:u, 64}}
+  }
+
+  @doc """
+  Draws one value from a random distribution for each element of the series.
+
+  Only the size of `series` is used, so that the draws can be put next to it,
+  for example as noise in `Explorer.DataFrame.mutate/2`. The parameters of the
+  distribution are given as a keyword list, and each of them can be a number
+  or a series with one value per element. Elements with a nil parameter have a
+  nil draw.
+
+  The supported distributions and their parameters are:
+
+    * `:beta` - `:a` and `:b`
+    * `:cauchy` - `:median` and `:scale`
+    * `:chi` - `:freedom`
+    * `:chi_squared` - `:freedom`
+    * `:erlang` - `:shape` and `:rate`
+    * `:exp` - `:rate`
+    * `:fisher_snedecor` - `:freedom_1` and `:freedom_2`
+    * `:gamma` - `:shape` and `:rate`
+    * `:gumbel` - `:location` and `:scale`
+    * `:inverse_gamma` - `:shape` and `:rate`
+    * `:laplace` - `:location` and `:scale`
+    * `:log_normal` - `:location` and `:scale`
+    * `:normal` - `:mu` and `:sigma`
+    * `:pareto` - `:scale` and `:shape`
+    * `:students_t` - `:location`, `:scale` and `:freedom`
+    * `:triangular` - `:min`, `:max` and `:mode`
+    * `:uniform` - `:min` and `:max`
+    * `:weibull` - `:shape` and `:scale`
+    * `:bernoulli` - `:p`
+    * `:binomial` - `:p` and `:n`
+    * `:discrete_uniform` - `:min` and `:max`
+    * `:geometric` - `:p`
+    * `:hypergeometric` - `:pop_size`, `:success_states` and `:sample_size`
+    * `:negative_binomial` - `:r` and `:p`
+    * `:poisson` - `:lambda`
+
+  Continuous distributions draw `{:f, 64}` values, the discrete uniform
+  distribution draws `{:s, 64}` values and the other discrete distributions
+  draw `{:u, 64}` values.
+
+  Within a grouped dataframe, each group draws from its own random stream.
+
+  ## Options
+
+    * `:seed` - An integer to be used as a random seed. If nil,
+      a random value between 0 and 2^64 − 1 will be used. (default: nil)
+
+  ## Examples
+
+  Adding normal noise whose standard deviation comes from another column:
+
+      df = Explorer.DataFrame.new(x: [1.0, 2.0, 3.0], sd: [0.1, 0.5, 1.0])
+
+      Explorer.DataFrame.mutate(df,
+        noise: draw(x, :normal, [mu: 0.0, sigma: sd], seed: 42)
+      )
+
+  """
+  @doc type: :element_wise
+  @spec draw(
+          series :: Series.t(),
+          distribution :: atom(),
+          params :: Keyword.t(Series.t() | number())
+        ) :: Series.t()
+  @spec draw(
+          series :: Series.t(),
+          distribution :: atom(),
+          params :: Keyword.t(Series.t() | number()),
+          opts :: Keyword.t()
+        ) :: Series.t()
+  def draw(series, distribution, params, opts \\ []) do
+    opts = Keyword.validate!(opts, seed: nil)
+
+    {names, dtype} =
+      case @draw_distributions do
+        %{^distribution => names_and_dtype} ->
+          names_and_dtype
+
+        %{} ->
+          raise ArgumentError,
+                "unsupported distribution #{inspect(distribution)}, expected one of: " <>
+                  inspect(@draw_distributions |> Map.keys() |> Enum.sort())
+      end
+
+    params = Keyword.validate!(params, names)
+
+    values =
+      for name <- names do
+        case params[name] do
+          nil ->
+            raise ArgumentError,
+                  "missing parameter #{inspect(name)} for the " <>
+                    "#{inspect(distribution)} distribution"
+
+          %Series{dtype: param_dtype} = value when is_numeric_dtype(param_dtype) ->
+            value
+
+          %Series{dtype: param_dtype} ->
+            dtype_error("draw/4", param_dtype, @numeric_dtypes)
+
+          value when is_number(value) ->
+            value
+
+          value ->
+            raise ArgumentError,
+                  "expected parameter #{inspect(name)} to be a number or a series, " <>
+                    "got: #{inspect(value)}"
+        end
+      end
+
+    seed = opts[:seed] || :rand.uniform(2 ** 64 - 1)
+
+    apply_series(series, :draw, [distribution, values, dtype, seed])
+  end
+
   @doc """
   Takes every *n*th value in this series, returned as a new series.
 
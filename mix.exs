@@ -82,6 +82,16 @@ defmodule Explorer.MixProject do
           Explorer.Duration,
           Explorer.TensorFrame
         ],
+        "Object stores": [
+          Explorer.FSS.GCS,
+          Explorer.FSS.GCS.Config,
+          Explorer.FSS.GCS.Entry,
+          Explorer.FSS.Azure,
+          Explorer.FSS.Azure.Config,
+          Explorer.FSS.Azure.Entry,
+          Explorer.FSS.Memory,
+          Explorer.FSS.Memory.Entry
+        ],
         Backends: [
           Explorer.Backend,
           Explorer.Backend.DataFrame,
@@ -0,0 +1,102 @@
+// Series and dataframes are shared with other NIFs in the same VM, such
+// as ADBC drivers, as Arrow C Data Interface structs, without copying
+// their buffers. A dataframe is shared as a struct array of its columns.
+//
+// An array has a single chunk, so a series in many chunks is rechunked
+// first, which copies it. A series in a single chunk is not copied.
+//
+// The structs are exported into a resource, and only their addresses
+// are given to Elixir, so the resource must be kept alive while the
+// other NIFs read them.
+
+use polars::export::arrow::ffi;
+use polars::prelude::*;
+use rustler::{Resource, ResourceArc};
+
+use crate::ExplorerError;
+
+// The head of an `ArrowArray`, as laid out by the C Data Interface, so
+// its release callback can be read. The callback is null once the array
+// was released, or moved out by an import.
+#[repr(C)]
+#[allow(dead_code)]
+struct ArrowArrayHead {
+    length: i64,
+    null_count: i64,
+    offset: i64,
+    n_buffers: i64,
+    n_children: i64,
+    buffers: *mut *const std::ffi::c_void,
+    children: *mut *mut ffi::ArrowArray,
+    dictionary: *mut ffi::ArrowArray,
+    release: Option<unsafe extern "C" fn(*mut ffi::ArrowArray)>,
+}
+
+pub struct ExArrowCData {
+    // Boxed, so their addresses stay the same while they are shared.
+    array: Box<ffi::ArrowArray>,
+    schema: Box<ffi::ArrowSchema>,
+}
+
+// The schema is only read through its address, and both structs are
+// released by their own callbacks when the resource is dropped.
+unsafe impl Send for ExArrowCData {}
+unsafe impl Sync for ExArrowCData {}
+
+#[rustler::resource_impl]
+impl Resource for ExArrowCData {}
+
+impl ExArrowCData {
+    pub fn export(series: &Series) -> Self {
+        // Only copies the buffers when there is more than one chunk.
+        let series = series.rechunk();
+        let field = series.field().to_arrow(CompatLevel::newest());
+        let array = series.to_arrow(0, CompatLevel::newest());
+
+        ExArrowCData {
+            array: Box::new(ffi::export_array_to_c(array)),
+            schema: Box::new(ffi::export_field_to_c(&field)),
+        }
+    }
+
+    // Returns the resource, and the addresses of its array and schema.
+    pub fn into_resource(self) -> (ResourceArc<ExArrowCData>, u64, u64) {
+        let array_ptr = &*self.array as *const ffi::ArrowArray as u64;
+        let schema_ptr = &*self.schema as *const ffi::ArrowSchema as u64;
+
+        (ResourceArc::new(self), array_ptr, schema_ptr)
+    }
+}
+
+// Imports the array at `array_ptr`. As the C Data Interface expects, the
+// array is moved out, and the producer is left with a released array,
+// while the schema is only read and is still released by its producer.
+//
+// The addresses are not checked beyond being non null, so they must be
+// of valid structs, like the ones of `df_from_arrow_stream_pointer`. A
+// released array, such as one that was already imported, is an error.
+pub fn import(array_ptr: u64, schema_ptr: u64) -> Result<Series, ExplorerError> {
+    let array_ptr = array_ptr as *mut ffi::ArrowArray;
+    let schema_ptr = schema_ptr as *const ffi::ArrowSchema;
+
+    let schema = unsafe { schema_ptr.as_ref() }
+        .ok_or(ExplorerError::Other("Incorrect schema pointer".into()))?;
+
+    if array_ptr.is_null() {
+        return Err(ExplorerError::Other("Incorrect array pointer".into()));
+    }
+
+    let head = unsafe { &*(array_ptr as *const ArrowArrayHead) };
+
+    if head.release.is_none() {
+        return Err(ExplorerError::Other(
+            "the array was already released, or imported, by another consumer".into(),
+        ));
+    }
+
+    let field = unsafe { ffi::import_field_from_c(schema) }?;
+    let array = unsafe { std::ptr::replace(array_ptr, ffi::ArrowArray::empty()) };
+    let array = unsafe { ffi::import_array_from_c(array, field.dtype.clone()) }?;
+
+    Ok(Series::try_from((&field, array))?)
+}
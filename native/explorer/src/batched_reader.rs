@@ -0,0 +1,313 @@
+// The batched readers return the rows of a file in dataframes of
+// `batch_size` rows, one per call, so a file can be processed in
+// Elixir without holding it whole in memory.
+//
+// Each source gives chunks in its own sizes (the CSV chunks, the
+// record batches of an IPC stream or the row groups of a Parquet
+// file), which are then stacked or split to the size of the batch.
+//
+// A compressed NDJSON file is decompressed as it is read. The CSV
+// reader would decompress the whole file into memory, so compressed
+// CSV files are refused instead.
+
+use polars::export::arrow::io::ipc::read as ipc_read;
+use polars::prelude::*;
+use std::fs::File;
+use std::io::{BufReader, Read};
+
+#[cfg(feature = "ndjson")]
+use std::io::{BufRead, Cursor};
+#[cfg(feature = "ndjson")]
+use std::num::NonZeroUsize;
+
+use crate::ExplorerError;
+
+pub enum BatchSource {
+    Csv(OwnedBatchedCsvReader),
+    #[cfg(feature = "ndjson")]
+    Ndjson {
+        reader: BufReader<Box<dyn Read + Send>>,
+        // Inferred before the first chunk, so all the chunks agree on it.
+        schema: SchemaRef,
+        // Whether the schema was inferred from only the first rows,
+        // in which case the later rows may have keys that it lacks.
+        partial_schema: bool,
+    },
+    IpcStream {
+        reader: ipc_read::StreamReader<BufReader<File>>,
+        fields: Vec<ArrowField>,
+    },
+    // The batched Parquet reader is async, but a local file is read
+    // without waiting on anything, so it is run on the calling thread.
+    Parquet(BatchedParquetReader),
+}
+
+impl BatchSource {
+    #[cfg(feature = "ndjson")]
+    pub fn ndjson(
+        filename: &str,
+        infer_schema_length: Option<usize>,
+    ) -> Result<Self, ExplorerError> {
+        // The file is opened again to read it from the start, since
+        // a decompressed stream can't be rewound.
+        let mut reader = BufReader::new(open_decompressed(filename)?);
+        let infer_schema_length = infer_schema_length.and_then(NonZeroUsize::new);
+        let schema = polars::io::ndjson::infer_schema(&mut reader, infer_schema_length)?;
+
+        Ok(BatchSource::Ndjson {
+            reader: BufReader::new(open_decompressed(filename)?),
+            schema: Arc::new(schema),
+            partial_schema: infer_schema_length.is_some(),
+        })
+    }
+
+    pub fn ipc_stream(
+        filename: &str,
+        columns: Option<Vec<String>>,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self, ExplorerError> {
+        let mut reader = BufReader::new(File::open(filename)?);
+        let metadata = ipc_read::read_stream_metadata(&mut reader)?;
+
+        let projection = match columns {
+            Some(columns) => Some(columns_to_projection(&columns, &metadata.schema)?),
+            None => projection,
+        };
+
+        let reader = ipc_read::StreamReader::new(reader, metadata, projection);
+        let fields = reader.schema().fields.clone();
+
+        Ok(BatchSource::IpcStream { reader, fields })
+    }
+
+    pub fn parquet(
+        filename: &str,
+        columns: Option<Vec<String>>,
+        projection: Option<Vec<usize>>,
+        batch_size: usize,
+    ) -> Result<Self, ExplorerError> {
+        let mut reader = ParquetReader::new(File::open(filename)?);
+
+        // The batched reader only takes a projection.
+        let projection = match columns {
+            Some(columns) => Some(columns_to_projection(&columns, &reader.schema()?)?),
+            None => projection,
+        };
+
+        let reader = reader
+            .with_projection(projection)
+            // A zero batch size is rejected when the batched reader is created.
+            .batched(batch_size.max(1))?;
+
+        Ok(BatchSource::Parquet(reader))
+    }
+
+    // Returns the next chunk, of about `size` rows, or `None` at the end.
+    fn next_chunk(&mut self, size: usize) -> Result<Option<DataFrame>, ExplorerError> {
+        match self {
+            BatchSource::Csv(reader) => stack_chunks(reader.next_batches(1)?),
+            #[cfg(feature = "ndjson")]
+            BatchSource::Ndjson {
+                reader,
+                schema,
+                partial_schema,
+            } => {
+                let mut lines = vec![];
+                let mut n_lines = 0;
+                let mut line = vec![];
+
+                while n_lines < size && reader.read_until(b'\n', &mut line)? > 0 {
+                    if !line.iter().all(u8::is_ascii_whitespace) {
+                        lines.append(&mut line);
+                        if lines.last() != Some(&b'\n') {
+                            lines.push(b'\n');
+                        }
+                        n_lines += 1;
+                    }
+                    line.clear();
+                }
+
+                if n_lines == 0 {
+                    return Ok(None);
+                }
+
+                if *partial_schema {
+                    check_ndjson_keys(&lines, schema)?;
+                }
+
+                let df = JsonReader::new(Cursor::new(lines))
+                    .with_json_format(JsonFormat::JsonLines)
+                    .with_schema(schema.clone())
+                    .finish()?;
+
+                Ok(Some(df))
+            }
+            BatchSource::IpcStream { reader, fields } => match reader.next() {
+                Some(state) => match state? {
+                    ipc_read::StreamState::Some(batch) => {
+                        Ok(Some(DataFrame::try_from((batch, fields.as_slice()))?))
+                    }
+                    ipc_read::StreamState::Waiting => Ok(None),
+                },
+                None => Ok(None),
+            },
+            BatchSource::Parquet(reader) => {
+                let chunks = futures::executor::block_on(reader.next_batches(1))?;
+                stack_chunks(chunks)
+            }
+        }
+    }
+}
+
+// The magic bytes of gzip, zlib and zstd, as detected by Polars.
+fn compressed_magic(filename: &str) -> Result<Option<[u8; 4]>, ExplorerError> {
+    let mut magic = Vec::with_capacity(4);
+    File::open(filename)?.take(4).read_to_end(&mut magic)?;
+
+    if is_compressed(&magic) {
+        let mut bytes = [0; 4];
+        bytes[..magic.len()].copy_from_slice(&magic);
+        Ok(Some(bytes))
+    } else {
+        Ok(None)
+    }
+}
+
+// Only a single file is checked, and not a glob pattern or a directory.
+#[cfg(feature = "ndjson")]
+pub fn is_compressed_file(filename: &str) -> Result<bool, ExplorerError> {
+    if std::path::Path::new(filename).is_file() {
+        Ok(compressed_magic(filename)?.is_some())
+    } else {
+        Ok(false)
+    }
+}
+
+pub fn ensure_uncompressed(filename: &str) -> Result<(), ExplorerError> {
+    match compressed_magic(filename)? {
+        Some(_) => Err(ExplorerError::Other(format!(
+            "compressed input is not supported by the streaming readers: {filename}"
+        ))),
+        None => Ok(()),
+    }
+}
+
+#[cfg(feature = "ndjson")]
+fn open_decompressed(filename: &str) -> Result<Box<dyn Read + Send>, ExplorerError> {
+    let file = File::open(filename)?;
+
+    let reader: Box<dyn Read + Send> = match compressed_magic(filename)? {
+        Some([0x1f, 0x8b, ..]) => Box::new(flate2::read::MultiGzDecoder::new(file)),
+        Some([0x28, 0xb5, 0x2f, 0xfd]) => Box::new(zstd::Decoder::new(file)?),
+        Some(_) => Box::new(flate2::read::ZlibDecoder::new(file)),
+        None => Box::new(file),
+    };
+
+    Ok(reader)
+}
+
+// The JSON reader drops the keys that are not in the schema, so
+// they are looked for in the chunk, as they would be lost otherwise.
+#[cfg(feature = "ndjson")]
+fn check_ndjson_keys(lines: &[u8], schema: &Schema) -> Result<(), ExplorerError> {
+    let chunk_schema = polars::io::ndjson::infer_schema(&mut Cursor::new(lines), None)?;
+
+    match chunk_schema
+        .iter_names()
+        .find(|name| !schema.contains(name.as_str()))
+    {
+        Some(name) => Err(ExplorerError::Other(format!(
+            "the key \"{name}\" is not in the schema inferred from the first rows \
+            of the file, increase :infer_schema_length to include it"
+        ))),
+        None => Ok(()),
+    }
+}
+
+fn stack_chunks(chunks: Option<Vec<DataFrame>>) -> Result<Option<DataFrame>, ExplorerError> {
+    match chunks {
+        Some(mut chunks) if !chunks.is_empty() => {
+            let mut df = chunks.remove(0);
+            for chunk in chunks {
+                df.vstack_mut(&chunk)?;
+            }
+            Ok(Some(df))
+        }
+        _ => Ok(None),
+    }
+}
+
+// Like Polars, a column that is not in the schema is an error.
+fn columns_to_projection(
+    columns: &[String],
+    schema: &ArrowSchema,
+) -> Result<Vec<usize>, ExplorerError> {
+    columns
+        .iter()
+        .map(|column| {
+            schema
+                .fields
+                .iter()
+                .position(|field| field.name.as_str() == column.as_str())
+                .ok_or_else(|| ExplorerError::Other(format!("column \"{column}\" not found")))
+        })
+        .collect()
+}
+
+pub struct BatchedReader {
+    source: BatchSource,
+    batch_size: usize,
+    // The rows read from the source that were not returned yet.
+    pending: Option<DataFrame>,
+    finished: bool,
+}
+
+impl BatchedReader {
+    pub fn new(source: BatchSource, batch_size: usize) -> Result<Self, ExplorerError> {
+        if batch_size == 0 {
+            return Err(ExplorerError::Other(
+                "\"batch_size\" expected to be non zero.".to_string(),
+            ));
+        }
+
+        Ok(BatchedReader {
+            source,
+            batch_size,
+            pending: None,
+            finished: false,
+        })
+    }
+
+    // Returns the next `batch_size` rows, or fewer in the last batch.
+    // Returns `None` once all the rows were returned.
+    pub fn next_batch(&mut self) -> Result<Option<DataFrame>, ExplorerError> {
+        while !self.finished && self.pending_height() < self.batch_size {
+            match self.source.next_chunk(self.batch_size)? {
+                Some(chunk) => match self.pending.as_mut() {
+                    Some(pending) => {
+                        pending.vstack_mut(&chunk)?;
+                    }
+                    None => self.pending = Some(chunk),
+                },
+                None => self.finished = true,
+            }
+        }
+
+        let mut batch = match self.pending.take() {
+            Some(df) if df.height() > self.batch_size => {
+                let (batch, rest) = df.split_at(self.batch_size as i64);
+                self.pending = Some(rest);
+                batch
+            }
+            Some(df) if df.height() > 0 => df,
+            _ => return Ok(None),
+        };
+
+        batch.as_single_chunk_par();
+        Ok(Some(batch))
+    }
+
+    fn pending_height(&self) -> usize {
+        self.pending.as_ref().map_or(0, DataFrame::height)
+    }
+}
@@ -0,0 +1,335 @@
+// The batched writers write the dataframes given one at a time to
+// a single file, or object, so a stream of dataframes can be written
+// without concatenating them in memory. The file is completed when
+// the writer is closed, or removed when the writer is aborted, fails
+// to close, or is dropped without being closed.
+//
+// Most formats need the schema before the first write, so the Polars
+// writers are configured when the batched writer is opened, and only
+// started with the schema of the first batch. The following batches
+// must have the same schema.
+
+use polars::export::arrow::io::ipc::write as ipc_write;
+use polars::io::csv::write::BatchedWriter as CsvBatchedWriter;
+use polars::io::ipc::BatchedWriter as IpcBatchedWriter;
+#[cfg(feature = "ndjson")]
+use polars::io::json::BatchedWriter as JsonBatchedWriter;
+use polars::io::parquet::write::BatchedWriter as ParquetBatchedWriter;
+use polars::prelude::*;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::compressed_writer::CompressedWriter;
+use crate::datatypes::ExTextCompression;
+use crate::ExplorerError;
+
+#[cfg(feature = "cloud")]
+use crate::cloud_writer::CloudWriter;
+
+// Where the bytes of a batched writer go.
+pub enum Sink {
+    File(BufWriter<File>, PathBuf),
+    #[cfg(feature = "cloud")]
+    Cloud(CloudWriter),
+    // Anything still written after an abort is refused.
+    Aborted,
+}
+
+impl Sink {
+    // On error, the sink is left in place, so it can still be aborted.
+    fn finish(&mut self) -> Result<(), ExplorerError> {
+        match self {
+            Sink::File(writer, _path) => Ok(writer.flush()?),
+            #[cfg(feature = "cloud")]
+            Sink::Cloud(writer) => {
+                let _ = writer.finish()?;
+                Ok(())
+            }
+            Sink::Aborted => Err(aborted_error()),
+        }
+    }
+
+    // Removes the file, or cancels the upload, so no partial file is left.
+    fn abort(&mut self) -> Result<(), ExplorerError> {
+        match std::mem::replace(self, Sink::Aborted) {
+            Sink::File(writer, path) => {
+                // The file is closed before it is removed.
+                drop(writer.into_parts());
+                Ok(std::fs::remove_file(path)?)
+            }
+            #[cfg(feature = "cloud")]
+            Sink::Cloud(mut writer) => writer.abort(),
+            Sink::Aborted => Ok(()),
+        }
+    }
+}
+
+impl Write for Sink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Sink::File(writer, _path) => writer.write(buf),
+            #[cfg(feature = "cloud")]
+            Sink::Cloud(writer) => writer.write(buf),
+            Sink::Aborted => Err(std::io::Error::other(aborted_error().to_string())),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Sink::File(writer, _path) => writer.flush(),
+            #[cfg(feature = "cloud")]
+            Sink::Cloud(writer) => writer.flush(),
+            Sink::Aborted => Ok(()),
+        }
+    }
+}
+
+// The Polars writers own their writer and do not give it back, so they
+// write through this handle, and the batched writer keeps another one
+// to finish the compression and the sink when it is closed.
+#[derive(Clone)]
+pub struct SharedWriter(Arc<Mutex<Option<CompressedWriter<Sink>>>>);
+
+impl SharedWriter {
+    pub fn new(sink: Sink, compression: Option<ExTextCompression>) -> Result<Self, ExplorerError> {
+        let writer = CompressedWriter::new(sink, compression)?;
+        Ok(SharedWriter(Arc::new(Mutex::new(Some(writer)))))
+    }
+
+    // The writer is only taken once it is finished, so that
+    // a writer that failed to finish can still be aborted.
+    fn finish(&self) -> Result<(), ExplorerError> {
+        let mut output = self.lock()?;
+
+        if let Some(writer) = output.as_mut() {
+            writer.try_finish()?;
+            writer.get_mut().finish()?;
+        }
+
+        output.take();
+        Ok(())
+    }
+
+    // The sink is aborted before the compression is dropped, so
+    // the end of the compressed stream is never written.
+    fn abort(&self) -> Result<(), ExplorerError> {
+        match self.lock()?.take() {
+            Some(mut writer) => writer.get_mut().abort(),
+            None => Ok(()),
+        }
+    }
+
+    fn lock(&self) -> std::io::Result<MutexGuard<'_, Option<CompressedWriter<Sink>>>> {
+        self.0
+            .lock()
+            .map_err(|_| std::io::Error::other("the writer is poisoned"))
+    }
+}
+
+impl Write for SharedWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self.lock()?.as_mut() {
+            Some(writer) => writer.write(buf),
+            None => Err(std::io::Error::other("the writer is closed")),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self.lock()?.as_mut() {
+            Some(writer) => writer.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+// The Polars writers, as configured when the batched writer is opened.
+pub enum FormatWriter {
+    // The Polars batched writer ignores the row group size of the
+    // Parquet writer, so it is kept along with it.
+    Parquet(ParquetWriter<SharedWriter>, Option<usize>),
+    Ipc(IpcWriter<SharedWriter>),
+    IpcStream(ipc_write::StreamWriter<SharedWriter>),
+    Csv(CsvWriter<SharedWriter>),
+    #[cfg(feature = "ndjson")]
+    Ndjson(JsonBatchedWriter<SharedWriter>),
+}
+
+impl FormatWriter {
+    fn start(self, schema: &Schema) -> Result<StartedWriter, ExplorerError> {
+        match self {
+            FormatWriter::Parquet(writer, row_group_size) => Ok(StartedWriter::Parquet(
+                writer.batched(schema)?,
+                row_group_size,
+            )),
+            FormatWriter::Ipc(writer) => Ok(StartedWriter::Ipc(writer.batched(schema)?)),
+            FormatWriter::IpcStream(mut writer) => {
+                writer.start(&schema.to_arrow(CompatLevel::newest()), None)?;
+                Ok(StartedWriter::IpcStream(writer))
+            }
+            FormatWriter::Csv(writer) => Ok(StartedWriter::Csv(writer.batched(schema)?)),
+            #[cfg(feature = "ndjson")]
+            FormatWriter::Ndjson(writer) => Ok(StartedWriter::Ndjson(writer)),
+        }
+    }
+}
+
+// The Polars writers, once started with the schema of the first batch.
+enum StartedWriter {
+    Parquet(ParquetBatchedWriter<SharedWriter>, Option<usize>),
+    Ipc(IpcBatchedWriter<SharedWriter>),
+    IpcStream(ipc_write::StreamWriter<SharedWriter>),
+    Csv(CsvBatchedWriter<SharedWriter>),
+    #[cfg(feature = "ndjson")]
+    Ndjson(JsonBatchedWriter<SharedWriter>),
+}
+
+impl StartedWriter {
+    // The dataframe must be in a single chunk. A Parquet writer writes
+    // each chunk as its own row group, so it is sliced by the row group size.
+    fn write_batch(&mut self, df: &DataFrame) -> Result<(), ExplorerError> {
+        match self {
+            StartedWriter::Parquet(writer, Some(row_group_size)) => {
+                let mut offset = 0;
+
+                while offset < df.height() {
+                    writer.write_batch(&df.slice(offset as i64, *row_group_size))?;
+                    offset += *row_group_size;
+                }
+            }
+            StartedWriter::Parquet(writer, None) => writer.write_batch(df)?,
+            StartedWriter::Ipc(writer) => writer.write_batch(df)?,
+            StartedWriter::IpcStream(writer) => {
+                for batch in df.iter_chunks(CompatLevel::newest(), true) {
+                    writer.write(&batch, None)?;
+                }
+            }
+            StartedWriter::Csv(writer) => writer.write_batch(df)?,
+            #[cfg(feature = "ndjson")]
+            StartedWriter::Ndjson(writer) => writer.write_batch(df)?,
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), ExplorerError> {
+        match self {
+            StartedWriter::Parquet(writer, _row_group_size) => {
+                let _ = writer.finish()?;
+            }
+            StartedWriter::Ipc(writer) => writer.finish()?,
+            StartedWriter::IpcStream(writer) => writer.finish()?,
+            StartedWriter::Csv(writer) => writer.finish()?,
+            #[cfg(feature = "ndjson")]
+            StartedWriter::Ndjson(_writer) => (),
+        }
+        Ok(())
+    }
+}
+
+enum WriterState {
+    Configured(FormatWriter),
+    Started(StartedWriter),
+    Closed,
+}
+
+pub struct BatchedWriter {
+    state: WriterState,
+    output: SharedWriter,
+    // The schema of the first batch, which all the batches must have.
+    schema: Option<Schema>,
+}
+
+impl BatchedWriter {
+    pub fn new(format_writer: FormatWriter, output: SharedWriter) -> Self {
+        BatchedWriter {
+            state: WriterState::Configured(format_writer),
+            output,
+            schema: None,
+        }
+    }
+
+    pub fn write_batch(&mut self, df: &DataFrame) -> Result<(), ExplorerError> {
+        let schema = df.schema();
+
+        match &self.schema {
+            Some(expected) if *expected != schema => {
+                return Err(ExplorerError::Other(
+                    "the dataframe does not have the same columns and dtypes as the first batch"
+                        .to_string(),
+                ));
+            }
+            Some(_) => (),
+            None => self.schema = Some(schema.clone()),
+        }
+
+        self.start(&schema)?;
+
+        let mut df = df.clone();
+        df.as_single_chunk_par();
+
+        match &mut self.state {
+            WriterState::Started(writer) => writer.write_batch(&df),
+            _ => Err(closed_error()),
+        }
+    }
+
+    // Writes the end of the file and completes the upload, if any.
+    // A writer that was given no batches writes a file without columns.
+    // A writer that fails to close is aborted.
+    pub fn close(&mut self) -> Result<(), ExplorerError> {
+        let result = self.finish();
+
+        if result.is_err() {
+            let _ = self.output.abort();
+        }
+
+        result
+    }
+
+    fn finish(&mut self) -> Result<(), ExplorerError> {
+        self.start(&Schema::default())?;
+
+        match std::mem::replace(&mut self.state, WriterState::Closed) {
+            WriterState::Started(mut writer) => {
+                writer.finish()?;
+                self.output.finish()
+            }
+            _ => Err(closed_error()),
+        }
+    }
+
+    // Closes the writer without completing the file, and removes it.
+    pub fn abort(&mut self) -> Result<(), ExplorerError> {
+        self.state = WriterState::Closed;
+        self.output.abort()
+    }
+
+    fn start(&mut self, schema: &Schema) -> Result<(), ExplorerError> {
+        if let WriterState::Configured(_) = self.state {
+            if let WriterState::Configured(format_writer) =
+                std::mem::replace(&mut self.state, WriterState::Closed)
+            {
+                self.state = WriterState::Started(format_writer.start(schema)?);
+            }
+        }
+        Ok(())
+    }
+}
+
+// A writer that is dropped without being closed, such as one that was
+// garbage collected, is aborted, so it leaves no partial file behind.
+// Once the writer is closed or aborted, there is nothing left to abort.
+impl Drop for BatchedWriter {
+    fn drop(&mut self) {
+        let _ = self.output.abort();
+    }
+}
+
+fn closed_error() -> ExplorerError {
+    ExplorerError::Other("the writer is already closed".to_string())
+}
+
+fn aborted_error() -> ExplorerError {
+    ExplorerError::Other("the writer was aborted".to_string())
+}
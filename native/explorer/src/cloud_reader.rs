@@ -0,0 +1,125 @@
+use crate::ExplorerError;
+use object_store::path::Path;
+use object_store::ObjectStore;
+use std::io::{Error, ErrorKind, SeekFrom};
+use std::sync::Arc;
+
+// The minimum amount of bytes fetched per request. Readers tend to do many
+// small reads, so we read ahead to avoid one request per read.
+const READ_AHEAD_SIZE: usize = 4 * 1024 * 1024;
+
+/// CloudReader exposes an object from an [ObjectStore](https://docs.rs/object_store/latest/object_store/trait.ObjectStore.html)
+/// through a synchronous interface which implements `std::io::Read` and `std::io::Seek`.
+///
+/// Each read is a ranged GET request, so only the parts of the object that are
+/// read are downloaded. This allows it to be used in sync code which would otherwise
+/// read from a simple File, such as with `polars::prelude::ParquetReader`.
+pub struct CloudReader {
+    // The Tokio runtime which the reader uses internally.
+    runtime: tokio::runtime::Runtime,
+    // The copy of the object_store
+    object_store: Arc<dyn ObjectStore>,
+    // The path for the object being read.
+    path: Path,
+    // The size of the object, read at creation.
+    size: u64,
+    // The current position of the reader in the object.
+    position: u64,
+    // The bytes read ahead, and where they start in the object.
+    buffer: Vec<u8>,
+    buffer_start: u64,
+}
+
+impl CloudReader {
+    /// Construct a new CloudReader
+    ///
+    /// Creates a new (current-thread) Tokio runtime
+    /// which bridges the sync reading process with the async ObjectStore downloading,
+    /// and makes a head request to find the size of the object.
+    pub fn new(object_store: Arc<dyn ObjectStore>, path: Path) -> Result<Self, ExplorerError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .enable_io()
+            .build()?;
+
+        let meta = runtime
+            .block_on(object_store.head(&path))
+            .map_err(|err| match err {
+                object_store::Error::NotFound { .. } => ExplorerError::NotFound,
+                err => ExplorerError::Other(format!("cannot read information from file. {err}")),
+            })?;
+
+        Ok(CloudReader {
+            runtime,
+            object_store,
+            path,
+            size: meta.size as u64,
+            position: 0,
+            buffer: vec![],
+            buffer_start: 0,
+        })
+    }
+
+    fn buffer_end(&self) -> u64 {
+        self.buffer_start + self.buffer.len() as u64
+    }
+
+    fn fill_buffer(&mut self, len: usize) -> std::io::Result<()> {
+        let start = self.position as usize;
+        let end = (start + len.max(READ_AHEAD_SIZE)).min(self.size as usize);
+
+        let bytes = self
+            .runtime
+            .block_on(self.object_store.get_range(&self.path, start..end))
+            .map_err(Error::other)?;
+
+        self.buffer = bytes.to_vec();
+        self.buffer_start = self.position;
+        Ok(())
+    }
+}
+
+impl std::io::Read for CloudReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() || self.position >= self.size {
+            return Ok(0);
+        }
+
+        if self.position < self.buffer_start || self.position >= self.buffer_end() {
+            self.fill_buffer(buf.len())?;
+        }
+
+        let offset = (self.position - self.buffer_start) as usize;
+        let available = &self.buffer[offset..];
+        let len = available.len().min(buf.len());
+
+        buf[..len].copy_from_slice(&available[..len]);
+        self.position += len as u64;
+        Ok(len)
+    }
+}
+
+impl std::io::Seek for CloudReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.size.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+
+        match position {
+            Some(position) => {
+                self.position = position;
+                Ok(position)
+            }
+            None => Err(Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+// Polars readers that need random access take a `MmapBytesReader`.
+// Since the object is not in memory, we keep the default implementations.
+impl polars::io::mmap::MmapBytesReader for CloudReader {}
@@ -69,6 +69,18 @@ impl CloudWriter {
             ))
         }
     }
+
+    /// Abort the upload, so the object is not created.
+    pub fn abort(&mut self) -> Result<(), ExplorerError> {
+        if self.status == CloudWriterStatus::Running {
+            self.status = CloudWriterStatus::Aborted;
+            self.runtime
+                .block_on(self.writer.abort())
+                .map_err(|err| ExplorerError::Other(format!("cannot abort the upload. {err}")))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl std::io::Write for CloudWriter {
@@ -104,7 +116,8 @@ impl std::io::Write for CloudWriter {
 
 impl Drop for CloudWriter {
     fn drop(&mut self) {
-        if self.status != CloudWriterStatus::Stopped {
+        // An aborted upload must not be completed.
+        if self.status == CloudWriterStatus::Running {
             self.status = CloudWriterStatus::Stopped;
             let _ = self.runtime.block_on(self.writer.shutdown());
         }
@@ -0,0 +1,81 @@
+use crate::datatypes::ExTextCompression;
+use flate2::write::GzEncoder;
+use std::io::{Result, Write};
+
+/// CompressedWriter wraps a writer and compresses everything written to it
+/// with the given codec, or passes it through untouched when there is none.
+///
+/// Polars decompresses text formats when reading, but it never compresses
+/// them, so this is used with `polars::prelude::CsvWriter` and `JsonWriter`.
+pub enum CompressedWriter<W: Write> {
+    Plain(W),
+    Gzip(GzEncoder<W>),
+    Zstd(zstd::Encoder<'static, W>),
+}
+
+impl<W: Write> CompressedWriter<W> {
+    pub fn new(writer: W, compression: Option<ExTextCompression>) -> Result<Self> {
+        match compression {
+            None => Ok(CompressedWriter::Plain(writer)),
+            Some(ExTextCompression::Gzip(level)) => {
+                let level = level.map_or_else(flate2::Compression::default, |level| {
+                    flate2::Compression::new(level)
+                });
+
+                Ok(CompressedWriter::Gzip(GzEncoder::new(writer, level)))
+            }
+            Some(ExTextCompression::Zstd(level)) => {
+                let level = level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL);
+
+                Ok(CompressedWriter::Zstd(zstd::Encoder::new(writer, level)?))
+            }
+        }
+    }
+
+    /// Returns the inner writer, without writing anything to it.
+    pub fn get_mut(&mut self) -> &mut W {
+        match self {
+            CompressedWriter::Plain(writer) => writer,
+            CompressedWriter::Gzip(encoder) => encoder.get_mut(),
+            CompressedWriter::Zstd(encoder) => encoder.get_mut(),
+        }
+    }
+
+    /// Writes the end of the compressed stream, if any, but keeps
+    /// the writer, so it can still be used if this fails.
+    pub fn try_finish(&mut self) -> Result<()> {
+        match self {
+            CompressedWriter::Plain(_writer) => Ok(()),
+            CompressedWriter::Gzip(encoder) => encoder.try_finish(),
+            CompressedWriter::Zstd(encoder) => encoder.do_finish(),
+        }
+    }
+
+    /// Writes the end of the compressed stream, if any, and
+    /// returns the inner writer, which is not flushed.
+    pub fn finish(self) -> Result<W> {
+        match self {
+            CompressedWriter::Plain(writer) => Ok(writer),
+            CompressedWriter::Gzip(encoder) => encoder.finish(),
+            CompressedWriter::Zstd(encoder) => encoder.finish(),
+        }
+    }
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        match self {
+            CompressedWriter::Plain(writer) => writer.write(buf),
+            CompressedWriter::Gzip(encoder) => encoder.write(buf),
+            CompressedWriter::Zstd(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        match self {
+            CompressedWriter::Plain(writer) => writer.flush(),
+            CompressedWriter::Gzip(encoder) => encoder.flush(),
+            CompressedWriter::Zstd(encoder) => encoder.flush(),
+        }
+    }
+}
@@ -11,17 +11,29 @@ use statrs::distribution::{
     Beta, Cauchy, Chi, ChiSquared, Dirac, Erlang, Exp,
     FisherSnedecor, Gamma, Gumbel, InverseGamma, Laplace,
     LogNormal, NegativeBinomial, Normal, Pareto,
-    StudentsT, Triangular, Uniform, Weibull
+    StudentsT, Triangular, Uniform, Weibull,
+    // Discrete distributions
+    Bernoulli, Binomial, Categorical, DiscreteUniform,
+    Geometric, Hypergeometric, Poisson,
+    // Multivariate distributions
+    Dirichlet, Multinomial, MultivariateNormal
 };
 
+use nalgebra::DVector;
+
 use rand_chacha::rand_core::SeedableRng;
 use rand_chacha::ChaCha12Rng;
 
+use rayon::prelude::*;
+
 use std::format;
-use std::iter;
 use std::collections::HashMap;
 
+use rustler::ResourceArc;
+
+use crate::arrow_c_data::{self, ExArrowCData};
 use crate::datatypes::ExSeriesDtype;
+use crate::error::check_distribution;
 use crate::ex_expr_to_exprs;
 use crate::{ExDataFrame, ExExpr, ExLazyFrame, ExSeries, ExplorerError};
 use either::Either;
@@ -219,6 +231,25 @@ pub fn df_sample_frac(
     Ok(ExDataFrame::new(new_df))
 }
 
+#[rustler::nif(schedule = "DirtyCpu")]
+fn df_to_arrow_c_data(
+    df: ExDataFrame,
+) -> Result<(ResourceArc<ExArrowCData>, u64, u64), ExplorerError> {
+    let series = df
+        .clone_inner()
+        .into_struct(PlSmallStr::EMPTY)
+        .into_series();
+    Ok(ExArrowCData::export(&series).into_resource())
+}
+
+#[rustler::nif]
+fn df_from_arrow_c_data(array_ptr: u64, schema_ptr: u64) -> Result<ExDataFrame, ExplorerError> {
+    let series = arrow_c_data::import(array_ptr, schema_ptr)?;
+    let df = DataFrame::new(series.struct_()?.fields_as_series())?;
+
+    Ok(ExDataFrame::new(df))
+}
+
 #[rustler::nif]
 fn df_from_arrow_stream_pointer(stream_ptr: u64) -> Result<ExDataFrame, ExplorerError> {
     let stream_ptr = stream_ptr as *mut ffi::ArrowArrayStream;
@@ -497,27 +528,76 @@ pub fn df_re_dtype(pattern: &str) -> Result<ExSeriesDtype, ExplorerError> {
     Ok(ex_dtype)
 }
 
+// Draws are split into chunks of `DRAWS_PER_STREAM` values, which are
+// generated in parallel. Each chunk gets its own ChaCha stream (all of them
+// seeded with the same seed), so the result only depends on the seed and
+// on the number of draws, and not on the number of threads.
+//
+// The first chunk uses the default stream (0), so small draws give
+// exactly the same values as drawing sequentially from a single RNG.
+// Don't change this constant, or previous results won't be reproducible.
+const DRAWS_PER_STREAM: usize = 1 << 16;
+
+// Fills a vector with `nr_of_draws` draws of `width` values each
+// (the width is 1 for univariate distributions). The `sample` function
+// receives the RNG for the current stream and the slice for one draw.
+fn draw_in_parallel<T, F>(seed: u64, nr_of_draws: u64, width: usize, sample: F) -> Vec<T>
+where
+    T: Default + Clone + Send,
+    F: Fn(&mut ChaCha12Rng, &mut [T]) + Sync,
+{
+    let mut values = vec![T::default(); nr_of_draws as usize * width];
+
+    if width == 0 {
+        return values;
+    }
+
+    values
+        .par_chunks_mut(DRAWS_PER_STREAM * width)
+        .enumerate()
+        .for_each(|(stream, chunk)| {
+            let mut rng = ChaCha12Rng::seed_from_u64(seed);
+            rng.set_stream(stream as u64);
+
+            for draw in chunk.chunks_mut(width) {
+                sample(&mut rng, draw);
+            }
+        });
+
+    values
+}
+
+// Continuous distributions are sampled as `f64`, which is the default.
+// The discrete distributions from `statrs` implement `Distribution<f64>`
+// in addition to the `Distribution` for their natural integer type,
+// so we must tell the compiler which one to sample from.
+// The first type is the one we sample and the second is the type
+// of the `x` column (both are the same unless we need a conversion).
 macro_rules! draw_from_univariate_dist {
-    ($seed:expr; $dist:expr; $nr_of_draws:expr) => {
+    ($seed:expr; $dist:ident::new($($arg:expr),+); $nr_of_draws:expr) => {
+        draw_from_univariate_dist!($seed; $dist::new($($arg),+); $nr_of_draws; f64)
+    };
+
+    ($seed:expr; $dist:ident::new($($arg:expr),+); $nr_of_draws:expr; $t:ty) => {
+        draw_from_univariate_dist!($seed; $dist::new($($arg),+); $nr_of_draws; $t => $t)
+    };
+
+    ($seed:expr; $dist:ident::new($($arg:expr),+); $nr_of_draws:expr; $t:ty => $out:ty) => {
         {
-            let dist = $dist.unwrap();
-            let mut rng = ChaCha12Rng::seed_from_u64($seed);
-    
-            let draws: Vec<u64> =
-                iter::successors(Some(0 as u64), |n| n.checked_add(1))
-                .take($nr_of_draws as usize)
-                .collect();
-    
-            let values: Vec<_> =
-                iter::repeat_with(|| dist.sample(&mut rng))
-                .take($nr_of_draws as usize)
-                .collect();
-    
+            let dist = check_distribution!($dist::new($($arg),+))?;
+
+            let draws: Vec<u64> = (0..$nr_of_draws).collect();
+
+            let values: Vec<$out> =
+                draw_in_parallel($seed, $nr_of_draws, 1, |rng, draw| {
+                    draw[0] = Distribution::<$t>::sample(&dist, rng) as $out;
+                });
+
             let column_draws: Series = Series::new("draw".into(), &draws);
             let column_values: Series = Series::new("x".into(), &values);
-    
+
             let df: PolarsResult<DataFrame> = DataFrame::new(vec![column_draws, column_values]);
-    
+
             Ok(ExDataFrame::new(df?))
         }
     };
@@ -577,12 +657,11 @@ pub fn df_draw_from_fisher_snedecor(seed: u64, freedom_1: f64, freedom_2: f64, n
     draw_from_univariate_dist!(seed; FisherSnedecor::new(freedom_1, freedom_2); nr_of_draws)
 }
 
-// // TODO: find why the Distribution trait is implemented twice!
-// #[rustler::nif(schedule = "DirtyCpu")]
-// pub fn df_draw_from_geometric(seed: u64, p: f64, nr_of_draws: u64)
-//             -> Result<ExDataFrame, ExplorerError> {
-//     draw_from_univariate_dist!(seed; Geometric::new(p); nr_of_draws)
-// }
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn df_draw_from_geometric(seed: u64, p: f64, nr_of_draws: u64)
+            -> Result<ExDataFrame, ExplorerError> {
+    draw_from_univariate_dist!(seed; Geometric::new(p); nr_of_draws; u64)
+}
 
 #[rustler::nif(schedule = "DirtyCpu")]
 pub fn df_draw_from_gumbel(seed: u64, location: f64, scale: f64, nr_of_draws: u64)
@@ -590,17 +669,17 @@ pub fn df_draw_from_gumbel(seed: u64, location: f64, scale: f64, nr_of_draws: u6
     draw_from_univariate_dist!(seed; Gumbel::new(location, scale); nr_of_draws)
 }
 
-// // TODO: find why the Distribution trait is implemented twice!
-// #[rustler::nif(schedule = "DirtyCpu")]
-// pub fn df_draw_from_hypergeometric(seed: u64, pop_size: u64, success_states: u64,
-//                                    sample_size: u64, nr_of_draws: u64)
-//             -> Result<ExDataFrame, ExplorerError> {
-//     draw_from_univariate_dist!(
-//         seed;
-//         Hypergeometric::new(pop_size, success_states, sample_size);
-//         nr_of_draws
-//     )
-// }
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn df_draw_from_hypergeometric(seed: u64, pop_size: u64, success_states: u64,
+                                   sample_size: u64, nr_of_draws: u64)
+            -> Result<ExDataFrame, ExplorerError> {
+    draw_from_univariate_dist!(
+        seed;
+        Hypergeometric::new(pop_size, success_states, sample_size);
+        nr_of_draws;
+        u64
+    )
+}
 
 #[rustler::nif(schedule = "DirtyCpu")]
 pub fn df_draw_from_inverse_gamma(seed: u64, shape: f64, rate: f64, nr_of_draws: u64)
@@ -615,9 +694,9 @@ pub fn df_draw_from_laplace(seed: u64, location: f64, scale: f64, nr_of_draws: u
 }
 
 #[rustler::nif(schedule = "DirtyCpu")]
-pub fn df_draw_from_log_normal(seed: u64, mean: f64, variance: f64, nr_of_draws: u64)
+pub fn df_draw_from_log_normal(seed: u64, location: f64, scale: f64, nr_of_draws: u64)
             -> Result<ExDataFrame, ExplorerError> {
-    draw_from_univariate_dist!(seed; LogNormal::new(mean, variance); nr_of_draws)
+    draw_from_univariate_dist!(seed; LogNormal::new(location, scale); nr_of_draws)
 }
 
 #[rustler::nif(schedule = "DirtyCpu")]
@@ -629,7 +708,7 @@ pub fn df_draw_from_gamma(seed: u64, shape: f64, rate: f64, nr_of_draws: u64)
 #[rustler::nif(schedule = "DirtyCpu")]
 pub fn df_draw_from_negative_binomial(seed: u64, r: f64, p: f64, nr_of_draws: u64)
         -> Result<ExDataFrame, ExplorerError> {
-    draw_from_univariate_dist!(seed; NegativeBinomial::new(r, p); nr_of_draws)
+    draw_from_univariate_dist!(seed; NegativeBinomial::new(r, p); nr_of_draws; u64)
 }
 
 #[rustler::nif(schedule = "DirtyCpu")]
@@ -644,11 +723,11 @@ pub fn df_draw_from_pareto(seed: u64, scale: f64, shape: f64, nr_of_draws: u64)
     draw_from_univariate_dist!(seed; Pareto::new(scale, shape); nr_of_draws)
 }
 
-// #[rustler::nif(schedule = "DirtyCpu")]
-// pub fn df_draw_from_poisson(seed: u64, lambda: f64, nr_of_draws: u64)
-//             -> Result<ExDataFrame, ExplorerError> {
-//     draw_from_univariate_dist!(seed; Poisson::new(lambda); nr_of_draws)
-// }
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn df_draw_from_poisson(seed: u64, lambda: f64, nr_of_draws: u64)
+            -> Result<ExDataFrame, ExplorerError> {
+    draw_from_univariate_dist!(seed; Poisson::new(lambda); nr_of_draws; u64)
+}
 
 #[rustler::nif(schedule = "DirtyCpu")]
 pub fn df_draw_from_students_t(seed: u64, location: f64, scale: f64, freedom: f64, nr_of_draws: u64)
@@ -674,46 +753,138 @@ pub fn df_draw_from_weibull(seed: u64, shape: f64, scale: f64, nr_of_draws: u64)
     draw_from_univariate_dist!(seed; Weibull::new(shape, scale); nr_of_draws)
 }
 
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn df_draw_from_binomial(seed: u64, p: f64, n: u64, nr_of_draws: u64)
+            -> Result<ExDataFrame, ExplorerError> {
+    draw_from_univariate_dist!(seed; Binomial::new(p, n); nr_of_draws; u64)
+}
+
+// Bernoulli samples are booleans, but we return them as 0 or 1
+// so that they can be summed and averaged like other discrete draws.
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn df_draw_from_bernoulli(seed: u64, p: f64, nr_of_draws: u64)
+            -> Result<ExDataFrame, ExplorerError> {
+    draw_from_univariate_dist!(seed; Bernoulli::new(p); nr_of_draws; bool => u64)
+}
+
+// Returns the index of the category (starting at 0) for each draw.
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn df_draw_from_categorical(seed: u64, prob_mass: Vec<f64>, nr_of_draws: u64)
+            -> Result<ExDataFrame, ExplorerError> {
+    draw_from_univariate_dist!(seed; Categorical::new(&prob_mass); nr_of_draws; u64)
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn df_draw_from_discrete_uniform(seed: u64, min: i64, max: i64, nr_of_draws: u64)
+            -> Result<ExDataFrame, ExplorerError> {
+    draw_from_univariate_dist!(seed; DiscreteUniform::new(min, max); nr_of_draws; i64)
+}
 
 // Multivariate distributions
 
-// #[rustler::nif(schedule = "DirtyCpu")]
-// pub fn df_draw_from_dirichlet(seed: u64, alpha: Vec<f64>, nr_of_draws: u64)
-//             -> Result<ExDataFrame, ExplorerError> {
-//     let k = alpha.len() as u64;
-//     let dist = Dirichlet::new(&alpha[..]).unwrap();
-//     let mut rng = ChaCha12Rng::seed_from_u64(seed);
+// Each draw is a vector with `k` components, which we store in the
+// columns `x_0`, `x_1`, ..., `x_{k-1}` (next to the `draw` column).
+// Like in the discrete case, the type of the vector must be explicit
+// because `Multinomial` can be sampled as integers or as floats.
+macro_rules! draw_from_multivariate_dist {
+    ($seed:expr; $dist:ident::new($($arg:expr),+); $k:expr; $nr_of_draws:expr; $t:ty) => {
+        {
+            let dist = check_distribution!($dist::new($($arg),+))?;
+            let k: usize = $k;
 
-//     let draws: Vec<u64> =
-//         (0..nr_of_draws)
-//         .into_iter()
-//         .collect();
+            let draws: Vec<u64> = (0..$nr_of_draws).collect();
 
-//     let variables: Vec<f64> =
-//         (0..nr_of_draws)
-//         .into_iter()
-//         .map(|_index| dist.sample(&mut rng))
-//         .flat_map(|result| result)
-//         .collect();
+            // The values of each draw are contiguous.
+            let values: Vec<$t> =
+                draw_in_parallel($seed, $nr_of_draws, k, |rng, draw| {
+                    let sample = Distribution::<DVector<$t>>::sample(&dist, rng);
+                    draw.copy_from_slice(sample.as_slice());
+                });
 
-//     let mut columns: Vec<Series> = vec![Series::new("draw".into(), &draws)];
-    
-//     for variable_index in 0..k {
-//         let mut col: Vec<f64> = Vec::with_capacity(nr_of_draws as usize);
+            // Split the draws into one vector per component in a single pass.
+            let mut variables: Vec<Vec<$t>> =
+                (0..k).map(|_| Vec::with_capacity($nr_of_draws as usize)).collect();
 
-//         for draw in 0..nr_of_draws {
-//             col.push(variables[(draw * k + variable_index) as usize])
-//         }
+            for draw in values.chunks_exact(k) {
+                for (variable, value) in variables.iter_mut().zip(draw) {
+                    variable.push(*value);
+                }
+            }
 
-//         let column_name = format!("x{}", variable_index + 1).into();
-//         let column: Series = Series::new(column_name, &col);
+            let mut columns: Vec<Series> = vec![Series::new("draw".into(), &draws)];
 
-//         columns.push(column);
-//     }
+            for (index, variable) in variables.into_iter().enumerate() {
+                columns.push(Series::new(format!("x_{index}").into(), variable));
+            }
 
-//     let df: PolarsResult<DataFrame> = DataFrame::new(columns);
+            let df: PolarsResult<DataFrame> = DataFrame::new(columns);
 
-//     Ok(ExDataFrame::new(df?))
-// }
+            Ok(ExDataFrame::new(df?))
+        }
+    };
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn df_draw_from_dirichlet(seed: u64, alpha: Vec<f64>, nr_of_draws: u64)
+            -> Result<ExDataFrame, ExplorerError> {
+    let k = alpha.len();
+    draw_from_multivariate_dist!(seed; Dirichlet::new(alpha); k; nr_of_draws; f64)
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn df_draw_from_multinomial(seed: u64, p: Vec<f64>, n: u64, nr_of_draws: u64)
+            -> Result<ExDataFrame, ExplorerError> {
+    let k = p.len();
+    draw_from_multivariate_dist!(seed; Multinomial::new(p, n); k; nr_of_draws; u64)
+}
 
+// The relative tolerance when checking that the covariance matrix is symmetric.
+const COV_SYMMETRY_TOLERANCE: f64 = 1e-8;
 
+// The covariance matrix is given as a list of rows.
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn df_draw_from_multivariate_normal(seed: u64, mean: Vec<f64>, cov: Vec<Vec<f64>>,
+                                        nr_of_draws: u64)
+            -> Result<ExDataFrame, ExplorerError> {
+    let k = mean.len();
+
+    // `statrs` panics (instead of returning an error) if the
+    // covariance matrix doesn't have the right dimensions.
+    if cov.len() != k || cov.iter().any(|row| row.len() != k) {
+        return Err(ExplorerError::Distribution {
+            distribution: "MultivariateNormal".to_string(),
+            parameters: format!("cov = {cov:?}"),
+            reason: format!("Covariance matrix must be {k}x{k}, the same size as the mean"),
+        });
+    }
+
+    // `statrs` requires the matrix to be exactly symmetric, which rounding
+    // errors in a computed covariance matrix easily break. So the entries
+    // are compared within a tolerance and then averaged with their mirror.
+    for i in 0..k {
+        for j in (i + 1)..k {
+            let (upper, lower) = (cov[i][j], cov[j][i]);
+            let tolerance = COV_SYMMETRY_TOLERANCE * upper.abs().max(lower.abs()).max(1.0);
+
+            if (upper - lower).abs() > tolerance {
+                return Err(ExplorerError::Distribution {
+                    distribution: "MultivariateNormal".to_string(),
+                    parameters: format!("cov = {cov:?}"),
+                    reason: format!(
+                        "Covariance matrix must be symmetric, but cov[{i}][{j}] = {upper} \
+                         and cov[{j}][{i}] = {lower}"
+                    ),
+                });
+            }
+        }
+    }
+
+    // The matrix is symmetric, so it doesn't matter that `statrs`
+    // reads the flattened rows in column-major order.
+    let cov: Vec<f64> = (0..k)
+        .flat_map(|i| (0..k).map(move |j| (i, j)))
+        .map(|(i, j)| (cov[i][j] + cov[j][i]) / 2.0)
+        .collect();
+
+    draw_from_multivariate_dist!(seed; MultivariateNormal::new(mean, cov); k; nr_of_draws; f64)
+}
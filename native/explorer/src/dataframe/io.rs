@@ -8,16 +8,45 @@
 //
 // Today we have the following formats: CSV, NDJSON, Parquet, Apache Arrow and Apache Arrow Stream.
 //
+use polars::export::arrow::io::ipc::write as ipc_write;
+use polars::io::mmap::MmapBytesReader;
 use polars::prelude::*;
-use std::num::NonZeroUsize;
+use polars_parquet::parquet::metadata::ColumnDescriptor;
+use polars_parquet::parquet::statistics::Statistics as ParquetColumnStatistics;
 
-use rustler::{Binary, Env, NewBinary};
+use rustler::{Binary, Env, NewBinary, ResourceArc};
 use std::fs::File;
-use std::io::{BufReader, BufWriter, Cursor};
-
-use crate::datatypes::{ExParquetCompression, ExS3Entry, ExSeriesDtype};
+use std::io::{BufReader, BufWriter, Cursor, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::batched_reader::{ensure_uncompressed, BatchSource, BatchedReader};
+use crate::batched_writer::{BatchedWriter, FormatWriter, SharedWriter, Sink};
+use crate::compressed_writer::CompressedWriter;
+use crate::datatypes::{
+    ExBatchedReaderRef, ExBatchedWriterRef, ExCloudEntry, ExCsvDialect, ExCsvWriteOptions,
+    ExMemoryStoreRef, ExParquetCompression, ExParquetWriteOptions, ExSeriesDtype,
+    ExTextCompression,
+};
 use crate::{ExDataFrame, ExplorerError};
 
+#[cfg(feature = "azure")]
+use crate::datatypes::ExAzureConfig;
+#[cfg(feature = "gcp")]
+use crate::datatypes::ExGcsConfig;
+#[cfg(feature = "http")]
+use crate::datatypes::ExHttpConfig;
+#[cfg(feature = "aws")]
+use crate::datatypes::ExS3Config;
+#[cfg(feature = "ndjson")]
+use polars::io::json::BatchedWriter as JsonBatchedWriter;
+#[cfg(feature = "ndjson")]
+use std::io::{Read, Seek};
+#[cfg(feature = "ndjson")]
+use std::num::NonZeroUsize;
+
+#[cfg(feature = "cloud")]
+use crate::cloud_reader::CloudReader;
 #[cfg(feature = "cloud")]
 use crate::cloud_writer::CloudWriter;
 
@@ -41,13 +70,101 @@ pub fn df_from_csv(
     null_vals: Vec<String>,
     parse_dates: bool,
     eol_delimiter: Option<u8>,
+    dialect: ExCsvDialect,
+) -> Result<ExDataFrame, ExplorerError> {
+    let dataframe = csv_read_options(
+        infer_schema_length,
+        has_header,
+        stop_after_n_rows,
+        skip_rows,
+        skip_rows_after_header,
+        projection,
+        delimiter_as_byte,
+        do_rechunk,
+        column_names,
+        dtypes,
+        encoding,
+        null_vals,
+        parse_dates,
+        eol_delimiter,
+        &dialect,
+    )?
+    .try_into_reader_with_file_path(Some(filename.into()))?
+    .finish();
+
+    Ok(ExDataFrame::new(dataframe?))
+}
+
+#[cfg(feature = "cloud")]
+#[rustler::nif(schedule = "DirtyIo")]
+#[allow(clippy::too_many_arguments)]
+pub fn df_from_csv_cloud(
+    ex_entry: ExCloudEntry,
+    infer_schema_length: Option<usize>,
+    has_header: bool,
+    stop_after_n_rows: Option<usize>,
+    skip_rows: usize,
+    skip_rows_after_header: usize,
+    projection: Option<Vec<usize>>,
+    delimiter_as_byte: u8,
+    do_rechunk: bool,
+    column_names: Option<Vec<String>>,
+    dtypes: Vec<(&str, ExSeriesDtype)>,
+    encoding: &str,
+    null_vals: Vec<String>,
+    parse_dates: bool,
+    eol_delimiter: Option<u8>,
+    dialect: ExCsvDialect,
 ) -> Result<ExDataFrame, ExplorerError> {
+    let cloud_reader = build_cloud_reader(&ex_entry)?;
+
+    let dataframe = csv_read_options(
+        infer_schema_length,
+        has_header,
+        stop_after_n_rows,
+        skip_rows,
+        skip_rows_after_header,
+        projection,
+        delimiter_as_byte,
+        do_rechunk,
+        column_names,
+        dtypes,
+        encoding,
+        null_vals,
+        parse_dates,
+        eol_delimiter,
+        &dialect,
+    )?
+    .into_reader_with_file_handle(cloud_reader)
+    .finish();
+
+    Ok(ExDataFrame::new(dataframe?))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn csv_read_options(
+    infer_schema_length: Option<usize>,
+    has_header: bool,
+    stop_after_n_rows: Option<usize>,
+    skip_rows: usize,
+    skip_rows_after_header: usize,
+    projection: Option<Vec<usize>>,
+    delimiter_as_byte: u8,
+    do_rechunk: bool,
+    column_names: Option<Vec<String>>,
+    dtypes: Vec<(&str, ExSeriesDtype)>,
+    encoding: &str,
+    null_vals: Vec<String>,
+    parse_dates: bool,
+    eol_delimiter: Option<u8>,
+    dialect: &ExCsvDialect,
+) -> Result<CsvReadOptions, ExplorerError> {
     let encoding = match encoding {
         "utf8-lossy" => CsvEncoding::LossyUtf8,
         _ => CsvEncoding::Utf8,
     };
 
-    let dataframe = CsvReadOptions::default()
+    Ok(CsvReadOptions::default()
         .with_schema_overwrite(schema_from_dtypes_pairs(dtypes)?)
         .with_infer_schema_length(infer_schema_length)
         .with_has_header(has_header)
@@ -63,20 +180,17 @@ pub fn df_from_csv(
                 .collect()
         }))
         .with_parse_options(
-            CsvParseOptions::default()
-                .with_encoding(encoding)
-                .with_truncate_ragged_lines(true)
-                .with_try_parse_dates(parse_dates)
-                .with_separator(delimiter_as_byte)
-                .with_eol_char(eol_delimiter.unwrap_or(b'\n'))
-                .with_null_values(Some(NullValues::AllColumns(
-                    null_vals.iter().map(|val| val.into()).collect(),
-                ))),
-        )
-        .try_into_reader_with_file_path(Some(filename.into()))?
-        .finish();
-
-    Ok(ExDataFrame::new(dataframe?))
+            dialect.parse_options(
+                CsvParseOptions::default()
+                    .with_encoding(encoding)
+                    .with_try_parse_dates(parse_dates)
+                    .with_separator(delimiter_as_byte)
+                    .with_eol_char(eol_delimiter.unwrap_or(b'\n'))
+                    .with_null_values(Some(NullValues::AllColumns(
+                        null_vals.iter().map(|val| val.into()).collect(),
+                    ))),
+            ),
+        ))
 }
 
 pub fn schema_from_dtypes_pairs(
@@ -100,31 +214,40 @@ pub fn df_to_csv(
     filename: &str,
     include_headers: bool,
     delimiter: u8,
+    options: ExCsvWriteOptions,
+    compression: Option<ExTextCompression>,
 ) -> Result<(), ExplorerError> {
     let file = File::create(filename)?;
-    let mut buf_writer = BufWriter::new(file);
-    CsvWriter::new(&mut buf_writer)
+    let mut writer = CompressedWriter::new(BufWriter::new(file), compression)?;
+    options
+        .csv_writer(CsvWriter::new(&mut writer))
         .include_header(include_headers)
         .with_separator(delimiter)
         .finish(&mut data.clone())?;
+    writer.finish()?.flush()?;
     Ok(())
 }
 
-#[cfg(feature = "aws")]
+#[cfg(feature = "cloud")]
 #[rustler::nif(schedule = "DirtyIo")]
 pub fn df_to_csv_cloud(
     data: ExDataFrame,
-    ex_entry: ExS3Entry,
+    ex_entry: ExCloudEntry,
     include_headers: bool,
     delimiter: u8,
+    options: ExCsvWriteOptions,
+    compression: Option<ExTextCompression>,
 ) -> Result<(), ExplorerError> {
-    let mut cloud_writer = build_aws_s3_cloud_writer(ex_entry)?;
+    let mut cloud_writer = build_cloud_writer(&ex_entry)?;
+    let mut writer = CompressedWriter::new(&mut cloud_writer, compression)?;
 
-    CsvWriter::new(&mut cloud_writer)
+    options
+        .csv_writer(CsvWriter::new(&mut writer))
         .include_header(include_headers)
         .with_separator(delimiter)
         .finish(&mut data.clone())?;
 
+    writer.finish()?;
     let _ = cloud_writer.finish()?;
 
     Ok(())
@@ -136,14 +259,19 @@ pub fn df_dump_csv(
     data: ExDataFrame,
     include_headers: bool,
     delimiter: u8,
+    options: ExCsvWriteOptions,
+    compression: Option<ExTextCompression>,
 ) -> Result<Binary, ExplorerError> {
-    let mut buf = vec![];
+    let mut writer = CompressedWriter::new(vec![], compression)?;
 
-    CsvWriter::new(&mut buf)
+    options
+        .csv_writer(CsvWriter::new(&mut writer))
         .include_header(include_headers)
         .with_separator(delimiter)
         .finish(&mut data.clone())?;
 
+    let buf = writer.finish()?;
+
     let mut values_binary = NewBinary::new(env, buf.len());
     values_binary.copy_from_slice(&buf);
 
@@ -168,41 +296,29 @@ pub fn df_load_csv(
     null_vals: Vec<String>,
     parse_dates: bool,
     eol_delimiter: Option<u8>,
+    dialect: ExCsvDialect,
 ) -> Result<ExDataFrame, ExplorerError> {
-    let encoding = match encoding {
-        "utf8-lossy" => CsvEncoding::LossyUtf8,
-        _ => CsvEncoding::Utf8,
-    };
-
     let cursor = Cursor::new(binary.as_slice());
 
-    let dataframe = CsvReadOptions::default()
-        .with_schema_overwrite(schema_from_dtypes_pairs(dtypes)?)
-        .with_has_header(has_header)
-        .with_infer_schema_length(infer_schema_length)
-        .with_n_rows(stop_after_n_rows)
-        .with_columns(column_names.map(|names| {
-            names
-                .iter()
-                .map(|name| PlSmallStr::from_string(name.clone()))
-                .collect()
-        }))
-        .with_skip_rows(skip_rows)
-        .with_skip_rows_after_header(skip_rows_after_header)
-        .with_projection(projection.map(Arc::new))
-        .with_rechunk(do_rechunk)
-        .with_parse_options(
-            CsvParseOptions::default()
-                .with_separator(delimiter_as_byte)
-                .with_encoding(encoding)
-                .with_null_values(Some(NullValues::AllColumns(
-                    null_vals.iter().map(|x| x.into()).collect(),
-                )))
-                .with_try_parse_dates(parse_dates)
-                .with_eol_char(eol_delimiter.unwrap_or(b'\n')),
-        )
-        .into_reader_with_file_handle(cursor)
-        .finish();
+    let dataframe = csv_read_options(
+        infer_schema_length,
+        has_header,
+        stop_after_n_rows,
+        skip_rows,
+        skip_rows_after_header,
+        projection,
+        delimiter_as_byte,
+        do_rechunk,
+        column_names,
+        dtypes,
+        encoding,
+        null_vals,
+        parse_dates,
+        eol_delimiter,
+        &dialect,
+    )?
+    .into_reader_with_file_handle(cursor)
+    .finish();
 
     Ok(ExDataFrame::new(dataframe?))
 }
@@ -229,51 +345,146 @@ pub fn df_from_parquet(
     Ok(ExDataFrame::new(reader.finish()?))
 }
 
+#[cfg(feature = "cloud")]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn df_from_parquet_cloud(
+    ex_entry: ExCloudEntry,
+    stop_after_n_rows: Option<usize>,
+    column_names: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+    rechunk: bool,
+) -> Result<ExDataFrame, ExplorerError> {
+    use polars::export::arrow::array::new_empty_array;
+    use polars_parquet::read::{infer_schema, read_metadata, FileReader};
+
+    // `ParquetReader` reads the whole object, so the `FileReader` is used
+    // instead. It seeks to the chunks of the selected columns, which means
+    // only the footer and those chunks, in the row groups that are needed
+    // for `stop_after_n_rows`, are downloaded.
+    let mut cloud_reader = build_cloud_reader(&ex_entry)?;
+    let metadata = read_metadata(&mut cloud_reader)?;
+    let schema = infer_schema(&metadata)?;
+
+    let fields = match (column_names, projection) {
+        (Some(names), _) => names
+            .iter()
+            .map(|name| {
+                schema
+                    .get(name.as_str())
+                    .cloned()
+                    .ok_or_else(|| ExplorerError::Other(format!("column {name} not found")))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        (None, Some(indices)) => indices
+            .iter()
+            .map(|index| {
+                schema
+                    .get_at_index(*index)
+                    .map(|(_name, field)| field.clone())
+                    .ok_or_else(|| {
+                        ExplorerError::Other(format!("column index {index} is out of bounds"))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        (None, None) => schema.iter_values().cloned().collect(),
+    };
+
+    let projected_schema = fields
+        .iter()
+        .map(|field| (field.name.clone(), field.clone()))
+        .collect();
+
+    let batches = FileReader::new(
+        cloud_reader,
+        metadata.row_groups,
+        projected_schema,
+        stop_after_n_rows,
+    )
+    .collect::<PolarsResult<Vec<_>>>()?;
+
+    let columns = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let chunks: Vec<ArrayRef> = batches
+                .iter()
+                .map(|batch| batch.arrays()[index].clone())
+                .collect();
+
+            if chunks.is_empty() {
+                Series::try_from((field, new_empty_array(field.dtype.clone())))
+            } else {
+                Series::try_from((field, chunks))
+            }
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let mut df = DataFrame::new(columns)?;
+
+    if rechunk {
+        df.as_single_chunk_par();
+    }
+
+    Ok(ExDataFrame::new(df))
+}
+
+// The writer shared by all Parquet writes, including the lazy frame ones.
+pub fn parquet_writer<W: std::io::Write>(
+    writer: W,
+    compression: ParquetCompression,
+    ex_options: &ExParquetWriteOptions,
+) -> ParquetWriter<W> {
+    ParquetWriter::new(writer)
+        .with_compression(compression)
+        .with_statistics(ex_options.statistics_options())
+        .with_row_group_size(ex_options.row_group_size)
+        .with_data_page_size(ex_options.data_page_size)
+}
+
 #[rustler::nif(schedule = "DirtyIo")]
 pub fn df_to_parquet(
     data: ExDataFrame,
     filename: &str,
     ex_compression: ExParquetCompression,
+    ex_options: ExParquetWriteOptions,
 ) -> Result<(), ExplorerError> {
     let file = File::create(filename)?;
     let mut buf_writer = BufWriter::new(file);
 
     let compression = ParquetCompression::try_from(ex_compression)?;
 
-    ParquetWriter::new(&mut buf_writer)
-        .with_compression(compression)
-        .finish(&mut data.clone())?;
+    parquet_writer(&mut buf_writer, compression, &ex_options).finish(&mut data.clone())?;
     Ok(())
 }
 
-#[cfg(feature = "aws")]
+#[cfg(feature = "cloud")]
 #[rustler::nif(schedule = "DirtyIo")]
 pub fn df_to_parquet_cloud(
     data: ExDataFrame,
-    ex_entry: ExS3Entry,
+    ex_entry: ExCloudEntry,
     ex_compression: ExParquetCompression,
+    ex_options: ExParquetWriteOptions,
 ) -> Result<(), ExplorerError> {
-    let mut cloud_writer = build_aws_s3_cloud_writer(ex_entry)?;
+    let mut cloud_writer = build_cloud_writer(&ex_entry)?;
 
     let compression = ParquetCompression::try_from(ex_compression)?;
 
-    ParquetWriter::new(&mut cloud_writer)
-        .with_compression(compression)
-        .finish(&mut data.clone())?;
+    parquet_writer(&mut cloud_writer, compression, &ex_options).finish(&mut data.clone())?;
 
     let _ = cloud_writer.finish()?;
 
     Ok(())
 }
 
-#[cfg(feature = "aws")]
+#[cfg(feature = "cloud")]
 fn object_store_to_explorer_error(error: impl std::fmt::Debug) -> ExplorerError {
     ExplorerError::Other(format!("Internal ObjectStore error: #{error:?}"))
 }
 
 #[cfg(feature = "aws")]
-fn build_aws_s3_cloud_writer(ex_entry: ExS3Entry) -> Result<CloudWriter, ExplorerError> {
-    let config = ex_entry.config;
+fn build_aws_s3_object_store(
+    config: &ExS3Config,
+) -> Result<Arc<dyn object_store::ObjectStore>, ExplorerError> {
     let mut aws_builder = object_store::aws::AmazonS3Builder::new()
         .with_region(&config.region)
         .with_access_key_id(&config.access_key_id)
@@ -291,7 +502,7 @@ fn build_aws_s3_cloud_writer(ex_entry: ExS3Entry) -> Result<CloudWriter, Explore
             .with_virtual_hosted_style_request(true);
     }
 
-    if let Some(token) = config.token {
+    if let Some(token) = &config.token {
         aws_builder = aws_builder.with_token(token);
     }
 
@@ -299,8 +510,173 @@ fn build_aws_s3_cloud_writer(ex_entry: ExS3Entry) -> Result<CloudWriter, Explore
         .build()
         .map_err(object_store_to_explorer_error)?;
 
-    let object_store: Arc<dyn object_store::ObjectStore> = Arc::new(aws_s3);
-    CloudWriter::new(object_store, ex_entry.key.into())
+    Ok(Arc::new(aws_s3))
+}
+
+#[cfg(feature = "gcp")]
+fn build_gcs_object_store(
+    config: &ExGcsConfig,
+) -> Result<Arc<dyn object_store::ObjectStore>, ExplorerError> {
+    let mut gcs_builder =
+        object_store::gcp::GoogleCloudStorageBuilder::new().with_bucket_name(&config.bucket);
+
+    if let Some(service_account_key) = config.service_account_key()? {
+        gcs_builder = gcs_builder.with_service_account_key(service_account_key);
+    }
+
+    let gcs = gcs_builder
+        .build()
+        .map_err(object_store_to_explorer_error)?;
+
+    Ok(Arc::new(gcs))
+}
+
+#[cfg(feature = "azure")]
+fn build_azure_object_store(
+    config: &ExAzureConfig,
+) -> Result<Arc<dyn object_store::ObjectStore>, ExplorerError> {
+    let mut azure_builder = object_store::azure::MicrosoftAzureBuilder::new()
+        .with_account(&config.account)
+        .with_container_name(&config.container);
+
+    if let Some(access_key) = &config.access_key {
+        azure_builder = azure_builder.with_access_key(access_key);
+    }
+
+    // Emulators like Azurite take the account in the path of the
+    // endpoint, such as "http://127.0.0.1:10000/devstoreaccount1".
+    if let Some(endpoint) = &config.endpoint {
+        azure_builder = azure_builder
+            .with_endpoint(endpoint.clone())
+            .with_allow_http(true);
+    }
+
+    let azure = azure_builder
+        .build()
+        .map_err(object_store_to_explorer_error)?;
+
+    Ok(Arc::new(azure))
+}
+
+#[cfg(feature = "http")]
+fn build_http_object_store(
+    config: &ExHttpConfig,
+    url: &str,
+) -> Result<Arc<dyn object_store::ObjectStore>, ExplorerError> {
+    use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+    let mut headers = HeaderMap::with_capacity(config.headers.len());
+
+    for (name, value) in &config.headers {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|_| ExplorerError::Other(format!("invalid HTTP header name: {name:?}")))?;
+        let value = HeaderValue::from_str(value)
+            .map_err(|_| ExplorerError::Other(format!("invalid value for HTTP header {name}")))?;
+
+        headers.insert(name, value);
+    }
+
+    let options = object_store::ClientOptions::new()
+        .with_default_headers(headers)
+        .with_allow_http(true);
+
+    // The store is built for the URL itself, so it is read with an empty
+    // path, which keeps the query string of the URL untouched.
+    let http = object_store::http::HttpBuilder::new()
+        .with_url(url)
+        .with_client_options(options)
+        .build()
+        .map_err(object_store_to_explorer_error)?;
+
+    Ok(Arc::new(http))
+}
+
+// Returns the object store of the entry and the path of the object in it.
+#[cfg(feature = "cloud")]
+fn build_object_store(
+    ex_entry: &ExCloudEntry,
+) -> Result<(Arc<dyn object_store::ObjectStore>, object_store::path::Path), ExplorerError> {
+    match ex_entry {
+        #[cfg(feature = "aws")]
+        ExCloudEntry::S3(entry) => Ok((
+            build_aws_s3_object_store(&entry.config)?,
+            entry.key.as_str().into(),
+        )),
+        #[cfg(not(feature = "aws"))]
+        ExCloudEntry::S3(_) => Err(ExplorerError::Other(
+            "Explorer was compiled without the \"aws\" feature enabled.".to_string(),
+        )),
+        #[cfg(feature = "gcp")]
+        ExCloudEntry::Gcs(entry) => Ok((
+            build_gcs_object_store(&entry.config)?,
+            entry.key.as_str().into(),
+        )),
+        #[cfg(not(feature = "gcp"))]
+        ExCloudEntry::Gcs(_) => Err(ExplorerError::Other(
+            "Explorer was compiled without the \"gcp\" feature enabled.".to_string(),
+        )),
+        #[cfg(feature = "azure")]
+        ExCloudEntry::Azure(entry) => Ok((
+            build_azure_object_store(&entry.config)?,
+            entry.key.as_str().into(),
+        )),
+        #[cfg(not(feature = "azure"))]
+        ExCloudEntry::Azure(_) => Err(ExplorerError::Other(
+            "Explorer was compiled without the \"azure\" feature enabled.".to_string(),
+        )),
+        #[cfg(feature = "http")]
+        ExCloudEntry::Http(entry) => Ok((
+            build_http_object_store(&entry.config, &entry.url)?,
+            object_store::path::Path::default(),
+        )),
+        #[cfg(not(feature = "http"))]
+        ExCloudEntry::Http(_) => Err(ExplorerError::Other(
+            "Explorer was compiled without the \"http\" feature enabled.".to_string(),
+        )),
+        ExCloudEntry::Local(entry) => {
+            // Unlike `from_filesystem_path`, this doesn't require the file to exist.
+            let path = std::env::current_dir()?.join(&entry.path);
+            let path = object_store::path::Path::from_absolute_path(path)
+                .map_err(object_store_to_explorer_error)?;
+            let local: Arc<dyn object_store::ObjectStore> =
+                Arc::new(object_store::local::LocalFileSystem::new());
+
+            Ok((local, path))
+        }
+        ExCloudEntry::Memory(entry) => {
+            let memory: Arc<dyn object_store::ObjectStore> = entry.store.0.clone();
+            Ok((memory, entry.key.as_str().into()))
+        }
+    }
+}
+
+#[cfg(feature = "cloud")]
+pub fn build_cloud_writer(ex_entry: &ExCloudEntry) -> Result<CloudWriter, ExplorerError> {
+    let (object_store, path) = build_object_store(ex_entry)?;
+    CloudWriter::new(object_store, path)
+}
+
+#[cfg(feature = "cloud")]
+fn build_cloud_reader(ex_entry: &ExCloudEntry) -> Result<CloudReader, ExplorerError> {
+    let (object_store, path) = build_object_store(ex_entry)?;
+    CloudReader::new(object_store, path)
+}
+
+// The in-memory stores are mostly useful for testing, since
+// they live only as long as they are referenced.
+#[cfg(feature = "cloud")]
+#[rustler::nif]
+pub fn memory_store_new() -> Result<ResourceArc<ExMemoryStoreRef>, ExplorerError> {
+    let store = object_store::memory::InMemory::new();
+    Ok(ResourceArc::new(ExMemoryStoreRef(Arc::new(store))))
+}
+
+#[cfg(not(feature = "cloud"))]
+#[rustler::nif]
+pub fn memory_store_new() -> Result<ResourceArc<ExMemoryStoreRef>, ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
 }
 
 #[rustler::nif(schedule = "DirtyCpu")]
@@ -308,14 +684,13 @@ pub fn df_dump_parquet(
     env: Env,
     data: ExDataFrame,
     ex_compression: ExParquetCompression,
+    ex_options: ExParquetWriteOptions,
 ) -> Result<Binary, ExplorerError> {
     let mut buf = vec![];
 
     let compression = ParquetCompression::try_from(ex_compression)?;
 
-    ParquetWriter::new(&mut buf)
-        .with_compression(compression)
-        .finish(&mut data.clone())?;
+    parquet_writer(&mut buf, compression, &ex_options).finish(&mut data.clone())?;
 
     let mut values_binary = NewBinary::new(env, buf.len());
     values_binary.copy_from_slice(&buf);
@@ -328,7 +703,396 @@ pub fn df_load_parquet(binary: Binary) -> Result<ExDataFrame, ExplorerError> {
     let cursor = Cursor::new(binary.as_slice());
     let reader = ParquetReader::new(cursor);
 
-    Ok(ExDataFrame::new(reader.finish()?))
+    Ok(ExDataFrame::new(reader.finish()?))
+}
+
+// ============ Parquet metadata ============ //
+//
+// Reads only the footer of a Parquet file, and returns a dataframe with
+// one row per column chunk (that is, per column of each row group), with
+// the sizes, compression, encodings and statistics of the chunk. Every
+// row also has the number of rows of the file and the writer that
+// created it (`created_by`).
+//
+// The min and max statistics are given as strings of their physical
+// values. For example, a date column has its min and max as the number
+// of days since the epoch.
+
+type ColumnStatistics = (Option<i64>, Option<i64>, Option<String>, Option<String>);
+
+fn column_statistics(statistics: &ParquetColumnStatistics) -> ColumnStatistics {
+    macro_rules! values {
+        ($stats:expr, $to_string:expr) => {
+            (
+                $stats.null_count,
+                $stats.distinct_count,
+                $stats.min_value.as_ref().map($to_string),
+                $stats.max_value.as_ref().map($to_string),
+            )
+        };
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    match statistics {
+        ParquetColumnStatistics::Binary(s) => {
+            values!(s, |v: &Vec<u8>| String::from_utf8_lossy(v).into_owned())
+        }
+        ParquetColumnStatistics::Boolean(s) => values!(s, bool::to_string),
+        ParquetColumnStatistics::FixedLen(s) => values!(s, |v: &Vec<u8>| hex(v)),
+        ParquetColumnStatistics::Int32(s) => values!(s, i32::to_string),
+        ParquetColumnStatistics::Int64(s) => values!(s, i64::to_string),
+        ParquetColumnStatistics::Int96(s) => values!(s, |v: &[u32; 3]| format!("{v:?}")),
+        ParquetColumnStatistics::Float(s) => values!(s, f32::to_string),
+        ParquetColumnStatistics::Double(s) => values!(s, f64::to_string),
+    }
+}
+
+fn parquet_metadata_to_df(metadata: &FileMetaData) -> Result<ExDataFrame, ExplorerError> {
+    let mut row_group: Vec<Option<u32>> = vec![];
+    let mut row_group_num_rows: Vec<Option<u64>> = vec![];
+    let mut row_group_byte_size: Vec<Option<u64>> = vec![];
+    let mut column: Vec<String> = vec![];
+    let mut physical_type: Vec<String> = vec![];
+    let mut logical_type: Vec<Option<String>> = vec![];
+    let mut compression: Vec<Option<String>> = vec![];
+    let mut encodings: Vec<Series> = vec![];
+    let mut num_values: Vec<Option<i64>> = vec![];
+    let mut compressed_size: Vec<Option<i64>> = vec![];
+    let mut uncompressed_size: Vec<Option<i64>> = vec![];
+    let mut null_count: Vec<Option<i64>> = vec![];
+    let mut distinct_count: Vec<Option<i64>> = vec![];
+    let mut min: Vec<Option<String>> = vec![];
+    let mut max: Vec<Option<String>> = vec![];
+
+    let mut push_schema = |descriptor: &ColumnDescriptor| {
+        let primitive_type = &descriptor.descriptor.primitive_type;
+
+        column.push(descriptor.path_in_schema.join("."));
+        physical_type.push(format!("{:?}", primitive_type.physical_type));
+        logical_type.push(
+            primitive_type
+                .logical_type
+                .as_ref()
+                .map(|logical_type| format!("{logical_type:?}")),
+        );
+    };
+
+    for (index, group) in metadata.row_groups.iter().enumerate() {
+        for chunk in group.columns() {
+            push_schema(chunk.descriptor());
+
+            row_group.push(Some(index as u32));
+            row_group_num_rows.push(Some(group.num_rows() as u64));
+            row_group_byte_size.push(Some(group.total_byte_size() as u64));
+            compression.push(Some(format!("{:?}", chunk.compression()).to_lowercase()));
+            encodings.push(Series::new(
+                PlSmallStr::EMPTY,
+                chunk
+                    .column_encoding()
+                    .iter()
+                    .map(|encoding| format!("{encoding:?}"))
+                    .collect::<Vec<String>>(),
+            ));
+            num_values.push(Some(chunk.num_values()));
+            compressed_size.push(Some(chunk.compressed_size()));
+            uncompressed_size.push(Some(chunk.uncompressed_size()));
+
+            // Invalid statistics are ignored, since readers ignore them too.
+            let (nulls, distinct, min_value, max_value) =
+                match chunk.statistics().and_then(Result::ok) {
+                    Some(statistics) => column_statistics(&statistics),
+                    None => (None, None, None, None),
+                };
+
+            null_count.push(nulls);
+            distinct_count.push(distinct);
+            min.push(min_value);
+            max.push(max_value);
+        }
+    }
+
+    // A file without row groups still has a schema, which is given
+    // with a row per column, and nil for the fields of the chunks.
+    if metadata.row_groups.is_empty() {
+        for descriptor in metadata.schema().columns() {
+            push_schema(descriptor);
+
+            row_group.push(None);
+            row_group_num_rows.push(None);
+            row_group_byte_size.push(None);
+            compression.push(None);
+            encodings.push(Series::new_empty(PlSmallStr::EMPTY, &DataType::String));
+            num_values.push(None);
+            compressed_size.push(None);
+            uncompressed_size.push(None);
+            null_count.push(None);
+            distinct_count.push(None);
+            min.push(None);
+            max.push(None);
+        }
+    }
+
+    let height = row_group.len();
+
+    let df = df!(
+        "num_rows" => vec![metadata.num_rows as u64; height],
+        "created_by" => vec![metadata.created_by.clone(); height],
+        "row_group" => row_group,
+        "row_group_num_rows" => row_group_num_rows,
+        "row_group_byte_size" => row_group_byte_size,
+        "column" => column,
+        "physical_type" => physical_type,
+        "logical_type" => logical_type,
+        "compression" => compression,
+        "encodings" => encodings,
+        "num_values" => num_values,
+        "compressed_size" => compressed_size,
+        "uncompressed_size" => uncompressed_size,
+        "null_count" => null_count,
+        "distinct_count" => distinct_count,
+        "min" => min,
+        "max" => max
+    )?;
+
+    Ok(ExDataFrame::new(df))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn df_parquet_metadata(filename: &str) -> Result<ExDataFrame, ExplorerError> {
+    let file = File::open(filename)?;
+    let mut reader = ParquetReader::new(BufReader::new(file));
+
+    parquet_metadata_to_df(reader.get_metadata()?)
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn df_load_parquet_metadata(binary: Binary) -> Result<ExDataFrame, ExplorerError> {
+    let mut reader = ParquetReader::new(Cursor::new(binary.as_slice()));
+
+    parquet_metadata_to_df(reader.get_metadata()?)
+}
+
+// Only the footer is downloaded, with ranged requests.
+#[cfg(feature = "cloud")]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn df_parquet_metadata_cloud(ex_entry: ExCloudEntry) -> Result<ExDataFrame, ExplorerError> {
+    let cloud_reader = build_cloud_reader(&ex_entry)?;
+    let mut reader = ParquetReader::new(cloud_reader);
+
+    parquet_metadata_to_df(reader.get_metadata()?)
+}
+
+// ============ Partitioned writes ============ //
+//
+// The dataframe is split by the values of the partition columns, and each
+// part is written to its own file in a Hive-style directory tree, such as
+// `year=2024/month=03/part-0.parquet`. The partition columns are not written
+// to the files, since readers recover them from the paths.
+
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+// Percent-encodes the characters that are not safe in a path segment,
+// including the `/` and `=` that would break the Hive layout.
+fn encode_hive_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+
+    for byte in value.bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b' ' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+
+    encoded
+}
+
+// Calls `write` with the relative path and the dataframe of each partition.
+fn write_partitioned<F>(
+    df: &DataFrame,
+    partition_by: &[String],
+    extension: &str,
+    mut write: F,
+) -> Result<(), ExplorerError>
+where
+    F: FnMut(&str, &mut DataFrame) -> Result<(), ExplorerError>,
+{
+    if partition_by.is_empty() {
+        return Err(ExplorerError::Other(
+            "partitioned writes need at least one partition column".into(),
+        ));
+    }
+
+    for mut partition in df.partition_by_stable(partition_by.to_vec(), true)? {
+        let mut segments = Vec::with_capacity(partition_by.len() + 1);
+
+        for name in partition_by {
+            let key = partition.drop_in_place(name)?.cast(&DataType::String)?;
+            let value = match key.str()?.get(0) {
+                Some(value) => encode_hive_value(value),
+                None => HIVE_DEFAULT_PARTITION.to_string(),
+            };
+
+            segments.push(format!("{}={value}", encode_hive_value(name)));
+        }
+
+        segments.push(format!("part-0.{extension}"));
+        write(&segments.join("/"), &mut partition)?;
+    }
+
+    Ok(())
+}
+
+fn create_partition_file(dirname: &str, path: &str) -> Result<BufWriter<File>, ExplorerError> {
+    let path = std::path::Path::new(dirname).join(path);
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    Ok(BufWriter::new(File::create(path)?))
+}
+
+pub fn write_parquet_partitioned(
+    df: &DataFrame,
+    dirname: &str,
+    partition_by: &[String],
+    compression: ParquetCompression,
+    ex_options: &ExParquetWriteOptions,
+) -> Result<(), ExplorerError> {
+    write_partitioned(df, partition_by, "parquet", |path, partition| {
+        let mut buf_writer = create_partition_file(dirname, path)?;
+
+        parquet_writer(&mut buf_writer, compression, ex_options).finish(partition)?;
+        Ok(())
+    })
+}
+
+pub fn write_ipc_partitioned(
+    df: &DataFrame,
+    dirname: &str,
+    partition_by: &[String],
+    compression: Option<IpcCompression>,
+) -> Result<(), ExplorerError> {
+    write_partitioned(df, partition_by, "ipc", |path, partition| {
+        let mut buf_writer = create_partition_file(dirname, path)?;
+
+        IpcWriter::new(&mut buf_writer)
+            .with_compression(compression)
+            .finish(partition)?;
+        Ok(())
+    })
+}
+
+// The key of the entry is used as the prefix of the partition files.
+#[cfg(feature = "cloud")]
+fn build_partition_cloud_writer(
+    ex_entry: &ExCloudEntry,
+    path: &str,
+) -> Result<CloudWriter, ExplorerError> {
+    let (object_store, prefix) = build_object_store(ex_entry)?;
+    let key = format!("{prefix}/{path}");
+
+    CloudWriter::new(object_store, key.into())
+}
+
+#[cfg(feature = "cloud")]
+pub fn write_parquet_partitioned_cloud(
+    df: &DataFrame,
+    ex_entry: &ExCloudEntry,
+    partition_by: &[String],
+    compression: ParquetCompression,
+    ex_options: &ExParquetWriteOptions,
+) -> Result<(), ExplorerError> {
+    write_partitioned(df, partition_by, "parquet", |path, partition| {
+        let mut cloud_writer = build_partition_cloud_writer(ex_entry, path)?;
+
+        parquet_writer(&mut cloud_writer, compression, ex_options).finish(partition)?;
+
+        let _ = cloud_writer.finish()?;
+        Ok(())
+    })
+}
+
+#[cfg(feature = "cloud")]
+pub fn write_ipc_partitioned_cloud(
+    df: &DataFrame,
+    ex_entry: &ExCloudEntry,
+    partition_by: &[String],
+    compression: Option<IpcCompression>,
+) -> Result<(), ExplorerError> {
+    write_partitioned(df, partition_by, "ipc", |path, partition| {
+        let mut cloud_writer = build_partition_cloud_writer(ex_entry, path)?;
+
+        IpcWriter::new(&mut cloud_writer)
+            .with_compression(compression)
+            .finish(partition)?;
+
+        let _ = cloud_writer.finish()?;
+        Ok(())
+    })
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn df_to_parquet_partitioned(
+    data: ExDataFrame,
+    dirname: &str,
+    partition_by: Vec<String>,
+    ex_compression: ExParquetCompression,
+    ex_options: ExParquetWriteOptions,
+) -> Result<(), ExplorerError> {
+    let compression = ParquetCompression::try_from(ex_compression)?;
+
+    write_parquet_partitioned(&data, dirname, &partition_by, compression, &ex_options)
+}
+
+#[cfg(feature = "cloud")]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn df_to_parquet_partitioned_cloud(
+    data: ExDataFrame,
+    ex_entry: ExCloudEntry,
+    partition_by: Vec<String>,
+    ex_compression: ExParquetCompression,
+    ex_options: ExParquetWriteOptions,
+) -> Result<(), ExplorerError> {
+    let compression = ParquetCompression::try_from(ex_compression)?;
+
+    write_parquet_partitioned_cloud(&data, &ex_entry, &partition_by, compression, &ex_options)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn df_to_ipc_partitioned(
+    data: ExDataFrame,
+    dirname: &str,
+    partition_by: Vec<String>,
+    compression: Option<&str>,
+) -> Result<(), ExplorerError> {
+    let compression = match compression {
+        Some(algo) => Some(decode_ipc_compression(algo)?),
+        None => None,
+    };
+
+    write_ipc_partitioned(&data, dirname, &partition_by, compression)
+}
+
+#[cfg(feature = "cloud")]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn df_to_ipc_partitioned_cloud(
+    data: ExDataFrame,
+    ex_entry: ExCloudEntry,
+    partition_by: Vec<String>,
+    compression: Option<&str>,
+) -> Result<(), ExplorerError> {
+    let compression = match compression {
+        Some(algo) => Some(decode_ipc_compression(algo)?),
+        None => None,
+    };
+
+    write_ipc_partitioned_cloud(&data, &ex_entry, &partition_by, compression)
 }
 
 // ============ IPC ============ //
@@ -348,6 +1112,21 @@ pub fn df_from_ipc(
     Ok(ExDataFrame::new(reader.finish()?))
 }
 
+#[cfg(feature = "cloud")]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn df_from_ipc_cloud(
+    ex_entry: ExCloudEntry,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+) -> Result<ExDataFrame, ExplorerError> {
+    let cloud_reader = build_cloud_reader(&ex_entry)?;
+    let reader = IpcReader::new(cloud_reader)
+        .with_columns(columns)
+        .with_projection(projection);
+
+    Ok(ExDataFrame::new(reader.finish()?))
+}
+
 #[rustler::nif(schedule = "DirtyIo")]
 pub fn df_to_ipc(
     data: ExDataFrame,
@@ -367,11 +1146,11 @@ pub fn df_to_ipc(
     Ok(())
 }
 
-#[cfg(feature = "aws")]
+#[cfg(feature = "cloud")]
 #[rustler::nif(schedule = "DirtyIo")]
 pub fn df_to_ipc_cloud(
     data: ExDataFrame,
-    ex_entry: ExS3Entry,
+    ex_entry: ExCloudEntry,
     compression: Option<&str>,
 ) -> Result<(), ExplorerError> {
     let compression = match compression {
@@ -379,7 +1158,7 @@ pub fn df_to_ipc_cloud(
         None => None,
     };
 
-    let mut cloud_writer = build_aws_s3_cloud_writer(ex_entry)?;
+    let mut cloud_writer = build_cloud_writer(&ex_entry)?;
 
     IpcWriter::new(&mut cloud_writer)
         .with_compression(compression)
@@ -427,7 +1206,7 @@ pub fn df_load_ipc(
     Ok(ExDataFrame::new(reader.finish()?))
 }
 
-fn decode_ipc_compression(compression: &str) -> Result<IpcCompression, ExplorerError> {
+pub fn decode_ipc_compression(compression: &str) -> Result<IpcCompression, ExplorerError> {
     match compression {
         "lz4" => Ok(IpcCompression::LZ4),
         "zstd" => Ok(IpcCompression::ZSTD),
@@ -454,6 +1233,21 @@ pub fn df_from_ipc_stream(
     Ok(ExDataFrame::new(reader.finish()?))
 }
 
+#[cfg(feature = "cloud")]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn df_from_ipc_stream_cloud(
+    ex_entry: ExCloudEntry,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+) -> Result<ExDataFrame, ExplorerError> {
+    let cloud_reader = build_cloud_reader(&ex_entry)?;
+    let reader = IpcStreamReader::new(cloud_reader)
+        .with_columns(columns)
+        .with_projection(projection);
+
+    Ok(ExDataFrame::new(reader.finish()?))
+}
+
 #[rustler::nif(schedule = "DirtyIo")]
 pub fn df_to_ipc_stream(
     data: ExDataFrame,
@@ -472,11 +1266,11 @@ pub fn df_to_ipc_stream(
     Ok(())
 }
 
-#[cfg(feature = "aws")]
+#[cfg(feature = "cloud")]
 #[rustler::nif(schedule = "DirtyIo")]
 pub fn df_to_ipc_stream_cloud(
     data: ExDataFrame,
-    ex_entry: ExS3Entry,
+    ex_entry: ExCloudEntry,
     compression: Option<&str>,
 ) -> Result<(), ExplorerError> {
     let compression = match compression {
@@ -484,7 +1278,7 @@ pub fn df_to_ipc_stream_cloud(
         None => None,
     };
 
-    let mut cloud_writer = build_aws_s3_cloud_writer(ex_entry)?;
+    let mut cloud_writer = build_cloud_writer(&ex_entry)?;
 
     IpcStreamWriter::new(&mut cloud_writer)
         .with_compression(compression)
@@ -541,40 +1335,102 @@ pub fn df_from_ndjson(
     infer_schema_length: Option<usize>,
     batch_size: usize,
 ) -> Result<ExDataFrame, ExplorerError> {
-    let file = File::open(filename)?;
-    let buf_reader = BufReader::new(file);
+    let df = read_ndjson_file(filename, infer_schema_length, batch_size)?;
+
+    Ok(ExDataFrame::new(df))
+}
+
+#[cfg(feature = "ndjson")]
+pub fn read_ndjson_file(
+    filename: &str,
+    infer_schema_length: Option<usize>,
+    batch_size: usize,
+) -> Result<DataFrame, ExplorerError> {
+    // Unlike the CSV reader, the JSON reader does not decompress
+    // by itself, so we check the magic bytes first and only load
+    // the file into memory when it has to be decompressed.
+    let mut file = File::open(filename)?;
+    let mut magic = Vec::with_capacity(4);
+    (&file).take(4).read_to_end(&mut magic)?;
+    file.rewind()?;
+
+    if is_compressed(&magic) {
+        let mut bytes = vec![];
+        file.read_to_end(&mut bytes)?;
+        let mut decompressed = vec![];
+        let cursor = Cursor::new(maybe_decompress_bytes(&bytes, &mut decompressed)?);
+        read_ndjson(cursor, infer_schema_length, batch_size)
+    } else {
+        read_ndjson(file, infer_schema_length, batch_size)
+    }
+}
+
+#[cfg(feature = "ndjson")]
+fn read_ndjson<R: MmapBytesReader>(
+    reader: R,
+    infer_schema_length: Option<usize>,
+    batch_size: usize,
+) -> Result<DataFrame, ExplorerError> {
     let batch_size = NonZeroUsize::new(batch_size).ok_or(ExplorerError::Other(
         "\"batch_size\" expected to be non zero.".to_string(),
     ))?;
-    let reader = JsonReader::new(buf_reader)
+    let reader = JsonReader::new(reader)
         .with_json_format(JsonFormat::JsonLines)
         .with_batch_size(batch_size)
         .infer_schema_len(infer_schema_length.and_then(NonZeroUsize::new));
 
-    Ok(ExDataFrame::new(reader.finish()?))
+    Ok(reader.finish()?)
+}
+
+#[cfg(all(feature = "ndjson", feature = "cloud"))]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn df_from_ndjson_cloud(
+    ex_entry: ExCloudEntry,
+    infer_schema_length: Option<usize>,
+    batch_size: usize,
+) -> Result<ExDataFrame, ExplorerError> {
+    let mut bytes = vec![];
+    build_cloud_reader(&ex_entry)?.read_to_end(&mut bytes)?;
+    let mut decompressed = vec![];
+    let cursor = Cursor::new(maybe_decompress_bytes(&bytes, &mut decompressed)?);
+    let df = read_ndjson(cursor, infer_schema_length, batch_size)?;
+
+    Ok(ExDataFrame::new(df))
 }
 
 #[cfg(feature = "ndjson")]
 #[rustler::nif(schedule = "DirtyIo")]
-pub fn df_to_ndjson(data: ExDataFrame, filename: &str) -> Result<(), ExplorerError> {
+pub fn df_to_ndjson(
+    data: ExDataFrame,
+    filename: &str,
+    compression: Option<ExTextCompression>,
+) -> Result<(), ExplorerError> {
     let file = File::create(filename)?;
-    let mut buf_writer = BufWriter::new(file);
+    let mut writer = CompressedWriter::new(BufWriter::new(file), compression)?;
 
-    JsonWriter::new(&mut buf_writer)
+    JsonWriter::new(&mut writer)
         .with_json_format(JsonFormat::JsonLines)
         .finish(&mut data.clone())?;
+    writer.finish()?.flush()?;
     Ok(())
 }
 
-#[cfg(all(feature = "ndjson", feature = "aws"))]
+#[cfg(all(feature = "ndjson", feature = "cloud"))]
 #[rustler::nif(schedule = "DirtyIo")]
-pub fn df_to_ndjson_cloud(data: ExDataFrame, ex_entry: ExS3Entry) -> Result<(), ExplorerError> {
-    let mut cloud_writer = build_aws_s3_cloud_writer(ex_entry)?;
+pub fn df_to_ndjson_cloud(
+    data: ExDataFrame,
+    ex_entry: ExCloudEntry,
+    compression: Option<ExTextCompression>,
+) -> Result<(), ExplorerError> {
+    let mut cloud_writer = build_cloud_writer(&ex_entry)?;
+    let mut writer = CompressedWriter::new(&mut cloud_writer, compression)?;
 
-    JsonWriter::new(&mut cloud_writer)
+    JsonWriter::new(&mut writer)
         .with_json_format(JsonFormat::JsonLines)
         .finish(&mut data.clone())?;
 
+    writer.finish()?;
+
     let _ = cloud_writer.finish()?;
 
     Ok(())
@@ -582,13 +1438,19 @@ pub fn df_to_ndjson_cloud(data: ExDataFrame, ex_entry: ExS3Entry) -> Result<(),
 
 #[cfg(feature = "ndjson")]
 #[rustler::nif(schedule = "DirtyCpu")]
-pub fn df_dump_ndjson(env: Env, data: ExDataFrame) -> Result<Binary, ExplorerError> {
-    let mut buf = vec![];
+pub fn df_dump_ndjson(
+    env: Env,
+    data: ExDataFrame,
+    compression: Option<ExTextCompression>,
+) -> Result<Binary, ExplorerError> {
+    let mut writer = CompressedWriter::new(vec![], compression)?;
 
-    JsonWriter::new(&mut buf)
+    JsonWriter::new(&mut writer)
         .with_json_format(JsonFormat::JsonLines)
         .finish(&mut data.clone())?;
 
+    let buf = writer.finish()?;
+
     let mut values_binary = NewBinary::new(env, buf.len());
     values_binary.copy_from_slice(&buf);
 
@@ -602,16 +1464,262 @@ pub fn df_load_ndjson(
     infer_schema_length: Option<usize>,
     batch_size: usize,
 ) -> Result<ExDataFrame, ExplorerError> {
-    let cursor = Cursor::new(binary.as_slice());
-    let batch_size = NonZeroUsize::new(batch_size).ok_or(ExplorerError::Other(
-        "\"batch_size\" expected to be non zero.".to_string(),
-    ))?;
-    let reader = JsonReader::new(cursor)
-        .with_json_format(JsonFormat::JsonLines)
-        .with_batch_size(batch_size)
-        .infer_schema_len(infer_schema_length.and_then(NonZeroUsize::new));
+    let mut decompressed = vec![];
+    let bytes = maybe_decompress_bytes(binary.as_slice(), &mut decompressed)?;
+    let df = read_ndjson(Cursor::new(bytes), infer_schema_length, batch_size)?;
 
-    Ok(ExDataFrame::new(reader.finish()?))
+    Ok(ExDataFrame::new(df))
+}
+
+// ============ Batched reading ============ //
+
+// The batched readers read local files only, and return the
+// rows in dataframes of `batch_size` rows from `reader_next_batch`.
+
+fn batched_reader_resource(
+    source: BatchSource,
+    batch_size: usize,
+) -> Result<ResourceArc<ExBatchedReaderRef>, ExplorerError> {
+    let reader = BatchedReader::new(source, batch_size)?;
+    Ok(ResourceArc::new(ExBatchedReaderRef(Mutex::new(reader))))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+#[allow(clippy::too_many_arguments)]
+pub fn csv_batched_reader_new(
+    filename: &str,
+    batch_size: usize,
+    infer_schema_length: Option<usize>,
+    has_header: bool,
+    skip_rows: usize,
+    skip_rows_after_header: usize,
+    projection: Option<Vec<usize>>,
+    delimiter_as_byte: u8,
+    column_names: Option<Vec<String>>,
+    dtypes: Vec<(&str, ExSeriesDtype)>,
+    encoding: &str,
+    null_vals: Vec<String>,
+    parse_dates: bool,
+    eol_delimiter: Option<u8>,
+    dialect: ExCsvDialect,
+) -> Result<ResourceArc<ExBatchedReaderRef>, ExplorerError> {
+    ensure_uncompressed(filename)?;
+    let file = File::open(filename)?;
+
+    let reader = csv_read_options(
+        infer_schema_length,
+        has_header,
+        None,
+        skip_rows,
+        skip_rows_after_header,
+        projection,
+        delimiter_as_byte,
+        false,
+        column_names,
+        dtypes,
+        encoding,
+        null_vals,
+        parse_dates,
+        eol_delimiter,
+        &dialect,
+    )?
+    // A zero batch size is rejected when the reader is created below.
+    .with_chunk_size(batch_size.max(1))
+    .into_reader_with_file_handle(Box::new(file) as Box<dyn MmapBytesReader>)
+    .batched(None)?;
+
+    batched_reader_resource(BatchSource::Csv(reader), batch_size)
+}
+
+#[cfg(feature = "ndjson")]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn ndjson_batched_reader_new(
+    filename: &str,
+    batch_size: usize,
+    infer_schema_length: Option<usize>,
+) -> Result<ResourceArc<ExBatchedReaderRef>, ExplorerError> {
+    let source = BatchSource::ndjson(filename, infer_schema_length)?;
+    batched_reader_resource(source, batch_size)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn parquet_batched_reader_new(
+    filename: &str,
+    batch_size: usize,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+) -> Result<ResourceArc<ExBatchedReaderRef>, ExplorerError> {
+    let source = BatchSource::parquet(filename, columns, projection, batch_size)?;
+    batched_reader_resource(source, batch_size)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn ipc_stream_batched_reader_new(
+    filename: &str,
+    batch_size: usize,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+) -> Result<ResourceArc<ExBatchedReaderRef>, ExplorerError> {
+    let source = BatchSource::ipc_stream(filename, columns, projection)?;
+    batched_reader_resource(source, batch_size)
+}
+
+// Returns `nil` once all the rows were read.
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn reader_next_batch(
+    reader: ResourceArc<ExBatchedReaderRef>,
+) -> Result<Option<ExDataFrame>, ExplorerError> {
+    let mut reader = reader
+        .0
+        .lock()
+        .map_err(|_| ExplorerError::Other("the batched reader is poisoned".to_string()))?;
+
+    Ok(reader.next_batch()?.map(ExDataFrame::new))
+}
+
+// ============ Batched writing ============ //
+
+// The batched writers are given dataframes by `writer_write_batch`,
+// and complete the file, or the upload of the object, in `writer_close`.
+
+fn open_sink(ex_entry: &ExCloudEntry) -> Result<Sink, ExplorerError> {
+    match ex_entry {
+        ExCloudEntry::Local(entry) => Ok(Sink::File(
+            BufWriter::new(File::create(&entry.path)?),
+            PathBuf::from(&entry.path),
+        )),
+        #[cfg(feature = "cloud")]
+        _ => Ok(Sink::Cloud(build_cloud_writer(ex_entry)?)),
+        #[cfg(not(feature = "cloud"))]
+        _ => Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
+            This is mostly due to this feature being incompatible with your computer's architecture. \
+            Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string())),
+    }
+}
+
+fn batched_writer_resource(
+    format_writer: FormatWriter,
+    output: SharedWriter,
+) -> ResourceArc<ExBatchedWriterRef> {
+    let writer = BatchedWriter::new(format_writer, output);
+    ResourceArc::new(ExBatchedWriterRef(Mutex::new(writer)))
+}
+
+// Each batch is written as its own row group.
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn parquet_writer_new(
+    ex_entry: ExCloudEntry,
+    ex_compression: ExParquetCompression,
+    ex_options: ExParquetWriteOptions,
+) -> Result<ResourceArc<ExBatchedWriterRef>, ExplorerError> {
+    let compression = ParquetCompression::try_from(ex_compression)?;
+    let output = SharedWriter::new(open_sink(&ex_entry)?, None)?;
+    let writer = parquet_writer(output.clone(), compression, &ex_options);
+
+    Ok(batched_writer_resource(
+        FormatWriter::Parquet(writer, ex_options.row_group_size),
+        output,
+    ))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn ipc_writer_new(
+    ex_entry: ExCloudEntry,
+    compression: Option<&str>,
+) -> Result<ResourceArc<ExBatchedWriterRef>, ExplorerError> {
+    let compression = match compression {
+        Some(algo) => Some(decode_ipc_compression(algo)?),
+        None => None,
+    };
+
+    let output = SharedWriter::new(open_sink(&ex_entry)?, None)?;
+    let writer = IpcWriter::new(output.clone()).with_compression(compression);
+
+    Ok(batched_writer_resource(FormatWriter::Ipc(writer), output))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn ipc_stream_writer_new(
+    ex_entry: ExCloudEntry,
+    compression: Option<&str>,
+) -> Result<ResourceArc<ExBatchedWriterRef>, ExplorerError> {
+    let compression = match compression {
+        Some(algo) => Some(decode_ipc_compression(algo)?),
+        None => None,
+    };
+
+    let output = SharedWriter::new(open_sink(&ex_entry)?, None)?;
+    let writer = ipc_write::StreamWriter::new(
+        output.clone(),
+        ipc_write::WriteOptions {
+            compression: compression.map(Into::into),
+        },
+    );
+
+    Ok(batched_writer_resource(
+        FormatWriter::IpcStream(writer),
+        output,
+    ))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn csv_writer_new(
+    ex_entry: ExCloudEntry,
+    include_headers: bool,
+    delimiter: u8,
+    options: ExCsvWriteOptions,
+    compression: Option<ExTextCompression>,
+) -> Result<ResourceArc<ExBatchedWriterRef>, ExplorerError> {
+    let output = SharedWriter::new(open_sink(&ex_entry)?, compression)?;
+    let writer = options
+        .csv_writer(CsvWriter::new(output.clone()))
+        .include_header(include_headers)
+        .with_separator(delimiter);
+
+    Ok(batched_writer_resource(FormatWriter::Csv(writer), output))
+}
+
+#[cfg(feature = "ndjson")]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn ndjson_writer_new(
+    ex_entry: ExCloudEntry,
+    compression: Option<ExTextCompression>,
+) -> Result<ResourceArc<ExBatchedWriterRef>, ExplorerError> {
+    let output = SharedWriter::new(open_sink(&ex_entry)?, compression)?;
+    let writer = JsonBatchedWriter::new(output.clone());
+
+    Ok(batched_writer_resource(
+        FormatWriter::Ndjson(writer),
+        output,
+    ))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn writer_write_batch(
+    writer: ResourceArc<ExBatchedWriterRef>,
+    data: ExDataFrame,
+) -> Result<(), ExplorerError> {
+    lock_batched_writer(&writer)?.write_batch(&data)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn writer_close(writer: ResourceArc<ExBatchedWriterRef>) -> Result<(), ExplorerError> {
+    lock_batched_writer(&writer)?.close()
+}
+
+// Removes the file, or cancels the upload, of a writer that failed.
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn writer_abort(writer: ResourceArc<ExBatchedWriterRef>) -> Result<(), ExplorerError> {
+    lock_batched_writer(&writer)?.abort()
+}
+
+fn lock_batched_writer(
+    writer: &ExBatchedWriterRef,
+) -> Result<std::sync::MutexGuard<'_, BatchedWriter>, ExplorerError> {
+    writer
+        .0
+        .lock()
+        .map_err(|_| ExplorerError::Other("the batched writer is poisoned".to_string()))
 }
 
 // ============ For when the feature is not enabled ============ //
@@ -630,7 +1738,11 @@ pub fn df_from_ndjson(
 
 #[cfg(not(feature = "ndjson"))]
 #[rustler::nif]
-pub fn df_to_ndjson(_data: ExDataFrame, _filename: &str) -> Result<(), ExplorerError> {
+pub fn df_to_ndjson(
+    _data: ExDataFrame,
+    _filename: &str,
+    _compression: Option<ExTextCompression>,
+) -> Result<(), ExplorerError> {
     Err(ExplorerError::Other("Explorer was compiled without the \"ndjson\" feature enabled. \
         This is mostly due to this feature being incompatible with your computer's architecture. \
         Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
@@ -638,7 +1750,10 @@ pub fn df_to_ndjson(_data: ExDataFrame, _filename: &str) -> Result<(), ExplorerE
 
 #[cfg(not(feature = "ndjson"))]
 #[rustler::nif]
-pub fn df_dump_ndjson(_data: ExDataFrame) -> Result<Binary<'static>, ExplorerError> {
+pub fn df_dump_ndjson(
+    _data: ExDataFrame,
+    _compression: Option<ExTextCompression>,
+) -> Result<Binary<'static>, ExplorerError> {
     Err(ExplorerError::Other("Explorer was compiled without the \"ndjson\" feature enabled. \
         This is mostly due to this feature being incompatible with your computer's architecture. \
         Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
@@ -656,59 +1771,200 @@ pub fn df_load_ndjson(
         Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
 }
 
-#[cfg(not(feature = "aws"))]
+#[cfg(not(feature = "ndjson"))]
+#[rustler::nif]
+pub fn ndjson_batched_reader_new(
+    _filename: &str,
+    _batch_size: usize,
+    _infer_schema_length: Option<usize>,
+) -> Result<ResourceArc<ExBatchedReaderRef>, ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"ndjson\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+#[cfg(not(feature = "ndjson"))]
+#[rustler::nif]
+pub fn ndjson_writer_new(
+    _ex_entry: ExCloudEntry,
+    _compression: Option<ExTextCompression>,
+) -> Result<ResourceArc<ExBatchedWriterRef>, ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"ndjson\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+#[cfg(not(feature = "cloud"))]
 #[rustler::nif]
 pub fn df_to_parquet_cloud(
     _data: ExDataFrame,
-    _ex_entry: ExS3Entry,
+    _ex_entry: ExCloudEntry,
     _ex_compression: ExParquetCompression,
+    _ex_options: ExParquetWriteOptions,
 ) -> Result<(), ExplorerError> {
-    Err(ExplorerError::Other("Explorer was compiled without the \"aws\" feature enabled. \
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
         This is mostly due to this feature being incompatible with your computer's architecture. \
         Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
 }
 
-#[cfg(not(feature = "aws"))]
+#[cfg(not(feature = "cloud"))]
 #[rustler::nif]
 pub fn df_to_csv_cloud(
     _data: ExDataFrame,
-    _ex_entry: ExS3Entry,
+    _ex_entry: ExCloudEntry,
     _has_headers: bool,
     _delimiter: u8,
+    _options: ExCsvWriteOptions,
+    _compression: Option<ExTextCompression>,
 ) -> Result<(), ExplorerError> {
-    Err(ExplorerError::Other("Explorer was compiled without the \"aws\" feature enabled. \
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
         This is mostly due to this feature being incompatible with your computer's architecture. \
         Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
 }
 
-#[cfg(not(feature = "aws"))]
+#[cfg(not(feature = "cloud"))]
 #[rustler::nif]
 pub fn df_to_ipc_cloud(
     _data: ExDataFrame,
-    _ex_entry: ExS3Entry,
+    _ex_entry: ExCloudEntry,
     _compression: Option<&str>,
 ) -> Result<(), ExplorerError> {
-    Err(ExplorerError::Other("Explorer was compiled without the \"aws\" feature enabled. \
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
         This is mostly due to this feature being incompatible with your computer's architecture. \
         Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
 }
 
-#[cfg(not(feature = "aws"))]
+#[cfg(not(feature = "cloud"))]
 #[rustler::nif]
 pub fn df_to_ipc_stream_cloud(
     _data: ExDataFrame,
-    _ex_entry: ExS3Entry,
+    _ex_entry: ExCloudEntry,
+    _compression: Option<&str>,
+) -> Result<(), ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+#[cfg(not(feature = "cloud"))]
+#[rustler::nif]
+pub fn df_to_parquet_partitioned_cloud(
+    _data: ExDataFrame,
+    _ex_entry: ExCloudEntry,
+    _partition_by: Vec<String>,
+    _ex_compression: ExParquetCompression,
+    _ex_options: ExParquetWriteOptions,
+) -> Result<(), ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+#[cfg(not(feature = "cloud"))]
+#[rustler::nif]
+pub fn df_to_ipc_partitioned_cloud(
+    _data: ExDataFrame,
+    _ex_entry: ExCloudEntry,
+    _partition_by: Vec<String>,
     _compression: Option<&str>,
 ) -> Result<(), ExplorerError> {
-    Err(ExplorerError::Other("Explorer was compiled without the \"aws\" feature enabled. \
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+#[cfg(not(feature = "cloud"))]
+#[rustler::nif]
+pub fn df_parquet_metadata_cloud(_ex_entry: ExCloudEntry) -> Result<ExDataFrame, ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
         This is mostly due to this feature being incompatible with your computer's architecture. \
         Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
 }
 
-#[cfg(not(any(feature = "ndjson", feature = "aws")))]
+#[cfg(not(all(feature = "ndjson", feature = "cloud")))]
 #[rustler::nif(schedule = "DirtyIo")]
-pub fn df_to_ndjson_cloud(_data: ExDataFrame, _ex_entry: ExS3Entry) -> Result<(), ExplorerError> {
-    Err(ExplorerError::Other("Explorer was compiled without the \"aws\" and \"ndjson\" features enabled. \
+pub fn df_to_ndjson_cloud(
+    _data: ExDataFrame,
+    _ex_entry: ExCloudEntry,
+    _compression: Option<ExTextCompression>,
+) -> Result<(), ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" and \"ndjson\" features enabled. \
+        This is mostly due to these feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+#[cfg(not(feature = "cloud"))]
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn df_from_csv_cloud(
+    _ex_entry: ExCloudEntry,
+    _infer_schema_length: Option<usize>,
+    _has_header: bool,
+    _stop_after_n_rows: Option<usize>,
+    _skip_rows: usize,
+    _skip_rows_after_header: usize,
+    _projection: Option<Vec<usize>>,
+    _delimiter_as_byte: u8,
+    _do_rechunk: bool,
+    _column_names: Option<Vec<String>>,
+    _dtypes: Vec<(&str, ExSeriesDtype)>,
+    _encoding: &str,
+    _null_vals: Vec<String>,
+    _parse_dates: bool,
+    _eol_delimiter: Option<u8>,
+    _dialect: ExCsvDialect,
+) -> Result<ExDataFrame, ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+#[cfg(not(feature = "cloud"))]
+#[rustler::nif]
+pub fn df_from_parquet_cloud(
+    _ex_entry: ExCloudEntry,
+    _stop_after_n_rows: Option<usize>,
+    _column_names: Option<Vec<String>>,
+    _projection: Option<Vec<usize>>,
+    _rechunk: bool,
+) -> Result<ExDataFrame, ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+#[cfg(not(feature = "cloud"))]
+#[rustler::nif]
+pub fn df_from_ipc_cloud(
+    _ex_entry: ExCloudEntry,
+    _columns: Option<Vec<String>>,
+    _projection: Option<Vec<usize>>,
+) -> Result<ExDataFrame, ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+#[cfg(not(feature = "cloud"))]
+#[rustler::nif]
+pub fn df_from_ipc_stream_cloud(
+    _ex_entry: ExCloudEntry,
+    _columns: Option<Vec<String>>,
+    _projection: Option<Vec<usize>>,
+) -> Result<ExDataFrame, ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+#[cfg(not(all(feature = "ndjson", feature = "cloud")))]
+#[rustler::nif]
+pub fn df_from_ndjson_cloud(
+    _ex_entry: ExCloudEntry,
+    _infer_schema_length: Option<usize>,
+    _batch_size: usize,
+) -> Result<ExDataFrame, ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" and \"ndjson\" features enabled. \
         This is mostly due to these feature being incompatible with your computer's architecture. \
         Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
 }
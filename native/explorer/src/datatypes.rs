@@ -1,21 +1,28 @@
 mod ex_dtypes;
 
 use crate::atoms;
+use crate::batched_reader::BatchedReader;
+use crate::batched_writer::BatchedWriter;
 use crate::ExplorerError;
 use chrono::prelude::*;
 
 #[cfg(feature = "cloud")]
 use polars::prelude::cloud::CloudOptions;
 use polars::prelude::*;
-use rustler::{Atom, NifStruct, NifTaggedEnum, Resource, ResourceArc};
+use rustler::{Atom, NifMap, NifStruct, NifTaggedEnum, Resource, ResourceArc};
 use std::fmt;
 use std::ops::Deref;
+use std::sync::Mutex;
 
 #[cfg(feature = "aws")]
 use std::str::FromStr;
 
 #[cfg(feature = "aws")]
 use polars::prelude::cloud::AmazonS3ConfigKey as S3Key;
+#[cfg(feature = "azure")]
+use polars::prelude::cloud::AzureConfigKey as AzureKey;
+#[cfg(feature = "gcp")]
+use polars::prelude::cloud::GoogleConfigKey as GcsKey;
 
 use chrono_tz::{OffsetComponents, OffsetName, Tz};
 
@@ -41,6 +48,18 @@ pub struct ExSeriesRef(pub Series);
 #[rustler::resource_impl]
 impl Resource for ExSeriesRef {}
 
+// Each read moves the batched reader forward, so it is behind a lock.
+pub struct ExBatchedReaderRef(pub Mutex<BatchedReader>);
+
+#[rustler::resource_impl]
+impl Resource for ExBatchedReaderRef {}
+
+// Each write and the close go through the same writer, so it is behind a lock.
+pub struct ExBatchedWriterRef(pub Mutex<BatchedWriter>);
+
+#[rustler::resource_impl]
+impl Resource for ExBatchedWriterRef {}
+
 // The structs that start with "Ex" are related to the modules in Elixir.
 // Some of them are just wrappers around Polars data structs.
 // For example, a "ExDataFrame" is a wrapper around Polars' "DataFrame".
@@ -690,6 +709,147 @@ pub enum ExParquetCompression {
     Zstd(Option<i32>),
 }
 
+// The compression of text formats, such as CSV and NDJSON, when writing.
+// In Elixir this would be represented like this:
+// * `{:gzip, nil}` for `ExTextCompression::Gzip(None)`, with the default level
+// * `{:zstd, 3}` for `ExTextCompression::Zstd(Some(3))`
+// Uncompressed is represented by `nil`, as in `Option<ExTextCompression>`.
+#[derive(NifTaggedEnum)]
+pub enum ExTextCompression {
+    Gzip(Option<u32>),
+    Zstd(Option<i32>),
+}
+
+#[derive(NifTaggedEnum)]
+pub enum ExParquetStatistic {
+    MinMax,
+    NullCount,
+    DistinctCount,
+}
+
+// The compression is given separately, as `ExParquetCompression`.
+// In Elixir this would be represented like this:
+// `%{row_group_size: 100_000, data_page_size: nil, statistics: [:min_max, :null_count]}`
+#[derive(NifMap)]
+pub struct ExParquetWriteOptions {
+    pub row_group_size: Option<usize>,
+    pub data_page_size: Option<usize>,
+    pub statistics: Vec<ExParquetStatistic>,
+}
+
+impl ExParquetWriteOptions {
+    pub fn statistics_options(&self) -> StatisticsOptions {
+        let mut options = StatisticsOptions::empty();
+
+        for statistic in &self.statistics {
+            match statistic {
+                ExParquetStatistic::MinMax => {
+                    options.min_value = true;
+                    options.max_value = true;
+                }
+                ExParquetStatistic::NullCount => options.null_count = true,
+                ExParquetStatistic::DistinctCount => options.distinct_count = true,
+            }
+        }
+
+        options
+    }
+}
+
+// The dialect of the CSV files being read, besides the delimiter, EOL,
+// null values and encoding. In Elixir this would be represented like this:
+// `%{quote_char: ?", comment_prefix: "#", decimal_comma: false,
+//    missing_is_null: true, truncate_ragged_lines: false}`
+#[derive(NifMap)]
+pub struct ExCsvDialect {
+    pub quote_char: Option<u8>,
+    pub comment_prefix: Option<String>,
+    pub decimal_comma: bool,
+    pub missing_is_null: bool,
+    pub truncate_ragged_lines: bool,
+}
+
+impl ExCsvDialect {
+    pub fn parse_options(&self, options: CsvParseOptions) -> CsvParseOptions {
+        options
+            .with_quote_char(self.quote_char)
+            .with_comment_prefix(self.comment_prefix.as_deref())
+            .with_decimal_comma(self.decimal_comma)
+            .with_missing_is_null(self.missing_is_null)
+            .with_truncate_ragged_lines(self.truncate_ragged_lines)
+    }
+
+    pub fn lazy_csv_reader(&self, reader: LazyCsvReader) -> LazyCsvReader {
+        reader
+            .with_quote_char(self.quote_char)
+            .with_comment_prefix(self.comment_prefix.as_deref())
+            .with_decimal_comma(self.decimal_comma)
+            .with_missing_is_null(self.missing_is_null)
+            .with_truncate_ragged_lines(self.truncate_ragged_lines)
+    }
+}
+
+#[derive(NifTaggedEnum)]
+pub enum ExCsvQuoteStyle {
+    Necessary,
+    Always,
+    NonNumeric,
+    Never,
+}
+
+impl From<&ExCsvQuoteStyle> for QuoteStyle {
+    fn from(quote_style: &ExCsvQuoteStyle) -> Self {
+        match quote_style {
+            ExCsvQuoteStyle::Necessary => QuoteStyle::Necessary,
+            ExCsvQuoteStyle::Always => QuoteStyle::Always,
+            ExCsvQuoteStyle::NonNumeric => QuoteStyle::NonNumeric,
+            ExCsvQuoteStyle::Never => QuoteStyle::Never,
+        }
+    }
+}
+
+// The header and the delimiter are given separately.
+// In Elixir this would be represented like this:
+// `%{date_format: "%Y-%m-%d", time_format: nil, datetime_format: nil, float_precision: 2,
+//    null_value: "", line_terminator: "\n", quote_style: :necessary}`
+#[derive(NifMap)]
+pub struct ExCsvWriteOptions {
+    pub date_format: Option<String>,
+    pub time_format: Option<String>,
+    pub datetime_format: Option<String>,
+    pub float_precision: Option<usize>,
+    pub null_value: String,
+    pub line_terminator: String,
+    pub quote_style: ExCsvQuoteStyle,
+}
+
+impl ExCsvWriteOptions {
+    pub fn csv_writer<W: std::io::Write>(&self, writer: CsvWriter<W>) -> CsvWriter<W> {
+        writer
+            .with_date_format(self.date_format.clone())
+            .with_time_format(self.time_format.clone())
+            .with_datetime_format(self.datetime_format.clone())
+            .with_float_precision(self.float_precision)
+            .with_null_value(self.null_value.clone())
+            .with_line_terminator(self.line_terminator.clone())
+            .with_quote_style((&self.quote_style).into())
+    }
+
+    pub fn serialize_options(&self, separator: u8) -> SerializeOptions {
+        SerializeOptions {
+            date_format: self.date_format.clone(),
+            time_format: self.time_format.clone(),
+            datetime_format: self.datetime_format.clone(),
+            float_precision: self.float_precision,
+            separator,
+            null: self.null_value.clone(),
+            line_terminator: self.line_terminator.clone(),
+            quote_style: (&self.quote_style).into(),
+            ..Default::default()
+        }
+    }
+}
+
 #[derive(NifTaggedEnum)]
 pub enum ExCorrelationMethod {
     Pearson,
@@ -706,6 +866,22 @@ pub enum ExRankMethod {
     Random,
 }
 
+#[derive(NifTaggedEnum)]
+pub enum ExKdeKernel {
+    Gaussian,
+    Epanechnikov,
+}
+
+// In Elixir this would be represented like this:
+// * `:scott` for `ExKdeBandwidth::Scott`
+// * `{:fixed, 0.5}` for `ExKdeBandwidth::Fixed(0.5)`
+#[derive(NifTaggedEnum)]
+pub enum ExKdeBandwidth {
+    Scott,
+    Silverman,
+    Fixed(f64),
+}
+
 impl TryFrom<ExParquetCompression> for ParquetCompression {
     type Error = ExplorerError;
 
@@ -801,6 +977,222 @@ impl ExS3Config {
     }
 }
 
+#[derive(NifStruct, Clone, Debug)]
+#[module = "Explorer.FSS.GCS.Config"]
+pub struct ExGcsConfig {
+    pub bucket: String,
+    pub endpoint: Option<String>,
+    pub service_account_key: Option<String>,
+}
+
+#[derive(NifStruct, Clone, Debug)]
+#[module = "Explorer.FSS.GCS.Entry"]
+pub struct ExGcsEntry {
+    pub key: String,
+    pub config: ExGcsConfig,
+}
+
+impl fmt::Display for ExGcsEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "gs://{}/{}", self.config.bucket, self.key)
+    }
+}
+
+#[cfg(feature = "gcp")]
+impl ExGcsConfig {
+    // A custom endpoint, like the one of fake-gcs-server, is set through
+    // the service account key. The requests are signed with the given
+    // credentials, and only left unsigned when there are none.
+    pub fn service_account_key(&self) -> Result<Option<String>, ExplorerError> {
+        let Some(endpoint) = &self.endpoint else {
+            return Ok(self.service_account_key.clone());
+        };
+
+        let mut key = match &self.service_account_key {
+            Some(key) => serde_json::from_str(key).map_err(|error| {
+                ExplorerError::Other(format!("invalid GCS service account key: {error}"))
+            })?,
+            None => serde_json::json!({
+                "disable_oauth": true,
+                "client_email": "",
+                "private_key": "",
+                "private_key_id": ""
+            }),
+        };
+
+        let Some(fields) = key.as_object_mut() else {
+            return Err(ExplorerError::Other(
+                "invalid GCS service account key: expected a JSON object".to_string(),
+            ));
+        };
+
+        fields.insert("gcs_base_url".to_string(), endpoint.as_str().into());
+
+        Ok(Some(key.to_string()))
+    }
+
+    pub fn to_cloud_options(&self) -> Result<CloudOptions, ExplorerError> {
+        let gcs_opts = self
+            .service_account_key()?
+            .map(|key| (GcsKey::ServiceAccountKey, key));
+
+        Ok(CloudOptions::default().with_gcp(gcs_opts))
+    }
+}
+
+#[derive(NifStruct, Clone, Debug)]
+#[module = "Explorer.FSS.Azure.Config"]
+pub struct ExAzureConfig {
+    pub account: String,
+    pub access_key: Option<String>,
+    pub container: String,
+    pub endpoint: Option<String>,
+}
+
+#[derive(NifStruct, Clone, Debug)]
+#[module = "Explorer.FSS.Azure.Entry"]
+pub struct ExAzureEntry {
+    pub key: String,
+    pub config: ExAzureConfig,
+}
+
+impl fmt::Display for ExAzureEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "az://{}/{}", self.config.container, self.key)
+    }
+}
+
+#[cfg(feature = "azure")]
+impl ExAzureConfig {
+    pub fn to_cloud_options(&self) -> CloudOptions {
+        let mut azure_opts = vec![(AzureKey::AccountName, &self.account)];
+
+        if let Some(access_key) = &self.access_key {
+            azure_opts.push((AzureKey::AccessKey, access_key))
+        }
+
+        if let Some(endpoint) = &self.endpoint {
+            azure_opts.push((AzureKey::Endpoint, endpoint))
+        }
+
+        CloudOptions::default().with_azure(azure_opts)
+    }
+}
+
+#[derive(NifStruct, Clone, Debug)]
+#[module = "FSS.HTTP.Config"]
+pub struct ExHttpConfig {
+    pub headers: Vec<(String, String)>,
+}
+
+#[cfg(feature = "http")]
+impl ExHttpConfig {
+    // Polars has no public setter for the HTTP headers, so they are set
+    // through the serialized form of the options, as its config would.
+    pub fn to_cloud_options(&self) -> Result<CloudOptions, ExplorerError> {
+        let json_error = |error: serde_json::Error| {
+            ExplorerError::Other(format!("cannot build the HTTP options: {error}"))
+        };
+
+        let mut options = serde_json::to_value(CloudOptions::default()).map_err(json_error)?;
+        options["config"] = serde_json::json!({ "Http": { "headers": self.headers } });
+
+        serde_json::from_value(options).map_err(json_error)
+    }
+}
+
+#[derive(NifStruct, Clone, Debug)]
+#[module = "FSS.HTTP.Entry"]
+pub struct ExHttpEntry {
+    pub url: String,
+    pub config: ExHttpConfig,
+}
+
+#[derive(NifStruct, Clone, Debug)]
+#[module = "FSS.Local.Entry"]
+pub struct ExLocalEntry {
+    pub path: String,
+}
+
+// The in-memory object store lives in a resource, so its
+// objects are kept for as long as Elixir references it.
+#[cfg(feature = "cloud")]
+pub struct ExMemoryStoreRef(pub Arc<object_store::memory::InMemory>);
+
+// Without the "cloud" feature, no memory store can be created.
+#[cfg(not(feature = "cloud"))]
+#[allow(dead_code)]
+pub struct ExMemoryStoreRef;
+
+#[rustler::resource_impl]
+impl Resource for ExMemoryStoreRef {}
+
+#[derive(NifStruct, Clone)]
+#[module = "Explorer.FSS.Memory.Entry"]
+pub struct ExMemoryEntry {
+    pub key: String,
+    pub store: ResourceArc<ExMemoryStoreRef>,
+}
+
+// The entries accepted by the "*_cloud" functions. In Elixir, they
+// are tagged with the store, like `{:gcs, %Explorer.FSS.GCS.Entry{}}`.
+#[derive(NifTaggedEnum, Clone)]
+pub enum ExCloudEntry {
+    S3(ExS3Entry),
+    Gcs(ExGcsEntry),
+    Azure(ExAzureEntry),
+    Http(ExHttpEntry),
+    Local(ExLocalEntry),
+    Memory(ExMemoryEntry),
+}
+
+#[cfg(feature = "cloud")]
+impl ExCloudEntry {
+    // The URI and the options used by the Polars scans and sinks.
+    // Polars builds its own object store from them, so it can't
+    // reach the in-memory ones.
+    pub fn to_polars_uri(&self) -> Result<(String, Option<CloudOptions>), ExplorerError> {
+        match self {
+            #[cfg(feature = "aws")]
+            ExCloudEntry::S3(entry) => {
+                Ok((entry.to_string(), Some(entry.config.to_cloud_options())))
+            }
+            #[cfg(not(feature = "aws"))]
+            ExCloudEntry::S3(_) => Err(ExplorerError::Other(
+                "Explorer was compiled without the \"aws\" feature enabled.".to_string(),
+            )),
+            #[cfg(feature = "gcp")]
+            ExCloudEntry::Gcs(entry) => {
+                Ok((entry.to_string(), Some(entry.config.to_cloud_options()?)))
+            }
+            #[cfg(not(feature = "gcp"))]
+            ExCloudEntry::Gcs(_) => Err(ExplorerError::Other(
+                "Explorer was compiled without the \"gcp\" feature enabled.".to_string(),
+            )),
+            #[cfg(feature = "azure")]
+            ExCloudEntry::Azure(entry) => {
+                Ok((entry.to_string(), Some(entry.config.to_cloud_options())))
+            }
+            #[cfg(not(feature = "azure"))]
+            ExCloudEntry::Azure(_) => Err(ExplorerError::Other(
+                "Explorer was compiled without the \"azure\" feature enabled.".to_string(),
+            )),
+            #[cfg(feature = "http")]
+            ExCloudEntry::Http(entry) => {
+                Ok((entry.url.clone(), Some(entry.config.to_cloud_options()?)))
+            }
+            #[cfg(not(feature = "http"))]
+            ExCloudEntry::Http(_) => Err(ExplorerError::Other(
+                "Explorer was compiled without the \"http\" feature enabled.".to_string(),
+            )),
+            ExCloudEntry::Local(entry) => Ok((entry.path.clone(), None)),
+            ExCloudEntry::Memory(_) => Err(ExplorerError::Other(
+                "in-memory stores are not supported by the lazy scans and sinks".to_string(),
+            )),
+        }
+    }
+}
+
 impl From<ExExpr> for Expr {
     fn from(ex_expr: ExExpr) -> Self {
         ex_expr.clone_inner()
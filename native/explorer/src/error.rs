@@ -9,8 +9,10 @@ rustler::atoms! {
     polars,
     internal,
     other,
+    not_found,
     try_from_int,
     parquet,
+    distribution,
     unknown
 }
 
@@ -26,6 +28,16 @@ pub enum ExplorerError {
     Internal(String),
     #[error("Generic Error: {0}")]
     Other(String),
+    // The message is the same as the one of the Elixir file systems,
+    // so that the backend can return it as an argument error.
+    #[error("resource not found (404)")]
+    NotFound,
+    #[error("Invalid parameters for the {distribution} distribution ({parameters}): {reason}")]
+    Distribution {
+        distribution: String,
+        parameters: String,
+        reason: String,
+    },
     #[error(transparent)]
     TryFromInt(#[from] std::num::TryFromIntError),
     #[error(transparent)]
@@ -37,3 +49,51 @@ impl Encoder for ExplorerError {
         format!("{self}").encode(env)
     }
 }
+
+// The `statrs` distributions validate their parameters on construction
+// and each one has its own error type, whose message describes the invalid
+// parameter in its own words. This converts any of those errors into an
+// `ExplorerError` that also names the distribution and gives the parameters
+// as they were named by the caller, so that we can return it instead of
+// panicking. It is used through `check_distribution!`.
+pub fn distribution_result<D, E: std::fmt::Display>(
+    parameters: &[(&str, String)],
+    result: Result<D, E>,
+) -> Result<D, ExplorerError> {
+    result.map_err(|error| {
+        // Generic distributions, such as `Dirichlet<Dyn>`, end with their
+        // type parameters, so those are removed before taking the name.
+        let type_name = std::any::type_name::<D>();
+        let type_name = type_name.split('<').next().unwrap_or(type_name);
+        let distribution = type_name.rsplit("::").next().unwrap_or(type_name);
+
+        // Literal arguments, such as the location of a standard
+        // distribution, are not parameters of the caller.
+        let parameters = parameters
+            .iter()
+            .map(|(name, value)| (name.trim_start_matches('&'), value))
+            .filter(|(name, _value)| name.starts_with(|c: char| c.is_alphabetic() || c == '_'))
+            .map(|(name, value)| format!("{name} = {value}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        ExplorerError::Distribution {
+            distribution: distribution.to_string(),
+            parameters,
+            reason: error.to_string(),
+        }
+    })
+}
+
+// Builds a distribution with `Dist::new(...)`, returning an
+// `ExplorerError` that names its parameters if they are invalid.
+macro_rules! check_distribution {
+    ($dist:ident::new($($arg:expr),+ $(,)?)) => {
+        $crate::error::distribution_result(
+            &[$((stringify!($arg), format!("{:?}", $arg))),+],
+            $dist::new($($arg),+),
+        )
+    };
+}
+
+pub(crate) use check_distribution;
@@ -8,6 +8,7 @@ use crate::datatypes::{
     ExCorrelationMethod, ExDate, ExDateTime, ExDuration, ExNaiveDateTime, ExRankMethod,
     ExSeriesDtype, ExValidValue,
 };
+use crate::error::check_distribution;
 use crate::series::{cast_str_to_f64, ewm_opts, rolling_opts_fixed_window};
 use crate::{ExDataFrame, ExExpr, ExSeries};
 use polars::lazy::dsl;
@@ -16,6 +17,16 @@ use polars::prelude::{
     SortOptions,
 };
 use polars::prelude::{DataType, EWMOptions, Expr, Literal, StrptimeOptions, TimeUnit};
+use polars::prelude::{polars_bail, polars_err, GetOutput, NamedFrom, PolarsResult, Series};
+use rand::distributions::Distribution;
+use rand_chacha::rand_core::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+use statrs::distribution::{
+    Bernoulli, Beta, Binomial, Cauchy, Chi, ChiSquared, DiscreteUniform, Erlang, Exp,
+    FisherSnedecor, Gamma, Geometric, Gumbel, Hypergeometric, InverseGamma, Laplace, LogNormal,
+    NegativeBinomial, Normal, Pareto, Poisson, StudentsT, Triangular, Uniform, Weibull,
+};
+use std::hash::{DefaultHasher, Hash, Hasher};
 
 // Useful to get an ExExpr vec into a vec of expressions.
 pub fn ex_expr_to_exprs(ex_exprs: Vec<ExExpr>) -> Vec<Expr> {
@@ -1178,3 +1189,231 @@ pub fn expr_re_named_captures(expr: ExExpr, pattern: &str) -> ExExpr {
             .expect("should extract groups"),
     )
 }
+
+// Random draws as expressions.
+//
+// Unlike the `df_draw_from_*` functions, these draw one value per row
+// of the dataframe, and the parameters of the distribution are expressions,
+// so that they can come from other columns. Literal parameters are
+// broadcast to the height of the dataframe (given by `dsl::len()`).
+//
+// The draws run as a group-wise function, so Polars calls it once with
+// the whole columns (or once per group), instead of once per chunk, which
+// keeps the results reproducible for a given seed. Each group draws from
+// the ChaCha stream given by the values of its keys, so that groups don't
+// repeat each other's draws, whatever the order Polars evaluates them in.
+
+trait DrawParameter: Sized + Copy {
+    fn values(s: &Series) -> PolarsResult<Vec<Option<Self>>>;
+}
+
+macro_rules! impl_draw_parameter {
+    ($t:ty, $dtype:expr, $accessor:ident) => {
+        impl DrawParameter for $t {
+            fn values(s: &Series) -> PolarsResult<Vec<Option<Self>>> {
+                Ok(s.cast(&$dtype)?.$accessor()?.into_iter().collect())
+            }
+        }
+    };
+}
+
+impl_draw_parameter!(f64, DataType::Float64, f64);
+impl_draw_parameter!(u64, DataType::UInt64, u64);
+impl_draw_parameter!(i64, DataType::Int64, i64);
+
+// The keys have the same value in all the rows of a group. Without
+// groups, the whole frame draws from the first stream.
+fn draw_stream(keys: &[Series]) -> u64 {
+    if keys.is_empty() {
+        return 0;
+    }
+
+    let mut hasher = DefaultHasher::new();
+    for key in keys {
+        let value = key.get(0).map(|value| value.to_string());
+        value.unwrap_or_default().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+fn draw_parameter<T: DrawParameter>(s: &Series, height: usize) -> PolarsResult<Vec<Option<T>>> {
+    let values = T::values(s)?;
+
+    match values.len() {
+        1 => Ok(vec![values[0]; height]),
+        len if len == height => Ok(values),
+        len => polars_bail!(
+            ShapeMismatch: "distribution parameter `{}` has {} values, expected {}",
+            s.name(), len, height
+        ),
+    }
+}
+
+macro_rules! define_draw_expression {
+    ($name:ident; $dist_struct:ident; $t:ty => $out:ty, $dtype:expr; $($p:ident : $pt:ty),+) => {
+        #[rustler::nif]
+        pub fn $name(seed: u64, groups: Vec<ExExpr>, $($p: ExExpr),+) -> ExExpr {
+            let n_groups = groups.len();
+            let mut inputs = vec![dsl::len()];
+            inputs.extend(groups.into_iter().map(|group| group.clone_inner()));
+            $(inputs.push($p.clone_inner());)+
+
+            let function = move |s: &mut [Series]| -> PolarsResult<Option<Series>> {
+                let height = s[0].cast(&DataType::UInt64)?.u64()?.get(0).unwrap_or(0) as usize;
+                let (keys, parameters) = s[1..].split_at(n_groups);
+                let mut parameters = parameters.iter();
+                $(let $p = draw_parameter::<$pt>(parameters.next().unwrap(), height)?;)+
+
+                let mut rng = ChaCha12Rng::seed_from_u64(seed);
+                rng.set_stream(draw_stream(keys));
+
+                // Rows with a nil parameter don't consume random numbers.
+                let values = (0..height)
+                    .map(|row| match ($($p[row],)+) {
+                        ($(Some($p),)+) => {
+                            let dist = check_distribution!($dist_struct::new($($p),+))
+                                .map_err(|error| polars_err!(ComputeError: "{}", error))?;
+
+                            Ok(Some(Distribution::<$t>::sample(&dist, &mut rng) as $out))
+                        }
+                        _ => Ok(None),
+                    })
+                    .collect::<PolarsResult<Vec<Option<$out>>>>()?;
+
+                Ok(Some(Series::new("draw".into(), values)))
+            };
+
+            let output_type = GetOutput::from_type($dtype);
+            ExExpr::new(dsl::apply_multiple(function, inputs, output_type, false))
+        }
+    };
+}
+
+// Continuous distributions
+
+define_draw_expression!(
+    expr_draw_from_beta; Beta; f64 => f64, DataType::Float64;
+    a: f64, b: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_cauchy; Cauchy; f64 => f64, DataType::Float64;
+    median: f64, scale: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_chi; Chi; f64 => f64, DataType::Float64;
+    freedom: u64
+);
+
+define_draw_expression!(
+    expr_draw_from_chi_squared; ChiSquared; f64 => f64, DataType::Float64;
+    freedom: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_erlang; Erlang; f64 => f64, DataType::Float64;
+    shape: u64, rate: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_exp; Exp; f64 => f64, DataType::Float64;
+    rate: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_fisher_snedecor; FisherSnedecor; f64 => f64, DataType::Float64;
+    freedom_1: f64, freedom_2: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_gamma; Gamma; f64 => f64, DataType::Float64;
+    shape: f64, rate: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_gumbel; Gumbel; f64 => f64, DataType::Float64;
+    location: f64, scale: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_inverse_gamma; InverseGamma; f64 => f64, DataType::Float64;
+    shape: f64, rate: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_laplace; Laplace; f64 => f64, DataType::Float64;
+    location: f64, scale: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_log_normal; LogNormal; f64 => f64, DataType::Float64;
+    location: f64, scale: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_normal; Normal; f64 => f64, DataType::Float64;
+    mu: f64, sigma: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_pareto; Pareto; f64 => f64, DataType::Float64;
+    scale: f64, shape: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_students_t; StudentsT; f64 => f64, DataType::Float64;
+    location: f64, scale: f64, freedom: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_triangular; Triangular; f64 => f64, DataType::Float64;
+    min: f64, max: f64, mode: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_uniform; Uniform; f64 => f64, DataType::Float64;
+    min: f64, max: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_weibull; Weibull; f64 => f64, DataType::Float64;
+    shape: f64, scale: f64
+);
+
+// Discrete distributions
+
+define_draw_expression!(
+    expr_draw_from_bernoulli; Bernoulli; bool => u64, DataType::UInt64;
+    p: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_binomial; Binomial; u64 => u64, DataType::UInt64;
+    p: f64, n: u64
+);
+
+define_draw_expression!(
+    expr_draw_from_discrete_uniform; DiscreteUniform; i64 => i64, DataType::Int64;
+    min: i64, max: i64
+);
+
+define_draw_expression!(
+    expr_draw_from_geometric; Geometric; u64 => u64, DataType::UInt64;
+    p: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_hypergeometric; Hypergeometric; u64 => u64, DataType::UInt64;
+    pop_size: u64, success_states: u64, sample_size: u64
+);
+
+define_draw_expression!(
+    expr_draw_from_negative_binomial; NegativeBinomial; u64 => u64, DataType::UInt64;
+    r: f64, p: f64
+);
+
+define_draw_expression!(
+    expr_draw_from_poisson; Poisson; u64 => u64, DataType::UInt64;
+    lambda: f64
+);
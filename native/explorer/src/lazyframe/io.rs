@@ -1,68 +1,112 @@
+use polars::export::arrow::io::ipc::{read as ipc_read, write as ipc_write};
 use polars::prelude::*;
 use std::fs::File;
-use std::io::BufWriter;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
+use tempfile::NamedTempFile;
+
+use crate::compressed_writer::CompressedWriter;
+use crate::dataframe::io::{
+    decode_ipc_compression, parquet_writer, schema_from_dtypes_pairs, write_ipc_partitioned,
+    write_parquet_partitioned,
+};
+use crate::datatypes::{
+    ExCloudEntry, ExCsvDialect, ExCsvWriteOptions, ExParquetCompression, ExParquetWriteOptions,
+    ExSeriesDtype, ExTextCompression,
+};
+use crate::{ExLazyFrame, ExplorerError};
+
+#[cfg(feature = "ndjson")]
+use crate::{batched_reader::is_compressed_file, dataframe::io::read_ndjson_file};
+#[cfg(feature = "ndjson")]
 use std::num::NonZeroUsize;
 
-use crate::dataframe::io::schema_from_dtypes_pairs;
-use crate::datatypes::{ExParquetCompression, ExS3Entry, ExSeriesDtype};
-use crate::{ExLazyFrame, ExplorerError};
+#[cfg(feature = "cloud")]
+use crate::dataframe::io::{
+    build_cloud_writer, write_ipc_partitioned_cloud, write_parquet_partitioned_cloud,
+};
+
+// Selecting the columns right after the scan lets Polars push
+// the projection down, so only those columns are read.
+fn select_columns(lf: LazyFrame, columns: Option<Vec<String>>) -> LazyFrame {
+    match columns {
+        Some(columns) => lf.select(columns.iter().map(col).collect::<Vec<Expr>>()),
+        None => lf,
+    }
+}
+
+// The scans accept a single file, a glob pattern (like `data/*.parquet`)
+// or a directory, in which case all the files under it are read.
+//
+// The Hive partitions (the `key=value` segments of the paths) become
+// columns, with their types inferred from the values. Filters on these
+// columns are pushed down to skip the files that can't match.
+// `hive_partitioning` can be `None` to enable it only when a directory
+// is given.
+//
+// `include_file_paths` is the name of a column to add with the path of
+// the file that each row came from.
+fn hive_options(hive_partitioning: Option<bool>) -> HiveOptions {
+    HiveOptions {
+        enabled: hive_partitioning,
+        ..Default::default()
+    }
+}
 
 #[rustler::nif]
 pub fn lf_from_parquet(
     filename: &str,
     stop_after_n_rows: Option<usize>,
     columns: Option<Vec<String>>,
+    hive_partitioning: Option<bool>,
+    include_file_paths: Option<String>,
 ) -> Result<ExLazyFrame, ExplorerError> {
     let options = ScanArgsParquet {
         n_rows: stop_after_n_rows,
+        hive_options: hive_options(hive_partitioning),
+        include_file_paths: include_file_paths.map(PlSmallStr::from_string),
         ..Default::default()
     };
 
-    let cols: Vec<Expr> = if let Some(cols) = columns {
-        cols.iter().map(col).collect()
-    } else {
-        vec![all()]
-    };
+    let lf = LazyFrame::scan_parquet(filename, options)?;
 
-    let lf = LazyFrame::scan_parquet(filename, options)?.select(cols);
-
-    Ok(ExLazyFrame::new(lf))
+    Ok(ExLazyFrame::new(select_columns(lf, columns)))
 }
 
-// When we have more cloud entries, we could accept an Enum.
-#[cfg(feature = "aws")]
+#[cfg(feature = "cloud")]
 #[rustler::nif(schedule = "DirtyIo")]
 pub fn lf_from_parquet_cloud(
-    ex_entry: ExS3Entry,
+    ex_entry: ExCloudEntry,
     stop_after_n_rows: Option<usize>,
     columns: Option<Vec<String>>,
+    hive_partitioning: Option<bool>,
+    include_file_paths: Option<String>,
 ) -> Result<ExLazyFrame, ExplorerError> {
+    let (uri, cloud_options) = ex_entry.to_polars_uri()?;
     let options = ScanArgsParquet {
         n_rows: stop_after_n_rows,
-        cloud_options: Some(ex_entry.config.to_cloud_options()),
+        cloud_options,
+        hive_options: hive_options(hive_partitioning),
+        include_file_paths: include_file_paths.map(PlSmallStr::from_string),
         ..Default::default()
     };
-    let cols: Vec<Expr> = if let Some(cols) = columns {
-        cols.iter().map(col).collect()
-    } else {
-        vec![all()]
-    };
-    let lf = LazyFrame::scan_parquet(ex_entry.to_string(), options)?
+    let lf = LazyFrame::scan_parquet(uri, options)?
         .with_comm_subplan_elim(false)
-        .with_streaming(true)
-        .select(cols);
+        .with_streaming(true);
 
-    Ok(ExLazyFrame::new(lf))
+    Ok(ExLazyFrame::new(select_columns(lf, columns)))
 }
 
-#[cfg(not(feature = "aws"))]
+#[cfg(not(feature = "cloud"))]
 #[rustler::nif(schedule = "DirtyIo")]
 pub fn lf_from_parquet_cloud(
-    _ex_entry: ExS3Entry,
+    _ex_entry: ExCloudEntry,
     _stop_after_n_rows: Option<usize>,
     _columns: Option<Vec<String>>,
+    _hive_partitioning: Option<bool>,
+    _include_file_paths: Option<String>,
 ) -> Result<ExLazyFrame, ExplorerError> {
-    Err(ExplorerError::Other("Explorer was compiled without the \"aws\" feature enabled. \
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
         This is mostly due to this feature being incompatible with your computer's architecture. \
         Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
 }
@@ -72,6 +116,7 @@ pub fn lf_to_parquet(
     data: ExLazyFrame,
     filename: &str,
     ex_compression: ExParquetCompression,
+    ex_options: ExParquetWriteOptions,
     streaming: bool,
 ) -> Result<(), ExplorerError> {
     let compression = ParquetCompression::try_from(ex_compression)?;
@@ -81,9 +126,9 @@ pub fn lf_to_parquet(
     if streaming {
         let options = ParquetWriteOptions {
             compression,
-            statistics: StatisticsOptions::empty(),
-            row_group_size: None,
-            data_page_size: None,
+            statistics: ex_options.statistics_options(),
+            row_group_size: ex_options.row_group_size,
+            data_page_size: ex_options.data_page_size,
             maintain_order: false,
         };
 
@@ -96,60 +141,149 @@ pub fn lf_to_parquet(
         let file = File::create(filename)?;
         let mut buf_writer = BufWriter::new(file);
 
-        ParquetWriter::new(&mut buf_writer)
-            .with_compression(compression)
-            .finish(&mut df)?;
+        parquet_writer(&mut buf_writer, compression, &ex_options).finish(&mut df)?;
 
         Ok(())
     }
 }
 
-#[cfg(feature = "aws")]
+#[cfg(feature = "cloud")]
 #[rustler::nif(schedule = "DirtyIo")]
 pub fn lf_to_parquet_cloud(
     data: ExLazyFrame,
-    ex_entry: ExS3Entry,
+    ex_entry: ExCloudEntry,
     ex_compression: ExParquetCompression,
+    ex_options: ExParquetWriteOptions,
 ) -> Result<(), ExplorerError> {
     let lf = data.clone_inner();
-    let cloud_options = Some(ex_entry.config.to_cloud_options());
+    let (uri, cloud_options) = ex_entry.to_polars_uri()?;
     let compression = ParquetCompression::try_from(ex_compression)?;
 
     let options = ParquetWriteOptions {
         compression,
-        statistics: StatisticsOptions::empty(),
-        row_group_size: None,
-        data_page_size: None,
+        statistics: ex_options.statistics_options(),
+        row_group_size: ex_options.row_group_size,
+        data_page_size: ex_options.data_page_size,
         maintain_order: false,
     };
 
-    lf.with_comm_subplan_elim(false).sink_parquet_cloud(
-        ex_entry.to_string(),
-        cloud_options,
-        options,
-    )?;
+    lf.with_comm_subplan_elim(false)
+        .sink_parquet_cloud(uri, cloud_options, options)?;
     Ok(())
 }
 
-#[cfg(not(feature = "aws"))]
+#[cfg(not(feature = "cloud"))]
 #[rustler::nif(schedule = "DirtyIo")]
 pub fn lf_to_parquet_cloud(
     _data: ExLazyFrame,
-    _ex_entry: ExS3Entry,
+    _ex_entry: ExCloudEntry,
     _ex_compression: ExParquetCompression,
+    _ex_options: ExParquetWriteOptions,
 ) -> Result<(), ExplorerError> {
-    Err(ExplorerError::Other("Explorer was compiled without the \"aws\" feature enabled. \
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
         This is mostly due to this feature being incompatible with your computer's architecture. \
         Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
 }
 
+// Partitioned writes can't be streamed, so the lazy frame is collected
+// and written with the same functions used by the dataframe.
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn lf_to_parquet_partitioned(
+    data: ExLazyFrame,
+    dirname: &str,
+    partition_by: Vec<String>,
+    ex_compression: ExParquetCompression,
+    ex_options: ExParquetWriteOptions,
+) -> Result<(), ExplorerError> {
+    let compression = ParquetCompression::try_from(ex_compression)?;
+    let df = data.clone_inner().collect()?;
+
+    write_parquet_partitioned(&df, dirname, &partition_by, compression, &ex_options)
+}
+
+#[cfg(feature = "cloud")]
 #[rustler::nif(schedule = "DirtyIo")]
-pub fn lf_from_ipc(filename: &str) -> Result<ExLazyFrame, ExplorerError> {
-    let lf = LazyFrame::scan_ipc(filename, Default::default())?;
+pub fn lf_to_parquet_partitioned_cloud(
+    data: ExLazyFrame,
+    ex_entry: ExCloudEntry,
+    partition_by: Vec<String>,
+    ex_compression: ExParquetCompression,
+    ex_options: ExParquetWriteOptions,
+) -> Result<(), ExplorerError> {
+    let compression = ParquetCompression::try_from(ex_compression)?;
+    let df = data.clone_inner().collect()?;
+
+    write_parquet_partitioned_cloud(&df, &ex_entry, &partition_by, compression, &ex_options)
+}
+
+#[cfg(not(feature = "cloud"))]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn lf_to_parquet_partitioned_cloud(
+    _data: ExLazyFrame,
+    _ex_entry: ExCloudEntry,
+    _partition_by: Vec<String>,
+    _ex_compression: ExParquetCompression,
+    _ex_options: ExParquetWriteOptions,
+) -> Result<(), ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn lf_from_ipc(
+    filename: &str,
+    hive_partitioning: Option<bool>,
+    include_file_paths: Option<String>,
+) -> Result<ExLazyFrame, ExplorerError> {
+    let options = ScanArgsIpc {
+        hive_options: hive_options(hive_partitioning),
+        include_file_paths: include_file_paths.map(PlSmallStr::from_string),
+        ..Default::default()
+    };
+
+    let lf = LazyFrame::scan_ipc(filename, options)?;
 
     Ok(ExLazyFrame::new(lf))
 }
 
+#[cfg(feature = "cloud")]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn lf_from_ipc_cloud(
+    ex_entry: ExCloudEntry,
+    stop_after_n_rows: Option<usize>,
+    columns: Option<Vec<String>>,
+    hive_partitioning: Option<bool>,
+    include_file_paths: Option<String>,
+) -> Result<ExLazyFrame, ExplorerError> {
+    let (uri, cloud_options) = ex_entry.to_polars_uri()?;
+    let options = ScanArgsIpc {
+        n_rows: stop_after_n_rows,
+        cloud_options,
+        hive_options: hive_options(hive_partitioning),
+        include_file_paths: include_file_paths.map(PlSmallStr::from_string),
+        ..Default::default()
+    };
+
+    let lf = LazyFrame::scan_ipc(uri, options)?;
+
+    Ok(ExLazyFrame::new(select_columns(lf, columns)))
+}
+
+#[cfg(not(feature = "cloud"))]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn lf_from_ipc_cloud(
+    _ex_entry: ExCloudEntry,
+    _stop_after_n_rows: Option<usize>,
+    _columns: Option<Vec<String>>,
+    _hive_partitioning: Option<bool>,
+    _include_file_paths: Option<String>,
+) -> Result<ExLazyFrame, ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
 #[rustler::nif(schedule = "DirtyIo")]
 pub fn lf_to_ipc(
     data: ExLazyFrame,
@@ -159,9 +293,8 @@ pub fn lf_to_ipc(
 ) -> Result<(), ExplorerError> {
     // Select the compression algorithm.
     let compression = match compression {
-        Some("lz4") => Some(IpcCompression::LZ4),
-        Some("zstd") => Some(IpcCompression::ZSTD),
-        _ => None,
+        Some(algo) => Some(decode_ipc_compression(algo)?),
+        None => None,
     };
 
     let lf = data.clone_inner();
@@ -185,35 +318,235 @@ pub fn lf_to_ipc(
     }
 }
 
-#[cfg(feature = "aws")]
+#[cfg(feature = "cloud")]
 #[rustler::nif(schedule = "DirtyIo")]
 pub fn lf_to_ipc_cloud(
     data: ExLazyFrame,
-    ex_entry: ExS3Entry,
+    ex_entry: ExCloudEntry,
     compression: Option<&str>,
 ) -> Result<(), ExplorerError> {
     let lf = data.clone_inner();
-    let cloud_options = Some(ex_entry.config.to_cloud_options());
+    let (uri, cloud_options) = ex_entry.to_polars_uri()?;
     // Select the compression algorithm.
     let compression = match compression {
-        Some("lz4") => Some(IpcCompression::LZ4),
-        Some("zstd") => Some(IpcCompression::ZSTD),
-        _ => None,
+        Some(algo) => Some(decode_ipc_compression(algo)?),
+        None => None,
     };
 
     let options = IpcWriterOptions {
         compression,
         maintain_order: false,
     };
-    lf.with_comm_subplan_elim(false).sink_ipc_cloud(
-        ex_entry.to_string(),
-        cloud_options,
-        options,
-    )?;
+    lf.with_comm_subplan_elim(false)
+        .sink_ipc_cloud(uri, cloud_options, options)?;
+
+    Ok(())
+}
+
+#[cfg(not(feature = "cloud"))]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn lf_to_ipc_cloud(
+    _data: ExLazyFrame,
+    _ex_entry: ExCloudEntry,
+    _compression: Option<&str>,
+) -> Result<(), ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn lf_to_ipc_partitioned(
+    data: ExLazyFrame,
+    dirname: &str,
+    partition_by: Vec<String>,
+    compression: Option<&str>,
+) -> Result<(), ExplorerError> {
+    let compression = match compression {
+        Some(algo) => Some(decode_ipc_compression(algo)?),
+        None => None,
+    };
+
+    let df = data.clone_inner().collect()?;
+
+    write_ipc_partitioned(&df, dirname, &partition_by, compression)
+}
+
+#[cfg(feature = "cloud")]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn lf_to_ipc_partitioned_cloud(
+    data: ExLazyFrame,
+    ex_entry: ExCloudEntry,
+    partition_by: Vec<String>,
+    compression: Option<&str>,
+) -> Result<(), ExplorerError> {
+    let compression = match compression {
+        Some(algo) => Some(decode_ipc_compression(algo)?),
+        None => None,
+    };
+
+    let df = data.clone_inner().collect()?;
+
+    write_ipc_partitioned_cloud(&df, &ex_entry, &partition_by, compression)
+}
+
+#[cfg(not(feature = "cloud"))]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn lf_to_ipc_partitioned_cloud(
+    _data: ExLazyFrame,
+    _ex_entry: ExCloudEntry,
+    _partition_by: Vec<String>,
+    _compression: Option<&str>,
+) -> Result<(), ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+// ============ Spooled sinks ============ //
+//
+// Polars has no streaming sink for the Arrow IPC stream format, nor one
+// that writes NDJSON to the cloud or compresses CSV and NDJSON. For those,
+// the streaming engine sinks the query to a temporary file, which is then
+// copied to the target a batch at a time, so the result never has to fit
+// in memory.
+//
+// The temporary file is created with a random name, and only if it does
+// not exist yet, so it can't be guessed or replaced by a symlink. It is
+// removed once the copy is done, or when the sink fails.
+fn spool_file(extension: &str) -> Result<NamedTempFile, ExplorerError> {
+    let spool_file = tempfile::Builder::new()
+        .prefix("explorer-sink-")
+        .suffix(&format!(".{extension}"))
+        .tempfile()?;
+
+    Ok(spool_file)
+}
+
+// Runs the given sink to a spool file and copies the result to the writer.
+fn sink_spooled<W: Write>(
+    extension: &str,
+    writer: &mut W,
+    sink: impl FnOnce(&Path) -> PolarsResult<()>,
+) -> Result<(), ExplorerError> {
+    let spool_file = spool_file(extension)?;
+    sink(spool_file.path())?;
+
+    std::io::copy(&mut File::open(spool_file.path())?, writer)?;
+    Ok(())
+}
+
+// The spooled IPC file is uncompressed, and the compression is only
+// applied when the batches are written to the stream.
+fn sink_ipc_stream<W: Write>(
+    lf: LazyFrame,
+    writer: W,
+    compression: Option<IpcCompression>,
+) -> Result<(), ExplorerError> {
+    let spool_file = spool_file("arrow")?;
+    let options = IpcWriterOptions {
+        compression: None,
+        maintain_order: true,
+    };
+    lf.with_comm_subplan_elim(false)
+        .sink_ipc(spool_file.path(), options)?;
+
+    let mut reader = BufReader::new(File::open(spool_file.path())?);
+    let metadata = ipc_read::read_file_metadata(&mut reader)?;
+    let schema = metadata.schema.clone();
+    let ipc_fields = metadata.ipc_schema.fields.clone();
+
+    let mut stream_writer = ipc_write::StreamWriter::new(
+        writer,
+        ipc_write::WriteOptions {
+            compression: compression.map(Into::into),
+        },
+    );
+    stream_writer.start(&schema, Some(ipc_fields))?;
+
+    for batch in ipc_read::FileReader::new(reader, metadata, None, None) {
+        stream_writer.write(&batch?, None)?;
+    }
+
+    stream_writer.finish()?;
+    Ok(())
+}
+
+// ============ IPC Streaming ============ //
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn lf_to_ipc_stream(
+    data: ExLazyFrame,
+    filename: &str,
+    compression: Option<&str>,
+    streaming: bool,
+) -> Result<(), ExplorerError> {
+    // Select the compression algorithm.
+    let compression = match compression {
+        Some(algo) => Some(decode_ipc_compression(algo)?),
+        None => None,
+    };
+
+    let lf = data.clone_inner();
+    let file = File::create(filename)?;
+    let mut buf_writer = BufWriter::new(file);
+
+    if streaming {
+        sink_ipc_stream(lf, &mut buf_writer, compression)?;
+    } else {
+        let mut df = lf.collect()?;
+        IpcStreamWriter::new(&mut buf_writer)
+            .with_compression(compression)
+            .finish(&mut df)?;
+    }
+
+    buf_writer.flush()?;
+    Ok(())
+}
+
+#[cfg(feature = "cloud")]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn lf_to_ipc_stream_cloud(
+    data: ExLazyFrame,
+    ex_entry: ExCloudEntry,
+    compression: Option<&str>,
+    streaming: bool,
+) -> Result<(), ExplorerError> {
+    // Select the compression algorithm.
+    let compression = match compression {
+        Some(algo) => Some(decode_ipc_compression(algo)?),
+        None => None,
+    };
 
+    let lf = data.clone_inner();
+    let mut cloud_writer = build_cloud_writer(&ex_entry)?;
+
+    if streaming {
+        sink_ipc_stream(lf, &mut cloud_writer, compression)?;
+    } else {
+        let mut df = lf.collect()?;
+        IpcStreamWriter::new(&mut cloud_writer)
+            .with_compression(compression)
+            .finish(&mut df)?;
+    }
+
+    let _ = cloud_writer.finish()?;
     Ok(())
 }
 
+#[cfg(not(feature = "cloud"))]
+#[rustler::nif]
+pub fn lf_to_ipc_stream_cloud(
+    _data: ExLazyFrame,
+    _ex_entry: ExCloudEntry,
+    _compression: Option<&str>,
+    _streaming: bool,
+) -> Result<(), ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
 #[rustler::nif]
 #[allow(clippy::too_many_arguments)]
 pub fn lf_from_csv(
@@ -230,13 +563,20 @@ pub fn lf_from_csv(
     null_vals: Vec<String>,
     parse_dates: bool,
     eol_delimiter: Option<u8>,
+    dialect: ExCsvDialect,
+    include_file_paths: Option<String>,
 ) -> Result<ExLazyFrame, ExplorerError> {
     let encoding = match encoding {
         "utf8-lossy" => CsvEncoding::LossyUtf8,
         _ => CsvEncoding::Utf8,
     };
 
-    let df = LazyCsvReader::new(filename)
+    // The CSV scan supports glob patterns, but not Hive partitions.
+    // Like the eager reader, it decompresses compressed files, which
+    // are then held in memory.
+    let reader = LazyCsvReader::new(filename)
+        .with_glob(true)
+        .with_include_file_paths(include_file_paths.map(PlSmallStr::from_string))
         .with_infer_schema_length(infer_schema_length)
         .with_has_header(has_header)
         .with_try_parse_dates(parse_dates)
@@ -250,46 +590,130 @@ pub fn lf_from_csv(
         .with_null_values(Some(NullValues::AllColumns(
             null_vals.iter().map(|x| x.into()).collect(),
         )))
-        .with_eol_char(eol_delimiter.unwrap_or(b'\n'))
-        .finish()?;
+        .with_eol_char(eol_delimiter.unwrap_or(b'\n'));
+
+    let df = dialect.lazy_csv_reader(reader).finish()?;
 
     Ok(ExLazyFrame::new(df))
 }
 
+#[cfg(feature = "cloud")]
+#[rustler::nif(schedule = "DirtyIo")]
+#[allow(clippy::too_many_arguments)]
+pub fn lf_from_csv_cloud(
+    ex_entry: ExCloudEntry,
+    columns: Option<Vec<String>>,
+    infer_schema_length: Option<usize>,
+    has_header: bool,
+    stop_after_n_rows: Option<usize>,
+    skip_rows: usize,
+    skip_rows_after_header: usize,
+    delimiter_as_byte: u8,
+    do_rechunk: bool,
+    dtypes: Vec<(&str, ExSeriesDtype)>,
+    encoding: &str,
+    null_vals: Vec<String>,
+    parse_dates: bool,
+    eol_delimiter: Option<u8>,
+    dialect: ExCsvDialect,
+    include_file_paths: Option<String>,
+) -> Result<ExLazyFrame, ExplorerError> {
+    let encoding = match encoding {
+        "utf8-lossy" => CsvEncoding::LossyUtf8,
+        _ => CsvEncoding::Utf8,
+    };
+
+    let (uri, cloud_options) = ex_entry.to_polars_uri()?;
+    let reader = LazyCsvReader::new(uri)
+        .with_cloud_options(cloud_options)
+        .with_include_file_paths(include_file_paths.map(PlSmallStr::from_string))
+        .with_infer_schema_length(infer_schema_length)
+        .with_has_header(has_header)
+        .with_try_parse_dates(parse_dates)
+        .with_n_rows(stop_after_n_rows)
+        .with_separator(delimiter_as_byte)
+        .with_skip_rows(skip_rows)
+        .with_skip_rows_after_header(skip_rows_after_header)
+        .with_rechunk(do_rechunk)
+        .with_encoding(encoding)
+        .with_dtype_overwrite(schema_from_dtypes_pairs(dtypes)?)
+        .with_null_values(Some(NullValues::AllColumns(
+            null_vals.iter().map(|x| x.into()).collect(),
+        )))
+        .with_eol_char(eol_delimiter.unwrap_or(b'\n'));
+
+    let lf = dialect.lazy_csv_reader(reader).finish()?;
+
+    Ok(ExLazyFrame::new(select_columns(lf, columns)))
+}
+
+#[cfg(not(feature = "cloud"))]
+#[rustler::nif(schedule = "DirtyIo")]
+#[allow(clippy::too_many_arguments)]
+pub fn lf_from_csv_cloud(
+    _ex_entry: ExCloudEntry,
+    _columns: Option<Vec<String>>,
+    _infer_schema_length: Option<usize>,
+    _has_header: bool,
+    _stop_after_n_rows: Option<usize>,
+    _skip_rows: usize,
+    _skip_rows_after_header: usize,
+    _delimiter_as_byte: u8,
+    _do_rechunk: bool,
+    _dtypes: Vec<(&str, ExSeriesDtype)>,
+    _encoding: &str,
+    _null_vals: Vec<String>,
+    _parse_dates: bool,
+    _eol_delimiter: Option<u8>,
+    _dialect: ExCsvDialect,
+    _include_file_paths: Option<String>,
+) -> Result<ExLazyFrame, ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
 #[rustler::nif(schedule = "DirtyIo")]
 pub fn lf_to_csv(
     data: ExLazyFrame,
     filename: &str,
     include_headers: bool,
     delimiter: u8,
+    options: ExCsvWriteOptions,
+    compression: Option<ExTextCompression>,
     streaming: bool,
 ) -> Result<(), ExplorerError> {
     let lf = data.clone_inner();
     if streaming {
-        let serialize_options = SerializeOptions {
-            separator: delimiter,
-            ..Default::default()
-        };
-
-        let options = CsvWriterOptions {
+        let sink_options = CsvWriterOptions {
             include_header: include_headers,
             maintain_order: true,
-            serialize_options,
+            serialize_options: options.serialize_options(delimiter),
             ..Default::default()
         };
+        let lf = lf.with_comm_subplan_elim(false);
 
-        lf.with_comm_subplan_elim(false)
-            .sink_csv(filename, options)?;
+        if compression.is_none() {
+            lf.sink_csv(filename, sink_options)?;
+        } else {
+            let file = File::create(filename)?;
+            let mut writer = CompressedWriter::new(BufWriter::new(file), compression)?;
+
+            sink_spooled("csv", &mut writer, |path| lf.sink_csv(path, sink_options))?;
+            writer.finish()?.flush()?;
+        }
         Ok(())
     } else {
         let df = lf.collect()?;
         let file = File::create(filename)?;
-        let mut buf_writer = BufWriter::new(file);
+        let mut writer = CompressedWriter::new(BufWriter::new(file), compression)?;
 
-        CsvWriter::new(&mut buf_writer)
+        options
+            .csv_writer(CsvWriter::new(&mut writer))
             .include_header(include_headers)
             .with_separator(delimiter)
             .finish(&mut df.clone())?;
+        writer.finish()?.flush()?;
         Ok(())
     }
 }
@@ -301,6 +725,13 @@ pub fn lf_from_ndjson(
     infer_schema_length: Option<usize>,
     batch_size: usize,
 ) -> Result<ExLazyFrame, ExplorerError> {
+    // The NDJSON scan reads the file in place, so it can't decompress it.
+    // A compressed file is read, and decompressed, in memory instead.
+    if is_compressed_file(&filename)? {
+        let df = read_ndjson_file(&filename, infer_schema_length, batch_size)?;
+        return Ok(ExLazyFrame::new(df.lazy()));
+    }
+
     let batch_size = NonZeroUsize::new(batch_size).ok_or(ExplorerError::Other(
         "\"batch_size\" expected to be non zero.".to_string(),
     ))?;
@@ -312,6 +743,100 @@ pub fn lf_from_ndjson(
     Ok(ExLazyFrame::new(lf))
 }
 
+#[cfg(all(feature = "ndjson", feature = "cloud"))]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn lf_from_ndjson_cloud(
+    ex_entry: ExCloudEntry,
+    stop_after_n_rows: Option<usize>,
+    columns: Option<Vec<String>>,
+    infer_schema_length: Option<usize>,
+    batch_size: usize,
+) -> Result<ExLazyFrame, ExplorerError> {
+    let batch_size = NonZeroUsize::new(batch_size).ok_or(ExplorerError::Other(
+        "\"batch_size\" expected to be non zero.".to_string(),
+    ))?;
+    let (uri, cloud_options) = ex_entry.to_polars_uri()?;
+    let lf = LazyJsonLineReader::new(uri)
+        .with_cloud_options(cloud_options)
+        .with_n_rows(stop_after_n_rows)
+        .with_infer_schema_length(infer_schema_length.and_then(NonZeroUsize::new))
+        .with_batch_size(Some(batch_size))
+        .finish()?;
+
+    Ok(ExLazyFrame::new(select_columns(lf, columns)))
+}
+
+#[cfg(feature = "ndjson")]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn lf_to_ndjson(
+    data: ExLazyFrame,
+    filename: &str,
+    compression: Option<ExTextCompression>,
+    streaming: bool,
+) -> Result<(), ExplorerError> {
+    let lf = data.clone_inner();
+
+    if streaming {
+        let options = JsonWriterOptions {
+            maintain_order: true,
+        };
+        let lf = lf.with_comm_subplan_elim(false);
+
+        if compression.is_none() {
+            lf.sink_json(filename, options)?;
+        } else {
+            let file = File::create(filename)?;
+            let mut writer = CompressedWriter::new(BufWriter::new(file), compression)?;
+
+            sink_spooled("ndjson", &mut writer, |path| lf.sink_json(path, options))?;
+            writer.finish()?.flush()?;
+        }
+        Ok(())
+    } else {
+        let mut df = lf.collect()?;
+        let file = File::create(filename)?;
+        let mut writer = CompressedWriter::new(BufWriter::new(file), compression)?;
+
+        JsonWriter::new(&mut writer)
+            .with_json_format(JsonFormat::JsonLines)
+            .finish(&mut df)?;
+        writer.finish()?.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(all(feature = "ndjson", feature = "cloud"))]
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn lf_to_ndjson_cloud(
+    data: ExLazyFrame,
+    ex_entry: ExCloudEntry,
+    compression: Option<ExTextCompression>,
+    streaming: bool,
+) -> Result<(), ExplorerError> {
+    let lf = data.clone_inner();
+    let mut cloud_writer = build_cloud_writer(&ex_entry)?;
+    let mut writer = CompressedWriter::new(&mut cloud_writer, compression)?;
+
+    if streaming {
+        let options = JsonWriterOptions {
+            maintain_order: true,
+        };
+        let lf = lf.with_comm_subplan_elim(false);
+
+        sink_spooled("ndjson", &mut writer, |path| lf.sink_json(path, options))?;
+    } else {
+        let mut df = lf.collect()?;
+
+        JsonWriter::new(&mut writer)
+            .with_json_format(JsonFormat::JsonLines)
+            .finish(&mut df)?;
+    }
+
+    writer.finish()?;
+    let _ = cloud_writer.finish()?;
+    Ok(())
+}
+
 #[cfg(not(feature = "ndjson"))]
 #[rustler::nif]
 pub fn lf_from_ndjson(
@@ -323,3 +848,43 @@ pub fn lf_from_ndjson(
         This is mostly due to this feature being incompatible with your computer's architecture. \
         Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
 }
+
+#[cfg(not(all(feature = "ndjson", feature = "cloud")))]
+#[rustler::nif]
+pub fn lf_from_ndjson_cloud(
+    _ex_entry: ExCloudEntry,
+    _stop_after_n_rows: Option<usize>,
+    _columns: Option<Vec<String>>,
+    _infer_schema_length: Option<usize>,
+    _batch_size: usize,
+) -> Result<ExLazyFrame, ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" and \"ndjson\" features enabled. \
+        This is mostly due to these feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+#[cfg(not(feature = "ndjson"))]
+#[rustler::nif]
+pub fn lf_to_ndjson(
+    _data: ExLazyFrame,
+    _filename: &str,
+    _compression: Option<ExTextCompression>,
+    _streaming: bool,
+) -> Result<(), ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"ndjson\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+#[cfg(not(all(feature = "ndjson", feature = "cloud")))]
+#[rustler::nif]
+pub fn lf_to_ndjson_cloud(
+    _data: ExLazyFrame,
+    _ex_entry: ExCloudEntry,
+    _compression: Option<ExTextCompression>,
+    _streaming: bool,
+) -> Result<(), ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"cloud\" and \"ndjson\" features enabled. \
+        This is mostly due to these feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
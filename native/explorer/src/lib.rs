@@ -13,9 +13,15 @@ use mimalloc::MiMalloc;
 #[global_allocator]
 static GLOBAL: MiMalloc = MiMalloc;
 
+mod arrow_c_data;
+mod batched_reader;
+mod batched_writer;
+#[cfg(feature = "cloud")]
+mod cloud_reader;
 #[cfg(feature = "cloud")]
 mod cloud_writer;
 
+mod compressed_writer;
 mod dataframe;
 mod datatypes;
 mod encoding;
@@ -1,9 +1,12 @@
 use crate::{
+    arrow_c_data::{self, ExArrowCData},
     datatypes::{
         ExCorrelationMethod, ExDate, ExDecimal, ExNaiveDateTime, ExRankMethod, ExSeriesDtype,
         ExTime, ExTimeUnit, ExValidValue,
     },
-    encoding, ExDataFrame, ExSeries, ExplorerError,
+    encoding,
+    error::check_distribution,
+    ExDataFrame, ExSeries, ExplorerError,
 };
 
 use encoding::encode_naive_datetime;
@@ -11,9 +14,12 @@ use encoding::encode_naive_datetime;
 use polars::prelude::*;
 use polars_ops::chunked_array::cov::{cov, pearson_corr};
 use polars_ops::prelude::peaks::*;
-use rustler::{Binary, Encoder, Env, Term};
+use rustler::{Binary, Encoder, Env, ResourceArc, Term};
 
+pub mod density;
+pub mod fit;
 pub mod from_list;
+pub mod hypothesis;
 pub mod log;
 
 #[rustler::nif]
@@ -781,6 +787,18 @@ pub fn s_to_iovec(env: Env, series: ExSeries) -> Result<Term, ExplorerError> {
     }
 }
 
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_to_arrow_c_data(
+    series: ExSeries,
+) -> Result<(ResourceArc<ExArrowCData>, u64, u64), ExplorerError> {
+    Ok(ExArrowCData::export(&series).into_resource())
+}
+
+#[rustler::nif]
+pub fn s_from_arrow_c_data(array_ptr: u64, schema_ptr: u64) -> Result<ExSeries, ExplorerError> {
+    Ok(ExSeries::new(arrow_c_data::import(array_ptr, schema_ptr)?))
+}
+
 #[rustler::nif(schedule = "DirtyCpu")]
 pub fn s_sum(env: Env, s: ExSeries) -> Result<Term, ExplorerError> {
     match s.dtype() {
@@ -1743,116 +1761,94 @@ use statrs::distribution::{
     Continuous, ContinuousCDF,
     Beta, Cauchy, Chi, ChiSquared, Dirac, Erlang, Exp,
     FisherSnedecor, Gamma, Gumbel, InverseGamma, Laplace,
-    // LogNormal, NegativeBinomial, Normal, Pareto,
-    // StudentsT, Triangular, Uniform, Weibull
+    LogNormal, Normal, Pareto,
+    StudentsT, Triangular, Uniform, Weibull,
+    Discrete, DiscreteCDF,
+    Bernoulli, Binomial, Categorical, DiscreteUniform,
+    Geometric, Hypergeometric, NegativeBinomial, Poisson
 };
 
+// Most `statrs` distributions panic when asked for the quantile of
+// a probability outside of [0, 1], so those (and NaN) become nil.
+fn continuous_dist_apply_inverse<F>(s: &ExSeries, f: F) -> Result<ExSeries, ExplorerError>
+where
+    F: Fn(f64) -> f64,
+{
+    let s1: Float64Chunked = s
+        .f64()?
+        .into_iter()
+        .map(|opt| opt.and_then(|p| (0.0..=1.0).contains(&p).then(|| f(p))))
+        .collect();
+    Ok(ExSeries::new(s1.with_name(s.name().clone()).into_series()))
+}
+
 macro_rules! define_continuous_dist_functions {
-    // Distributions with 1 parameter
     ($dist_struct:ident;
-     $pdf_name:ident; $cdf_name:ident; $inv_cdf_name:ident;
-     $p1:ident : $t1:ident) => {
+     $pdf_name:ident; $ln_pdf_name:ident; $cdf_name:ident; $sf_name:ident; $inv_cdf_name:ident;
+     $($p:ident : $t:ident);+) => {
         #[rustler::nif(schedule = "DirtyCpu")]
-        pub fn $pdf_name(s: ExSeries, $p1: $t1)
+        pub fn $pdf_name(s: ExSeries, $($p: $t),+)
                 -> Result<ExSeries, ExplorerError> {
-            let dist = $dist_struct::new($p1).unwrap();
+            let dist = check_distribution!($dist_struct::new($($p),+))?;
             let s1 = s.f64()?.apply_values(|x| dist.pdf(x)).into();
             Ok(ExSeries::new(s1))
         }
 
         #[rustler::nif(schedule = "DirtyCpu")]
-        pub fn $cdf_name(s: ExSeries, $p1: $t1)
+        pub fn $ln_pdf_name(s: ExSeries, $($p: $t),+)
                 -> Result<ExSeries, ExplorerError> {
-            let dist = $dist_struct::new($p1).unwrap();
-            let s1 = s.f64()?.apply_values(|x| dist.cdf(x)).into();
+            let dist = check_distribution!($dist_struct::new($($p),+))?;
+            let s1 = s.f64()?.apply_values(|x| dist.ln_pdf(x)).into();
             Ok(ExSeries::new(s1))
         }
 
         #[rustler::nif(schedule = "DirtyCpu")]
-        pub fn $inv_cdf_name(s: ExSeries, $p1: $t1)
+        pub fn $cdf_name(s: ExSeries, $($p: $t),+)
                 -> Result<ExSeries, ExplorerError> {
-            let dist = $dist_struct::new($p1).unwrap();
-            let s1 = s.f64()?.apply_values(|x| dist.inverse_cdf(x)).into();
-            Ok(ExSeries::new(s1))
-        }
-    };
-
-    // Distributions with 2 parameters
-    ($dist_struct:ident;
-     $pdf_name:ident; $cdf_name:ident; $inv_cdf_name:ident;
-     $p1:ident : $t1:ident ; $p2:ident : $t2:ident) => {
-        #[rustler::nif(schedule = "DirtyCpu")]
-        pub fn $pdf_name(s: ExSeries, $p1: $t1, $p2: $t2)
-                -> Result<ExSeries, ExplorerError> {
-            let dist = $dist_struct::new($p1, $p2).unwrap();
-            let s1 = s.f64()?.apply_values(|x| dist.pdf(x)).into();
+            let dist = check_distribution!($dist_struct::new($($p),+))?;
+            let s1 = s.f64()?.apply_values(|x| dist.cdf(x)).into();
             Ok(ExSeries::new(s1))
         }
 
         #[rustler::nif(schedule = "DirtyCpu")]
-        pub fn $cdf_name(s: ExSeries, $p1: $t1, $p2: $t2)
+        pub fn $sf_name(s: ExSeries, $($p: $t),+)
                 -> Result<ExSeries, ExplorerError> {
-            let dist = $dist_struct::new($p1, $p2).unwrap();
-            let s1 = s.f64()?.apply_values(|x| dist.cdf(x)).into();
+            let dist = check_distribution!($dist_struct::new($($p),+))?;
+            let s1 = s.f64()?.apply_values(|x| dist.sf(x)).into();
             Ok(ExSeries::new(s1))
         }
 
         #[rustler::nif(schedule = "DirtyCpu")]
-        pub fn $inv_cdf_name(s: ExSeries, $p1: $t1, $p2: $t2)
+        pub fn $inv_cdf_name(s: ExSeries, $($p: $t),+)
                 -> Result<ExSeries, ExplorerError> {
-            let dist = $dist_struct::new($p1, $p2).unwrap();
-            let s1 = s.f64()?.apply_values(|x| dist.inverse_cdf(x)).into();
-            Ok(ExSeries::new(s1))
+            let dist = check_distribution!($dist_struct::new($($p),+))?;
+            continuous_dist_apply_inverse(&s, |p| dist.inverse_cdf(p))
         }
     };
-
-    // Distributions with 3 parameters
-    ($dist_struct:ident;
-        $pdf_name:ident; $cdf_name:ident; $inv_cdf_name:ident;
-        $p1:ident : $t1:ident ; $p2:ident : $t2:ident; $p3:ident : $t3:ident) => {
-           #[rustler::nif(schedule = "DirtyCpu")]
-           pub fn $pdf_name(s: ExSeries, $p1: $t1, $p2: $t2, $p3: $t3)
-                   -> Result<ExSeries, ExplorerError> {
-               let dist = $dist_struct::new($p1, $p2, $p3).unwrap();
-               let s1 = s.f64()?.apply_values(|x| dist.pdf(x)).into();
-               Ok(ExSeries::new(s1))
-           }
-   
-           #[rustler::nif(schedule = "DirtyCpu")]
-           pub fn $cdf_name(s: ExSeries, $p1: $t1, $p2: $t2, $p3: $t3)
-                   -> Result<ExSeries, ExplorerError> {
-               let dist = $dist_struct::new($p1, $p2, $p3).unwrap();
-               let s1 = s.f64()?.apply_values(|x| dist.cdf(x)).into();
-               Ok(ExSeries::new(s1))
-           }
-   
-           #[rustler::nif(schedule = "DirtyCpu")]
-           pub fn $inv_cdf_name(s: ExSeries, $p1: $t1, $p2: $t2, $p3: $t3)
-                   -> Result<ExSeries, ExplorerError> {
-               let dist = $dist_struct::new($p1, $p2, $p3).unwrap();
-               let s1 = s.f64()?.apply_values(|x| dist.inverse_cdf(x)).into();
-               Ok(ExSeries::new(s1))
-           }
-       };
 }
 
 define_continuous_dist_functions!(
-    Beta; s_beta_pdf; s_beta_cdf; s_beta_inverse_cdf;
-    shape_a: f64; shape_b: f64
+    Beta;
+    s_beta_pdf; s_beta_ln_pdf; s_beta_cdf; s_beta_sf; s_beta_inverse_cdf;
+    a: f64; b: f64
 );
 
 define_continuous_dist_functions!(
-    Cauchy; s_cauchy_pdf; s_cauchy_cdf; s_cauchy_inverse_cdf;
+    Cauchy;
+    s_cauchy_pdf; s_cauchy_ln_pdf; s_cauchy_cdf; s_cauchy_sf; s_cauchy_inverse_cdf;
     location: f64; scale: f64
 );
 
 define_continuous_dist_functions!(
-    Chi; s_chi_pdf; s_chi_cdf; s_chi_inverse_cdf;
+    Chi;
+    s_chi_pdf; s_chi_ln_pdf; s_chi_cdf; s_chi_sf; s_chi_inverse_cdf;
     freedom: u64
 );
 
 define_continuous_dist_functions!(
-    ChiSquared; s_chi_squared_pdf; s_chi_squared_cdf; s_chi_squared_inverse_cdf;
+    ChiSquared;
+    s_chi_squared_pdf; s_chi_squared_ln_pdf; s_chi_squared_cdf;
+    s_chi_squared_sf; s_chi_squared_inverse_cdf;
     freedom: f64
 );
 
@@ -1860,79 +1856,282 @@ define_continuous_dist_functions!(
 #[rustler::nif(schedule = "DirtyCpu")]
 pub fn s_dirac_cdf(s: ExSeries, v: f64)
         -> Result<ExSeries, ExplorerError> {
-    let dist = Dirac::new(v).unwrap();
+    let dist = check_distribution!(Dirac::new(v))?;
     let s1 = s.f64()?.apply_values(|x| dist.cdf(x)).into();
     Ok(ExSeries::new(s1))
 }
 
 #[rustler::nif(schedule = "DirtyCpu")]
-pub fn s_dirac_inverse_cdf(s: ExSeries, v: f64)
+pub fn s_dirac_sf(s: ExSeries, v: f64)
         -> Result<ExSeries, ExplorerError> {
-    let dist = Dirac::new(v).unwrap();
-    let s1 = s.f64()?.apply_values(|x| dist.inverse_cdf(x)).into();
+    let dist = check_distribution!(Dirac::new(v))?;
+    let s1 = s.f64()?.apply_values(|x| dist.sf(x)).into();
     Ok(ExSeries::new(s1))
 }
 
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_dirac_inverse_cdf(s: ExSeries, v: f64)
+        -> Result<ExSeries, ExplorerError> {
+    let dist = check_distribution!(Dirac::new(v))?;
+    continuous_dist_apply_inverse(&s, |p| dist.inverse_cdf(p))
+}
+
 define_continuous_dist_functions!(
-    Erlang; s_erlang_pdf; s_erlang_cdf; s_erlang_inverse_cdf;
+    Erlang;
+    s_erlang_pdf; s_erlang_ln_pdf; s_erlang_cdf; s_erlang_sf; s_erlang_inverse_cdf;
     shape: u64; rate: f64
 );
 
 define_continuous_dist_functions!(
-    Exp; s_exp_pdf; s_exp_cdf; s_exp_inverse_cdf;
+    Exp;
+    s_exp_pdf; s_exp_ln_pdf; s_exp_cdf; s_exp_sf; s_exp_inverse_cdf;
     rate: f64
 );
 
 define_continuous_dist_functions!(
     FisherSnedecor;
-    s_fisher_snedecor_pdf; s_fisher_snedecor_cdf; s_fisher_snedecor_inverse_cdf;
+    s_fisher_snedecor_pdf; s_fisher_snedecor_ln_pdf; s_fisher_snedecor_cdf;
+    s_fisher_snedecor_sf; s_fisher_snedecor_inverse_cdf;
     freedom_1: f64; freedom_2: f64
 );
 
 define_continuous_dist_functions!(
     Gamma;
-    s_gamma_pdf; s_gamma_cdf; s_gamma_inverse_cdf;
+    s_gamma_pdf; s_gamma_ln_pdf; s_gamma_cdf; s_gamma_sf; s_gamma_inverse_cdf;
     shape: f64; rate: f64
 );
 
 define_continuous_dist_functions!(
     Gumbel;
-    s_gumbel_pdf; s_gumbel_cdf; s_gumbel_inverse_cdf;
+    s_gumbel_pdf; s_gumbel_ln_pdf; s_gumbel_cdf; s_gumbel_sf; s_gumbel_inverse_cdf;
     location: f64; scale: f64
 );
 
 define_continuous_dist_functions!(
     InverseGamma;
-    s_inverse_gamma_pdf; s_inverse_gamma_cdf; s_inverse_gamma_inverse_cdf;
+    s_inverse_gamma_pdf; s_inverse_gamma_ln_pdf; s_inverse_gamma_cdf;
+    s_inverse_gamma_sf; s_inverse_gamma_inverse_cdf;
     shape: f64; rate: f64
 );
 
 define_continuous_dist_functions!(
     Laplace;
-    s_laplace_pdf; s_laplace_cdf; s_laplace_inverse_cdf;
+    s_laplace_pdf; s_laplace_ln_pdf; s_laplace_cdf; s_laplace_sf; s_laplace_inverse_cdf;
     location: f64; scale: f64
 );
 
-// #[rustler::nif(schedule = "DirtyCpu")]
-// pub fn s_beta_pdf(s: ExSeries, shape_a: f64, shape_b: f64)
-//         -> Result<ExSeries, ExplorerError> {
-//     let dist = Beta::new(shape_a, shape_b).unwrap();
-//     let s1 = s.f64()?.apply_values(|x| dist.pdf(x)).into();
-//     Ok(ExSeries::new(s1))
-// }
-
-// #[rustler::nif(schedule = "DirtyCpu")]
-// pub fn s_beta_cdf(s: ExSeries, shape_a: f64, shape_b: f64)
-//         -> Result<ExSeries, ExplorerError> {
-//     let dist = Beta::new(shape_a, shape_b).unwrap();
-//     let s1 = s.f64()?.apply_values(|x| dist.cdf(x)).into();
-//     Ok(ExSeries::new(s1))
-// }
-
-// #[rustler::nif(schedule = "DirtyCpu")]
-// pub fn s_beta_inverse_cdf(s: ExSeries, shape_a: f64, shape_b: f64)
-//         -> Result<ExSeries, ExplorerError> {
-//     let dist = Beta::new(shape_a, shape_b).unwrap();
-//     let s1 = s.f64()?.apply_values(|x| dist.inverse_cdf(x)).into();
-//     Ok(ExSeries::new(s1))
-// }
\ No newline at end of file
+define_continuous_dist_functions!(
+    LogNormal;
+    s_log_normal_pdf; s_log_normal_ln_pdf; s_log_normal_cdf;
+    s_log_normal_sf; s_log_normal_inverse_cdf;
+    location: f64; scale: f64
+);
+
+define_continuous_dist_functions!(
+    Normal;
+    s_normal_pdf; s_normal_ln_pdf; s_normal_cdf; s_normal_sf; s_normal_inverse_cdf;
+    mu: f64; sigma: f64
+);
+
+define_continuous_dist_functions!(
+    Pareto;
+    s_pareto_pdf; s_pareto_ln_pdf; s_pareto_cdf; s_pareto_sf; s_pareto_inverse_cdf;
+    scale: f64; shape: f64
+);
+
+define_continuous_dist_functions!(
+    StudentsT;
+    s_students_t_pdf; s_students_t_ln_pdf; s_students_t_cdf;
+    s_students_t_sf; s_students_t_inverse_cdf;
+    location: f64; scale: f64; freedom: f64
+);
+
+define_continuous_dist_functions!(
+    Triangular;
+    s_triangular_pdf; s_triangular_ln_pdf; s_triangular_cdf;
+    s_triangular_sf; s_triangular_inverse_cdf;
+    min: f64; max: f64; mode: f64
+);
+
+define_continuous_dist_functions!(
+    Uniform;
+    s_uniform_pdf; s_uniform_ln_pdf; s_uniform_cdf; s_uniform_sf; s_uniform_inverse_cdf;
+    min: f64; max: f64
+);
+
+define_continuous_dist_functions!(
+    Weibull;
+    s_weibull_pdf; s_weibull_ln_pdf; s_weibull_cdf; s_weibull_sf; s_weibull_inverse_cdf;
+    shape: f64; scale: f64
+);
+
+// Discrete distributions
+//
+// The discrete distributions are defined over non-negative integers,
+// so we accept any integer series and return zero probability
+// for negative values instead of failing the cast to `u64`.
+
+fn discrete_dist_integers(s: &ExSeries) -> Result<Series, ExplorerError> {
+    if s.dtype().is_integer() {
+        Ok(s.cast(&DataType::Int64)?)
+    } else {
+        Err(ExplorerError::Other(format!(
+            "expected an integer series, got {}",
+            s.dtype()
+        )))
+    }
+}
+
+fn discrete_dist_apply<F>(s: &ExSeries, f: F) -> Result<ExSeries, ExplorerError>
+where
+    F: Fn(i64) -> f64,
+{
+    let integers = discrete_dist_integers(s)?;
+    let s1: Float64Chunked = integers.i64()?.into_iter().map(|opt| opt.map(&f)).collect();
+    Ok(ExSeries::new(s1.with_name(s.name().clone()).into_series()))
+}
+
+// Like `continuous_dist_apply_inverse`, but the quantiles are integers.
+fn discrete_dist_apply_inverse<F>(s: &ExSeries, f: F) -> Result<ExSeries, ExplorerError>
+where
+    F: Fn(f64) -> u64,
+{
+    let s1: UInt64Chunked = s
+        .f64()?
+        .into_iter()
+        .map(|opt| opt.and_then(|p| (0.0..=1.0).contains(&p).then(|| f(p))))
+        .collect();
+    Ok(ExSeries::new(s1.with_name(s.name().clone()).into_series()))
+}
+
+macro_rules! define_discrete_dist_functions {
+    ($dist:ident::new($($arg:expr),+);
+     $pmf_name:ident; $ln_pmf_name:ident; $cdf_name:ident; $sf_name:ident; $inv_cdf_name:ident;
+     $($p:ident : $t:ty);+) => {
+        #[rustler::nif(schedule = "DirtyCpu")]
+        pub fn $pmf_name(s: ExSeries, $($p: $t),+)
+                -> Result<ExSeries, ExplorerError> {
+            let dist = check_distribution!($dist::new($($arg),+))?;
+            discrete_dist_apply(&s, |x| if x < 0 { 0.0 } else { dist.pmf(x as u64) })
+        }
+
+        #[rustler::nif(schedule = "DirtyCpu")]
+        pub fn $ln_pmf_name(s: ExSeries, $($p: $t),+)
+                -> Result<ExSeries, ExplorerError> {
+            let dist = check_distribution!($dist::new($($arg),+))?;
+            discrete_dist_apply(&s, |x| if x < 0 { f64::NEG_INFINITY } else { dist.ln_pmf(x as u64) })
+        }
+
+        #[rustler::nif(schedule = "DirtyCpu")]
+        pub fn $cdf_name(s: ExSeries, $($p: $t),+)
+                -> Result<ExSeries, ExplorerError> {
+            let dist = check_distribution!($dist::new($($arg),+))?;
+            discrete_dist_apply(&s, |x| if x < 0 { 0.0 } else { dist.cdf(x as u64) })
+        }
+
+        #[rustler::nif(schedule = "DirtyCpu")]
+        pub fn $sf_name(s: ExSeries, $($p: $t),+)
+                -> Result<ExSeries, ExplorerError> {
+            let dist = check_distribution!($dist::new($($arg),+))?;
+            discrete_dist_apply(&s, |x| if x < 0 { 1.0 } else { dist.sf(x as u64) })
+        }
+
+        #[rustler::nif(schedule = "DirtyCpu")]
+        pub fn $inv_cdf_name(s: ExSeries, $($p: $t),+)
+                -> Result<ExSeries, ExplorerError> {
+            let dist = check_distribution!($dist::new($($arg),+))?;
+            discrete_dist_apply_inverse(&s, |p| dist.inverse_cdf(p))
+        }
+    };
+}
+
+define_discrete_dist_functions!(
+    Bernoulli::new(p);
+    s_bernoulli_pmf; s_bernoulli_ln_pmf; s_bernoulli_cdf;
+    s_bernoulli_sf; s_bernoulli_inverse_cdf;
+    p: f64
+);
+
+define_discrete_dist_functions!(
+    Binomial::new(p, n);
+    s_binomial_pmf; s_binomial_ln_pmf; s_binomial_cdf;
+    s_binomial_sf; s_binomial_inverse_cdf;
+    p: f64; n: u64
+);
+
+define_discrete_dist_functions!(
+    Categorical::new(&prob_mass);
+    s_categorical_pmf; s_categorical_ln_pmf; s_categorical_cdf;
+    s_categorical_sf; s_categorical_inverse_cdf;
+    prob_mass: Vec<f64>
+);
+
+define_discrete_dist_functions!(
+    Geometric::new(p);
+    s_geometric_pmf; s_geometric_ln_pmf; s_geometric_cdf;
+    s_geometric_sf; s_geometric_inverse_cdf;
+    p: f64
+);
+
+define_discrete_dist_functions!(
+    Hypergeometric::new(pop_size, success_states, sample_size);
+    s_hypergeometric_pmf; s_hypergeometric_ln_pmf; s_hypergeometric_cdf;
+    s_hypergeometric_sf; s_hypergeometric_inverse_cdf;
+    pop_size: u64; success_states: u64; sample_size: u64
+);
+
+define_discrete_dist_functions!(
+    NegativeBinomial::new(r, p);
+    s_negative_binomial_pmf; s_negative_binomial_ln_pmf; s_negative_binomial_cdf;
+    s_negative_binomial_sf; s_negative_binomial_inverse_cdf;
+    r: f64; p: f64
+);
+
+define_discrete_dist_functions!(
+    Poisson::new(lambda);
+    s_poisson_pmf; s_poisson_ln_pmf; s_poisson_cdf;
+    s_poisson_sf; s_poisson_inverse_cdf;
+    lambda: f64
+);
+
+// Unlike the other discrete distributions, the discrete uniform
+// is defined over all integers, so negative values are valid.
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_discrete_uniform_pmf(s: ExSeries, min: i64, max: i64)
+        -> Result<ExSeries, ExplorerError> {
+    let dist = check_distribution!(DiscreteUniform::new(min, max))?;
+    discrete_dist_apply(&s, |x| dist.pmf(x))
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_discrete_uniform_ln_pmf(s: ExSeries, min: i64, max: i64)
+        -> Result<ExSeries, ExplorerError> {
+    let dist = check_distribution!(DiscreteUniform::new(min, max))?;
+    discrete_dist_apply(&s, |x| dist.ln_pmf(x))
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_discrete_uniform_cdf(s: ExSeries, min: i64, max: i64)
+        -> Result<ExSeries, ExplorerError> {
+    let dist = check_distribution!(DiscreteUniform::new(min, max))?;
+    discrete_dist_apply(&s, |x| dist.cdf(x))
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_discrete_uniform_sf(s: ExSeries, min: i64, max: i64)
+        -> Result<ExSeries, ExplorerError> {
+    let dist = check_distribution!(DiscreteUniform::new(min, max))?;
+    discrete_dist_apply(&s, |x| dist.sf(x))
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_discrete_uniform_inverse_cdf(s: ExSeries, min: i64, max: i64)
+        -> Result<ExSeries, ExplorerError> {
+    let dist = check_distribution!(DiscreteUniform::new(min, max))?;
+    let s1: Int64Chunked = s
+        .f64()?
+        .into_iter()
+        .map(|opt| opt.and_then(|p| (0.0..=1.0).contains(&p).then(|| dist.inverse_cdf(p))))
+        .collect();
+    Ok(ExSeries::new(s1.with_name(s.name().clone()).into_series()))
+}
@@ -0,0 +1,153 @@
+// Non-parametric estimates of the distribution of a series:
+// kernel density estimation (KDE) and the empirical CDF.
+//
+// Both functions estimate the distribution from the values of one series
+// and evaluate it at the points given by another series. Nils and NaNs
+// are ignored in the values, and nil points evaluate to nil.
+
+use crate::datatypes::{ExKdeBandwidth, ExKdeKernel};
+use crate::{ExSeries, ExplorerError};
+use polars::prelude::*;
+use rayon::prelude::*;
+use std::f64::consts::PI;
+
+// Returns the non-nil and non-NaN values of the series, sorted.
+fn sorted_values(s: &ExSeries) -> Result<Vec<f64>, ExplorerError> {
+    let mut values: Vec<f64> = s
+        .cast(&DataType::Float64)?
+        .f64()?
+        .into_iter()
+        .flatten()
+        .filter(|x| !x.is_nan())
+        .collect();
+
+    values.sort_by(f64::total_cmp);
+    Ok(values)
+}
+
+fn evaluate_at_points<F>(points: &ExSeries, f: F) -> Result<ExSeries, ExplorerError>
+where
+    F: Fn(f64) -> f64 + Sync,
+{
+    let name = points.name().clone();
+    let points: Vec<Option<f64>> = points.cast(&DataType::Float64)?.f64()?.into_iter().collect();
+    let values: Vec<Option<f64>> = points.par_iter().map(|point| point.map(&f)).collect();
+
+    Ok(ExSeries::new(Series::new(name, values)))
+}
+
+// Quantile of sorted values with linear interpolation.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+// The rules of thumb assume that the data is normally distributed:
+//
+// * Scott: `std * n^(-1/5)`, like `scipy.stats.gaussian_kde`
+// * Silverman: `0.9 * min(std, IQR / 1.34) * n^(-1/5)`, like R's `bw.nrd0`
+//
+// Silverman's rule is more robust for skewed or multimodal data.
+fn bandwidth(sorted: &[f64], bandwidth: ExKdeBandwidth) -> Result<f64, ExplorerError> {
+    let n = sorted.len() as f64;
+
+    let h = match bandwidth {
+        ExKdeBandwidth::Fixed(h) => h,
+        ExKdeBandwidth::Scott | ExKdeBandwidth::Silverman if sorted.len() < 2 => {
+            return Err(ExplorerError::Other(
+                "kde needs at least two values to select the bandwidth".into(),
+            ))
+        }
+        rule => {
+            let mean = sorted.iter().sum::<f64>() / n;
+            let variance = sorted.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+            let std = variance.sqrt();
+
+            match rule {
+                ExKdeBandwidth::Scott => std * n.powf(-0.2),
+                _ => {
+                    let iqr = quantile(sorted, 0.75) - quantile(sorted, 0.25);
+                    let spread = if iqr > 0.0 { std.min(iqr / 1.34) } else { std };
+
+                    0.9 * spread * n.powf(-0.2)
+                }
+            }
+        }
+    };
+
+    if h.is_finite() && h > 0.0 {
+        Ok(h)
+    } else {
+        Err(ExplorerError::Other(format!(
+            "kde bandwidth must be a positive number, got {h}"
+        )))
+    }
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_kde(
+    s: ExSeries,
+    points: ExSeries,
+    kernel: ExKdeKernel,
+    bandwidth_rule: ExKdeBandwidth,
+) -> Result<ExSeries, ExplorerError> {
+    let values = sorted_values(&s)?;
+
+    if values.is_empty() {
+        return evaluate_at_points(&points, |_x| f64::NAN);
+    }
+
+    let h = bandwidth(&values, bandwidth_rule)?;
+    let scale = 1.0 / (values.len() as f64 * h);
+
+    match kernel {
+        ExKdeKernel::Gaussian => {
+            let norm = 1.0 / (2.0 * PI).sqrt();
+
+            evaluate_at_points(&points, |x| {
+                let density: f64 = values
+                    .iter()
+                    .map(|xi| {
+                        let u = (x - xi) / h;
+                        norm * (-0.5 * u * u).exp()
+                    })
+                    .sum();
+
+                density * scale
+            })
+        }
+        // The Epanechnikov kernel is zero outside of [-h, h], so we only
+        // need to sum the values in that window of the sorted values.
+        ExKdeKernel::Epanechnikov => evaluate_at_points(&points, |x| {
+            let start = values.partition_point(|xi| *xi < x - h);
+            let end = values.partition_point(|xi| *xi <= x + h);
+
+            let density: f64 = values[start..end]
+                .iter()
+                .map(|xi| {
+                    let u = (x - xi) / h;
+                    0.75 * (1.0 - u * u)
+                })
+                .sum();
+
+            density * scale
+        }),
+    }
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_ecdf(s: ExSeries, points: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let values = sorted_values(&s)?;
+    let n = values.len() as f64;
+
+    evaluate_at_points(&points, |x| {
+        if x.is_nan() {
+            f64::NAN
+        } else {
+            values.partition_point(|xi| *xi <= x) as f64 / n
+        }
+    })
+}
@@ -0,0 +1,316 @@
+// Estimates the parameters of a distribution from the values of a series.
+//
+// Every fit returns a dataframe with a single row, with one column per
+// parameter followed by `log_likelihood`, `aic` and `bic`. The parameter
+// columns are named and ordered like the arguments of the matching
+// `s_<dist>_cdf` and `df_draw_from_<dist>` functions, so they can be
+// passed back to them as they are.
+//
+// Nils and NaNs are ignored.
+
+use crate::error::check_distribution;
+use crate::{ExDataFrame, ExSeries, ExplorerError};
+use polars::prelude::*;
+use statrs::distribution::{
+    Beta, Continuous, Discrete, Exp, Gamma, LogNormal, Normal, Poisson, Weibull,
+};
+use statrs::function::gamma::digamma;
+
+const MAX_ITERATIONS: usize = 100;
+const TOLERANCE: f64 = 1e-10;
+
+fn fit_values(s: &ExSeries, min_len: usize) -> Result<Vec<f64>, ExplorerError> {
+    let values: Vec<f64> = s
+        .cast(&DataType::Float64)?
+        .f64()?
+        .into_iter()
+        .flatten()
+        .filter(|x| !x.is_nan())
+        .collect();
+
+    if values.len() < min_len {
+        return Err(ExplorerError::Other(format!(
+            "fitting this distribution needs at least {min_len} values, got {}",
+            values.len()
+        )));
+    }
+
+    Ok(values)
+}
+
+fn check_support(
+    values: &[f64],
+    support: &str,
+    in_support: impl Fn(f64) -> bool,
+) -> Result<(), ExplorerError> {
+    if values.iter().all(|x| in_support(*x)) {
+        Ok(())
+    } else {
+        Err(ExplorerError::Other(format!(
+            "fitting this distribution needs {support} values"
+        )))
+    }
+}
+
+// A sample the distribution can't be fitted to, even though all the
+// values are in its support, such as a sample with no variation.
+fn degenerate_sample(distribution: &str, parameters: String, reason: &str) -> ExplorerError {
+    ExplorerError::Distribution {
+        distribution: distribution.to_string(),
+        parameters,
+        reason: reason.to_string(),
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+// The biased (maximum likelihood) variance.
+fn variance(values: &[f64], mean: f64) -> f64 {
+    values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / values.len() as f64
+}
+
+fn fit_result(
+    params: &[(&str, f64)],
+    log_likelihood: f64,
+    n: usize,
+) -> Result<ExDataFrame, ExplorerError> {
+    let k = params.len() as f64;
+    let aic = 2.0 * k - 2.0 * log_likelihood;
+    let bic = k * (n as f64).ln() - 2.0 * log_likelihood;
+
+    let mut columns: Vec<Series> = params
+        .iter()
+        .map(|(name, value)| Series::new((*name).into(), [*value]))
+        .collect();
+
+    columns.push(Series::new("log_likelihood".into(), [log_likelihood]));
+    columns.push(Series::new("aic".into(), [aic]));
+    columns.push(Series::new("bic".into(), [bic]));
+
+    Ok(ExDataFrame::new(DataFrame::new(columns)?))
+}
+
+fn continuous_log_likelihood<D: Continuous<f64, f64>>(dist: &D, values: &[f64]) -> f64 {
+    values.iter().map(|x| dist.ln_pdf(*x)).sum()
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_fit_normal(s: ExSeries) -> Result<ExDataFrame, ExplorerError> {
+    let values = fit_values(&s, 2)?;
+    let mu = mean(&values);
+    let sigma = variance(&values, mu).sqrt();
+
+    if sigma == 0.0 {
+        return Err(degenerate_sample(
+            "Normal",
+            format!("mu = {mu}, sigma = {sigma}"),
+            "All the values are equal, so there is no variation to fit sigma to",
+        ));
+    }
+
+    let dist = check_distribution!(Normal::new(mu, sigma))?;
+    let log_likelihood = continuous_log_likelihood(&dist, &values);
+
+    fit_result(
+        &[("mu", mu), ("sigma", sigma)],
+        log_likelihood,
+        values.len(),
+    )
+}
+
+// The parameters are the location and scale of the logarithm of the values.
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_fit_log_normal(s: ExSeries) -> Result<ExDataFrame, ExplorerError> {
+    let values = fit_values(&s, 2)?;
+    check_support(&values, "positive", |x| x > 0.0)?;
+
+    let logs: Vec<f64> = values.iter().map(|x| x.ln()).collect();
+    let location = mean(&logs);
+    let scale = variance(&logs, location).sqrt();
+
+    let dist = check_distribution!(LogNormal::new(location, scale))?;
+    let log_likelihood = continuous_log_likelihood(&dist, &values);
+
+    fit_result(
+        &[("location", location), ("scale", scale)],
+        log_likelihood,
+        values.len(),
+    )
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_fit_exp(s: ExSeries) -> Result<ExDataFrame, ExplorerError> {
+    let values = fit_values(&s, 1)?;
+    check_support(&values, "non-negative", |x| x >= 0.0)?;
+
+    let rate = 1.0 / mean(&values);
+
+    if rate.is_infinite() {
+        return Err(degenerate_sample(
+            "Exp",
+            format!("rate = {rate}"),
+            "All the values are zero, so the rate is infinite",
+        ));
+    }
+
+    let dist = check_distribution!(Exp::new(rate))?;
+    let log_likelihood = continuous_log_likelihood(&dist, &values);
+
+    fit_result(&[("rate", rate)], log_likelihood, values.len())
+}
+
+// The derivative of the digamma function, using the recurrence
+// `trigamma(x) = trigamma(x + 1) + 1 / x^2` until `x` is large enough
+// for its asymptotic expansion.
+fn trigamma(mut x: f64) -> f64 {
+    let mut result = 0.0;
+
+    while x < 6.0 {
+        result += 1.0 / (x * x);
+        x += 1.0;
+    }
+
+    let x2 = 1.0 / (x * x);
+    result
+        + 1.0 / x
+        + x2 / 2.0
+        + x2 / x * (1.0 / 6.0 - x2 * (1.0 / 30.0 - x2 * (1.0 / 42.0 - x2 / 30.0)))
+}
+
+// The maximum likelihood shape solves `ln(shape) - digamma(shape) = s`,
+// where `s = ln(mean(x)) - mean(ln(x))`. We start from Minka's closed form
+// approximation and refine it with Newton's method.
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_fit_gamma(s: ExSeries) -> Result<ExDataFrame, ExplorerError> {
+    let values = fit_values(&s, 2)?;
+    check_support(&values, "positive", |x| x > 0.0)?;
+
+    let mean_x = mean(&values);
+    let mean_ln_x = values.iter().map(|x| x.ln()).sum::<f64>() / values.len() as f64;
+    let target = mean_x.ln() - mean_ln_x;
+
+    let mut shape =
+        (3.0 - target + ((target - 3.0).powi(2) + 24.0 * target).sqrt()) / (12.0 * target);
+
+    for _ in 0..MAX_ITERATIONS {
+        let g = shape.ln() - digamma(shape) - target;
+        let dg = 1.0 / shape - trigamma(shape);
+        let next = shape - g / dg;
+        let next = if next > 0.0 { next } else { shape / 2.0 };
+        let converged = (next - shape).abs() < TOLERANCE * shape;
+
+        shape = next;
+
+        if converged {
+            break;
+        }
+    }
+
+    let rate = shape / mean_x;
+
+    let dist = check_distribution!(Gamma::new(shape, rate))?;
+    let log_likelihood = continuous_log_likelihood(&dist, &values);
+
+    fit_result(
+        &[("shape", shape), ("rate", rate)],
+        log_likelihood,
+        values.len(),
+    )
+}
+
+// Method of moments, since the maximum likelihood estimates of the beta
+// distribution have no closed form.
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_fit_beta(s: ExSeries) -> Result<ExDataFrame, ExplorerError> {
+    let values = fit_values(&s, 2)?;
+    check_support(&values, "strictly between 0 and 1", |x| x > 0.0 && x < 1.0)?;
+
+    let m = mean(&values);
+    let v = variance(&values, m);
+    let common = m * (1.0 - m) / v - 1.0;
+    let a = m * common;
+    let b = (1.0 - m) * common;
+
+    let dist = check_distribution!(Beta::new(a, b))?;
+    let log_likelihood = continuous_log_likelihood(&dist, &values);
+
+    fit_result(&[("a", a), ("b", b)], log_likelihood, values.len())
+}
+
+// The maximum likelihood shape `k` is the root of
+//
+//     sum(x^k * ln(x)) / sum(x^k) - 1 / k - mean(ln(x))
+//
+// which we find with Newton's method, and then `scale = mean(x^k)^(1/k)`.
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_fit_weibull(s: ExSeries) -> Result<ExDataFrame, ExplorerError> {
+    let values = fit_values(&s, 2)?;
+    check_support(&values, "positive", |x| x > 0.0)?;
+
+    let n = values.len() as f64;
+    let logs: Vec<f64> = values.iter().map(|x| x.ln()).collect();
+    let mean_ln_x = mean(&logs);
+    let std_ln_x = variance(&logs, mean_ln_x).sqrt();
+
+    let mut shape = if std_ln_x > 0.0 { 1.2 / std_ln_x } else { 1.0 };
+
+    for _ in 0..MAX_ITERATIONS {
+        let (mut sum_xk, mut sum_xk_ln, mut sum_xk_ln2) = (0.0, 0.0, 0.0);
+
+        for ln_x in &logs {
+            let xk = (shape * ln_x).exp();
+            sum_xk += xk;
+            sum_xk_ln += xk * ln_x;
+            sum_xk_ln2 += xk * ln_x * ln_x;
+        }
+
+        let f = sum_xk_ln / sum_xk - 1.0 / shape - mean_ln_x;
+        let df = (sum_xk_ln2 * sum_xk - sum_xk_ln * sum_xk_ln) / (sum_xk * sum_xk)
+            + 1.0 / (shape * shape);
+        let next = shape - f / df;
+        let next = if next > 0.0 { next } else { shape / 2.0 };
+        let converged = (next - shape).abs() < TOLERANCE * shape;
+
+        shape = next;
+
+        if converged {
+            break;
+        }
+    }
+
+    let scale = (values.iter().map(|x| x.powf(shape)).sum::<f64>() / n).powf(1.0 / shape);
+
+    let dist = check_distribution!(Weibull::new(shape, scale))?;
+    let log_likelihood = continuous_log_likelihood(&dist, &values);
+
+    fit_result(
+        &[("shape", shape), ("scale", scale)],
+        log_likelihood,
+        values.len(),
+    )
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_fit_poisson(s: ExSeries) -> Result<ExDataFrame, ExplorerError> {
+    let values = fit_values(&s, 1)?;
+    check_support(&values, "non-negative integer", |x| {
+        x >= 0.0 && x.fract() == 0.0
+    })?;
+
+    let lambda = mean(&values);
+
+    if lambda == 0.0 {
+        return Err(degenerate_sample(
+            "Poisson",
+            format!("lambda = {lambda}"),
+            "All the values are zero, so lambda is zero",
+        ));
+    }
+
+    let dist = check_distribution!(Poisson::new(lambda))?;
+    let log_likelihood = values.iter().map(|x| dist.ln_pmf(*x as u64)).sum();
+
+    fit_result(&[("lambda", lambda)], log_likelihood, values.len())
+}
@@ -0,0 +1,383 @@
+// Statistical hypothesis tests.
+//
+// Every test returns a dataframe with a single row and the columns
+// `statistic`, `p_value` and `df` (the degrees of freedom, which is nil
+// for tests that don't have them). All p-values are two-sided.
+//
+// Nils and NaNs are ignored in the samples.
+
+use crate::error::check_distribution;
+use crate::{ExDataFrame, ExSeries, ExplorerError};
+use polars::prelude::*;
+use statrs::distribution::{ChiSquared, ContinuousCDF, Normal, StudentsT};
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+fn test_result(
+    statistic: f64,
+    p_value: f64,
+    degrees_of_freedom: Option<f64>,
+) -> Result<ExDataFrame, ExplorerError> {
+    let df = df!(
+        "statistic" => [statistic],
+        "p_value" => [p_value],
+        "df" => [degrees_of_freedom]
+    )?;
+
+    Ok(ExDataFrame::new(df))
+}
+
+fn sample(s: &ExSeries) -> Result<Vec<f64>, ExplorerError> {
+    Ok(s.cast(&DataType::Float64)?
+        .f64()?
+        .into_iter()
+        .flatten()
+        .filter(|x| !x.is_nan())
+        .collect())
+}
+
+fn sorted_sample(s: &ExSeries) -> Result<Vec<f64>, ExplorerError> {
+    let mut values = sample(s)?;
+    values.sort_by(f64::total_cmp);
+    Ok(values)
+}
+
+// Returns the size, the mean and the (unbiased) variance of the sample.
+fn sample_moments(values: &[f64], name: &str) -> Result<(f64, f64, f64), ExplorerError> {
+    if values.len() < 2 {
+        return Err(ExplorerError::Other(format!(
+            "{name} needs at least two values in each sample"
+        )));
+    }
+
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+
+    Ok((n, mean, variance))
+}
+
+// Student's t-test assumes that both samples have the same variance,
+// while Welch's t-test doesn't (and is usually the safer choice).
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_t_test(
+    left: ExSeries,
+    right: ExSeries,
+    equal_variance: bool,
+) -> Result<ExDataFrame, ExplorerError> {
+    let (n1, mean1, var1) = sample_moments(&sample(&left)?, "t-test")?;
+    let (n2, mean2, var2) = sample_moments(&sample(&right)?, "t-test")?;
+
+    let (standard_error, degrees_of_freedom) = if equal_variance {
+        let pooled = ((n1 - 1.0) * var1 + (n2 - 1.0) * var2) / (n1 + n2 - 2.0);
+        ((pooled * (1.0 / n1 + 1.0 / n2)).sqrt(), n1 + n2 - 2.0)
+    } else {
+        let a = var1 / n1;
+        let b = var2 / n2;
+        let freedom = (a + b).powi(2) / (a.powi(2) / (n1 - 1.0) + b.powi(2) / (n2 - 1.0));
+        ((a + b).sqrt(), freedom)
+    };
+
+    if standard_error.is_nan() || standard_error == 0.0 {
+        return Err(ExplorerError::Other(
+            "t-test needs at least one sample with a non-zero variance".into(),
+        ));
+    }
+
+    let t = (mean1 - mean2) / standard_error;
+    let dist = check_distribution!(StudentsT::new(0.0, 1.0, degrees_of_freedom))?;
+    let p_value = 2.0 * dist.sf(t.abs());
+
+    test_result(t, p_value, Some(degrees_of_freedom))
+}
+
+// Uses the normal approximation, with a correction for ties and
+// a continuity correction. The statistic is the U of the left sample.
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_mann_whitney_u(left: ExSeries, right: ExSeries) -> Result<ExDataFrame, ExplorerError> {
+    let left = sample(&left)?;
+    let right = sample(&right)?;
+
+    if left.is_empty() || right.is_empty() {
+        return Err(ExplorerError::Other(
+            "Mann-Whitney U test needs at least one value in each sample".into(),
+        ));
+    }
+
+    let n1 = left.len() as f64;
+    let n2 = right.len() as f64;
+    let n = n1 + n2;
+
+    // Ranks of the combined samples (starting at 1), where ties get the
+    // average of their ranks. `true` marks the values of the left sample.
+    let mut combined: Vec<(f64, bool)> = left
+        .iter()
+        .map(|x| (*x, true))
+        .chain(right.iter().map(|x| (*x, false)))
+        .collect();
+    combined.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut left_rank_sum = 0.0;
+    let mut tie_correction = 0.0;
+    let mut start = 0;
+
+    while start < combined.len() {
+        let mut end = start;
+        while end < combined.len() && combined[end].0 == combined[start].0 {
+            end += 1;
+        }
+
+        let ties = (end - start) as f64;
+        let rank = (start + end + 1) as f64 / 2.0;
+        let left_ties = combined[start..end]
+            .iter()
+            .filter(|(_, is_left)| *is_left)
+            .count();
+
+        left_rank_sum += rank * left_ties as f64;
+        tie_correction += ties.powi(3) - ties;
+        start = end;
+    }
+
+    let u = left_rank_sum - n1 * (n1 + 1.0) / 2.0;
+    let mean = n1 * n2 / 2.0;
+    let std = (n1 * n2 / 12.0 * ((n + 1.0) - tie_correction / (n * (n - 1.0)))).sqrt();
+
+    let p_value = if std > 0.0 {
+        let z = ((u - mean).abs() - 0.5).max(0.0) / std;
+        (2.0 * Normal::standard().sf(z)).min(1.0)
+    } else {
+        1.0
+    };
+
+    test_result(u, p_value, None)
+}
+
+// The complement of the Kolmogorov distribution, `Q_KS(lambda)`,
+// as in "Numerical Recipes" (section 6.14).
+fn kolmogorov_sf(lambda: f64) -> f64 {
+    if lambda <= 0.0 {
+        1.0
+    } else if lambda < 1.18 {
+        let y = (-PI * PI / (8.0 * lambda * lambda)).exp();
+        let cdf = (2.0 * PI).sqrt() / lambda * (y + y.powi(9) + y.powi(25) + y.powi(49));
+        1.0 - cdf
+    } else {
+        let x = (-2.0 * lambda * lambda).exp();
+        2.0 * (x - x.powi(4) + x.powi(9))
+    }
+}
+
+// Asymptotic p-value of the statistic `d` for the effective sample size `n`.
+fn kolmogorov_p_value(d: f64, n: f64) -> f64 {
+    let sqrt_n = n.sqrt();
+    kolmogorov_sf((sqrt_n + 0.12 + 0.11 / sqrt_n) * d).clamp(0.0, 1.0)
+}
+
+// Instead of receiving the sample and a reference distribution, this
+// receives the theoretical CDF evaluated at each value of the sample,
+// which can be computed with any of the `s_*_cdf` functions.
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_ks_one_sample(cdf: ExSeries) -> Result<ExDataFrame, ExplorerError> {
+    let cdf = sorted_sample(&cdf)?;
+
+    if cdf.is_empty() {
+        return Err(ExplorerError::Other(
+            "Kolmogorov-Smirnov test needs at least one value".into(),
+        ));
+    }
+
+    let n = cdf.len() as f64;
+
+    let d = cdf
+        .iter()
+        .enumerate()
+        .map(|(i, u)| {
+            let above = (i + 1) as f64 / n - u;
+            let below = u - i as f64 / n;
+            above.max(below)
+        })
+        .fold(0.0, f64::max);
+
+    test_result(d, kolmogorov_p_value(d, n), None)
+}
+
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_ks_two_sample(left: ExSeries, right: ExSeries) -> Result<ExDataFrame, ExplorerError> {
+    let left = sorted_sample(&left)?;
+    let right = sorted_sample(&right)?;
+
+    if left.is_empty() || right.is_empty() {
+        return Err(ExplorerError::Other(
+            "Kolmogorov-Smirnov test needs at least one value in each sample".into(),
+        ));
+    }
+
+    let n1 = left.len() as f64;
+    let n2 = right.len() as f64;
+
+    // Walk both sorted samples, comparing the empirical CDFs after
+    // each distinct value.
+    let (mut i, mut j) = (0, 0);
+    let mut d: f64 = 0.0;
+
+    while i < left.len() && j < right.len() {
+        let x = left[i].min(right[j]);
+
+        while i < left.len() && left[i] <= x {
+            i += 1;
+        }
+        while j < right.len() && right[j] <= x {
+            j += 1;
+        }
+
+        d = d.max((i as f64 / n1 - j as f64 / n2).abs());
+    }
+
+    test_result(d, kolmogorov_p_value(d, n1 * n2 / (n1 + n2)), None)
+}
+
+fn chi_squared_p_value(statistic: f64, degrees_of_freedom: f64) -> Result<f64, ExplorerError> {
+    let dist = check_distribution!(ChiSquared::new(degrees_of_freedom))?;
+    Ok(dist.sf(statistic))
+}
+
+// The expected frequencies can be counts or probabilities, since they
+// are scaled to the total of the observed counts. When they are not
+// given, all categories are expected to be equally likely.
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn s_chi_squared_gof(
+    observed: ExSeries,
+    expected: Option<ExSeries>,
+) -> Result<ExDataFrame, ExplorerError> {
+    let observed = sample(&observed)?;
+    let k = observed.len();
+
+    if k < 2 {
+        return Err(ExplorerError::Other(
+            "chi-squared goodness-of-fit test needs at least two categories".into(),
+        ));
+    }
+
+    let expected = match expected {
+        Some(expected) => sample(&expected)?,
+        None => vec![1.0; k],
+    };
+
+    if expected.len() != k {
+        return Err(ExplorerError::Other(format!(
+            "chi-squared goodness-of-fit test expected {k} expected frequencies, got {}",
+            expected.len()
+        )));
+    }
+
+    if observed.iter().any(|o| *o < 0.0) {
+        return Err(ExplorerError::Other(
+            "chi-squared goodness-of-fit test needs non-negative observed frequencies".into(),
+        ));
+    }
+
+    if expected.iter().any(|e| *e <= 0.0 || e.is_infinite()) {
+        return Err(ExplorerError::Other(
+            "chi-squared goodness-of-fit test needs positive expected frequencies".into(),
+        ));
+    }
+
+    let total_observed: f64 = observed.iter().sum();
+    let total_expected: f64 = expected.iter().sum();
+
+    let statistic: f64 = observed
+        .iter()
+        .zip(expected.iter())
+        .map(|(o, e)| {
+            let e = e * total_observed / total_expected;
+            (o - e).powi(2) / e
+        })
+        .sum();
+
+    let degrees_of_freedom = (k - 1) as f64;
+    let p_value = chi_squared_p_value(statistic, degrees_of_freedom)?;
+
+    test_result(statistic, p_value, Some(degrees_of_freedom))
+}
+
+// Receives the contingency table in "long" format, with one row per
+// combination of the two variables, like the result of `s_frequencies`
+// on a struct series (after unnesting it) or of counting by two groups.
+// Missing combinations count as zero.
+#[rustler::nif(schedule = "DirtyCpu")]
+pub fn df_chi_squared_independence_test(
+    df: ExDataFrame,
+    row_column: &str,
+    col_column: &str,
+    counts_column: &str,
+) -> Result<ExDataFrame, ExplorerError> {
+    let rows = df.column(row_column)?.cast(&DataType::String)?;
+    let cols = df.column(col_column)?.cast(&DataType::String)?;
+    let counts = df.column(counts_column)?.cast(&DataType::Float64)?;
+
+    let mut row_index: HashMap<Option<&str>, usize> = HashMap::new();
+    let mut col_index: HashMap<Option<&str>, usize> = HashMap::new();
+    let mut cells: Vec<(usize, usize, f64)> = Vec::with_capacity(df.height());
+
+    for ((row, col), count) in rows.str()?.into_iter().zip(cols.str()?).zip(counts.f64()?) {
+        let next_row = row_index.len();
+        let next_col = col_index.len();
+        let i = *row_index.entry(row).or_insert(next_row);
+        let j = *col_index.entry(col).or_insert(next_col);
+
+        cells.push((i, j, count.unwrap_or(0.0)));
+    }
+
+    let (r, c) = (row_index.len(), col_index.len());
+
+    if r < 2 || c < 2 {
+        return Err(ExplorerError::Other(
+            "chi-squared independence test needs at least two values for each variable".into(),
+        ));
+    }
+
+    if cells
+        .iter()
+        .any(|(_, _, count)| *count < 0.0 || count.is_nan())
+    {
+        return Err(ExplorerError::Other(
+            "chi-squared independence test needs non-negative counts".into(),
+        ));
+    }
+
+    let mut table = vec![vec![0.0; c]; r];
+    for (i, j, count) in cells {
+        table[i][j] += count;
+    }
+
+    let row_totals: Vec<f64> = table.iter().map(|row| row.iter().sum()).collect();
+    let col_totals: Vec<f64> = (0..c)
+        .map(|j| table.iter().map(|row| row[j]).sum())
+        .collect();
+    let total: f64 = row_totals.iter().sum();
+
+    // An empty row or column has an expected count of zero in all of its cells.
+    if row_totals
+        .iter()
+        .chain(col_totals.iter())
+        .any(|t| *t == 0.0)
+    {
+        return Err(ExplorerError::Other(
+            "chi-squared independence test needs a non-zero count in every row and column".into(),
+        ));
+    }
+
+    let mut statistic = 0.0;
+    for (i, row) in table.iter().enumerate() {
+        for (j, observed) in row.iter().enumerate() {
+            let expected = row_totals[i] * col_totals[j] / total;
+            statistic += (observed - expected).powi(2) / expected;
+        }
+    }
+
+    let degrees_of_freedom = ((r - 1) * (c - 1)) as f64;
+    let p_value = chi_squared_p_value(statistic, degrees_of_freedom)?;
+
+    test_result(statistic, p_value, Some(degrees_of_freedom))
+}
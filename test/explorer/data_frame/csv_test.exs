@@ -1,6 +1,7 @@
 defmodule Explorer.DataFrame.CSVTest do
   use ExUnit.Case, async: true
   alias Explorer.DataFrame, as: DF
+  alias Explorer.PolarsBackend.Native
   import Explorer.IOHelpers
 
   @data """
@@ -285,6 +286,12 @@ defmodule Explorer.DataFrame.CSVTest do
   end
 
   describe "from_csv/2 options" do
+    test "rejects :hive_partitioning" do
+      assert_raise ArgumentError, ~r/the :hive_partitioning option is not supported/, fn ->
+        DF.from_csv("data/*.csv", hive_partitioning: true, lazy: true)
+      end
+    end
+
     @default_infer_schema_length 1_000
 
     @tag :tmp_dir
@@ -788,6 +795,114 @@ defmodule Explorer.DataFrame.CSVTest do
                a: [0.1, :nan, 4.2, :infinity, :neg_infinity, 8.1]
              }
     end
+
+    @tag :tmp_dir
+    test "quote_char", config do
+      csv =
+        tmp_csv(config.tmp_dir, """
+        a,b
+        'x,y',1
+        'z',2
+        """)
+
+      df = DF.from_csv!(csv, quote_char: "'")
+
+      assert DF.to_columns(df, atom_keys: true) == %{
+               a: ["x,y", "z"],
+               b: [1, 2]
+             }
+
+      df = DF.from_csv!(csv, quote_char: nil, delimiter: "|")
+
+      assert DF.to_columns(df) == %{"a,b" => ["'x,y',1", "'z',2"]}
+    end
+
+    @tag :tmp_dir
+    test "comment_prefix", config do
+      csv =
+        tmp_csv(config.tmp_dir, """
+        a,b
+        # this is a comment
+        1,2
+        3,4
+        """)
+
+      df = DF.from_csv!(csv, comment_prefix: "#")
+
+      assert DF.to_columns(df, atom_keys: true) == %{
+               a: [1, 3],
+               b: [2, 4]
+             }
+    end
+
+    @tag :tmp_dir
+    test "decimal_comma", config do
+      csv =
+        tmp_csv(config.tmp_dir, """
+        a;b
+        1,5;2
+        3,25;4
+        """)
+
+      df = DF.from_csv!(csv, delimiter: ";", decimal_comma: true)
+
+      assert DF.to_columns(df, atom_keys: true) == %{
+               a: [1.5, 3.25],
+               b: [2, 4]
+             }
+    end
+
+    @tag :tmp_dir
+    test "missing_is_null", config do
+      csv =
+        tmp_csv(config.tmp_dir, """
+        a,b
+        1,
+        3,x
+        """)
+
+      df = DF.from_csv!(csv, dtypes: %{b: :string})
+      assert DF.to_columns(df, atom_keys: true) == %{a: [1, 3], b: [nil, "x"]}
+
+      df = DF.from_csv!(csv, dtypes: %{b: :string}, missing_is_null: false)
+      assert DF.to_columns(df, atom_keys: true) == %{a: [1, 3], b: ["", "x"]}
+    end
+
+    @tag :tmp_dir
+    test "truncate_ragged_lines", config do
+      csv =
+        tmp_csv(config.tmp_dir, """
+        a,b
+        1,2,3
+        4,5
+        """)
+
+      assert {:error, %RuntimeError{}} = DF.from_csv(csv)
+
+      df = DF.from_csv!(csv, truncate_ragged_lines: true)
+
+      assert DF.to_columns(df, atom_keys: true) == %{
+               a: [1, 4],
+               b: [2, 5]
+             }
+    end
+
+    test "dialect options with load_csv/2" do
+      df =
+        DF.load_csv!(
+          """
+          a;b
+          # skipped
+          '1,5';x
+          """,
+          delimiter: ";",
+          quote_char: "'",
+          comment_prefix: "#",
+          decimal_comma: true
+        )
+
+      assert DF.to_columns(df, atom_keys: true) == %{a: [1.5], b: ["x"]}
+    end
   end
 
   describe "to_csv/3" do
@@ -806,6 +921,90 @@ defmodule Explorer.DataFrame.CSVTest do
       assert DF.dtypes(df) == DF.dtypes(csv_df)
       assert DF.to_columns(df) == DF.to_columns(csv_df)
     end
+
+    @tag :tmp_dir
+    test "can write a compressed CSV to file", %{df: df, tmp_dir: tmp_dir} do
+      csv_path = Path.join(tmp_dir, "test.csv.gz")
+
+      assert :ok = DF.to_csv(df, csv_path, compression: {:gzip, 6})
+      assert <<0x1F, 0x8B, _::binary>> = File.read!(csv_path)
+
+      assert {:ok, csv_df} = DF.from_csv(csv_path)
+      assert DF.to_columns(df) == DF.to_columns(csv_df)
+    end
+
+    @tag :tmp_dir
+    test "can write a CSV with formatting options", %{tmp_dir: tmp_dir} do
+      csv_path = Path.join(tmp_dir, "test.csv")
+
+      df =
+        DF.new(
+          a: [1.5, nil],
+          dt: [~N[2024-01-31 10:20:30], ~N[2024-02-01 00:00:00]],
+          t: [~T[10:20:30], ~T[23:59:00]]
+        )
+
+      assert :ok =
+               DF.to_csv(df, csv_path,
+                 float_precision: 3,
+                 nil_value: "NA",
+                 datetime_format: "%Y-%m-%dT%H:%M",
+                 time_format: "%H:%M"
+               )
+
+      assert File.read!(csv_path) ==
+               "a,dt,t\n1.500,2024-01-31T10:20,10:20\nNA,2024-02-01T00:00,23:59\n"
+    end
+  end
+
+  describe "dump_csv/2" do
+    test "with formatting options" do
+      df = DF.new(a: [1.5, nil], d: [~D[2024-01-31], ~D[2024-02-01]])
+
+      assert DF.dump_csv!(df,
+               float_precision: 2,
+               nil_value: "NULL",
+               date_format: "%d/%m/%Y",
+               line_terminator: "\r\n"
+             ) == "a,d\r\n1.50,31/01/2024\r\nNULL,01/02/2024\r\n"
+    end
+
+    test "with quote styles" do
+      df = DF.new(a: [1, 2], b: ["x", "y,z"])
+
+      assert DF.dump_csv!(df) == "a,b\n1,x\n2,\"y,z\"\n"
+
+      assert DF.dump_csv!(df, quote_style: :always) ==
+               "\"a\",\"b\"\n\"1\",\"x\"\n\"2\",\"y,z\"\n"
+
+      assert DF.dump_csv!(df, quote_style: :non_numeric) ==
+               "\"a\",\"b\"\n1,\"x\"\n2,\"y,z\"\n"
+
+      assert DF.dump_csv!(df, quote_style: :never) == "a,b\n1,x\n2,y,z\n"
+    end
+
+    test "with compression" do
+      df = DF.new(a: [1, 2], b: ["x", "y"])
+
+      assert {:ok, gzipped} = DF.dump_csv(df, compression: :gzip)
+      assert :zlib.gunzip(gzipped) == "a,b\n1,x\n2,y\n"
+
+      assert {:ok, zstd} = DF.dump_csv(df, compression: {:zstd, 3})
+      assert <<0x28, 0xB5, 0x2F, 0xFD, _::binary>> = zstd
+      assert DF.to_columns(DF.load_csv!(zstd)) == DF.to_columns(df)
+
+      assert_raise ArgumentError, "unsupported :compression :lz4 for CSV", fn ->
+        DF.dump_csv(df, compression: :lz4)
+      end
+    end
+
+    test "raises on invalid quote style" do
+      df = DF.new(a: [1, 2])
+
+      assert_raise ArgumentError,
+                   "expected :quote_style to be one of :necessary, :always, :non_numeric or :never, got: :sometimes",
+                   fn -> DF.dump_csv(df, quote_style: :sometimes) end
+    end
   end
 
   describe "cloud reads and writes" do
@@ -830,6 +1029,21 @@ defmodule Explorer.DataFrame.CSVTest do
       assert DF.to_columns(saved_df) == DF.to_columns(Explorer.Datasets.wine())
     end
 
+    @tag :cloud_integration
+    test "reads a subset of the columns and rows of a CSV file from S3", %{
+      df: df,
+      s3_config: config
+    } do
+      path = "s3://test-bucket/test-writes/wine-#{System.monotonic_time()}.csv"
+      assert :ok = DF.to_csv(df, path, config: config)
+
+      assert {:ok, csv_df} =
+               DF.from_csv(path, config: config, columns: ["class", "malic_acid"], max_rows: 10)
+
+      expected = df |> DF.head(10) |> DF.select(["class", "malic_acid"])
+      assert DF.to_columns(csv_df) == DF.to_columns(expected)
+    end
+
     @tag :cloud_integration
     test "returns an error in case file is not found in S3 bucket", %{s3_config: s3_config} do
       path = "s3://test-bucket/test-writes/file-does-not-exist.csv"
@@ -915,5 +1129,186 @@ defmodule Explorer.DataFrame.CSVTest do
     end
   end
 
+  describe "stream_csv/2" do
+    @tag :tmp_dir
+    test "reads the file in chunks of rows", %{tmp_dir: tmp_dir} do
+      path = Path.join(tmp_dir, "iris.csv")
+      :ok = DF.to_csv!(Explorer.Datasets.iris(), path)
+
+      chunks = path |> DF.stream_csv(chunk_size: 40) |> Enum.to_list()
+
+      assert Enum.map(chunks, &DF.n_rows/1) == [40, 40, 40, 30]
+      assert DF.to_columns(DF.concat_rows(chunks)) == DF.to_columns(DF.from_csv!(path))
+    end
+
+    @tag :tmp_dir
+    test "reads with options", %{tmp_dir: tmp_dir} do
+      csv =
+        tmp_csv(tmp_dir, """
+        a;b;c
+        1;x;1.5
+        2;y;2.5
+        3;z;NA
+        """)
+
+      chunks =
+        csv
+        |> DF.stream_csv(
+          chunk_size: 2,
+          delimiter: ";",
+          columns: ["a", "c"],
+          dtypes: [a: {:s, 32}],
+          nil_values: ["NA"]
+        )
+        |> Enum.to_list()
+
+      assert Enum.map(chunks, &DF.to_columns(&1, atom_keys: true)) == [
+               %{a: [1, 2], c: [1.5, 2.5]},
+               %{a: [3], c: [nil]}
+             ]
+
+      assert hd(chunks).dtypes == %{"a" => {:s, 32}, "c" => {:f, 64}}
+    end
+
+    @tag :tmp_dir
+    test "reads the file again when enumerated again", %{tmp_dir: tmp_dir} do
+      csv = tmp_csv(tmp_dir, "a\n1\n2\n3\n")
+      stream = DF.stream_csv(csv, chunk_size: 2)
+
+      assert Enum.map(stream, &DF.n_rows/1) == [2, 1]
+      assert Enum.map(stream, &DF.n_rows/1) == [2, 1]
+    end
+
+    test "raises for files that cannot be read" do
+      assert_raise RuntimeError, fn ->
+        "idontexist.csv" |> DF.stream_csv() |> Enum.to_list()
+      end
+    end
+
+    @tag :tmp_dir
+    test "raises for compressed files", %{tmp_dir: tmp_dir} do
+      path = Path.join(tmp_dir, "test.csv.gz")
+      File.write!(path, :zlib.gzip("a\n1\n2\n"))
+
+      assert_raise RuntimeError,
+                   ~r/compressed input is not supported by the streaming readers/,
+                   fn -> path |> DF.stream_csv() |> Enum.to_list() end
+    end
+
+    test "raises for remote files" do
+      assert_raise ArgumentError,
+                   ~r/stream_csv only reads local files, got: %FSS.HTTP.Entry{/,
+                   fn -> DF.stream_csv("http://localhost:9899/file.csv") end
+    end
+  end
+
+  describe "write_batches/4" do
+    @tag :tmp_dir
+    test "writes the dataframes to a single file", %{tmp_dir: tmp_dir} do
+      df = Explorer.Datasets.iris()
+      path = Path.join(tmp_dir, "iris.csv")
+      chunks = Stream.map(0..149//40, &DF.slice(df, &1, 40))
+
+      assert :ok = DF.write_batches(chunks, path, :csv, delimiter: ";")
+      assert DF.to_columns(DF.from_csv!(path, delimiter: ";")) == DF.to_columns(df)
+    end
+
+    @tag :tmp_dir
+    test "writes a compressed file", %{tmp_dir: tmp_dir} do
+      df = Explorer.Datasets.iris()
+      path = Path.join(tmp_dir, "iris.csv.gz")
+      chunks = Stream.map(0..149//40, &DF.slice(df, &1, 40))
+
+      assert :ok = DF.write_batches(chunks, path, :csv, compression: :gzip)
+      assert <<0x1F, 0x8B, _::binary>> = File.read!(path)
+      assert DF.to_columns(DF.from_csv!(path)) == DF.to_columns(df)
+    end
+
+    @tag :tmp_dir
+    test "returns an error and removes the file when the dtypes do not match", %{
+      tmp_dir: tmp_dir
+    } do
+      path = Path.join(tmp_dir, "test.csv")
+      chunks = [DF.new(a: [1, 2]), DF.new(a: ["x", "y"])]
+
+      assert {:error, %RuntimeError{} = error} = DF.write_batches(chunks, path, :csv)
+
+      assert Exception.message(error) =~
+               "the dataframe does not have the same columns and dtypes as the first batch"
+
+      refute File.exists?(path)
+    end
+
+    @tag :tmp_dir
+    test "removes the file when the enumerable raises", %{tmp_dir: tmp_dir} do
+      df = Explorer.Datasets.iris()
+      path = Path.join(tmp_dir, "iris.csv")
+
+      chunks =
+        Stream.map([df, :error], fn
+          :error -> raise "cannot build the batch"
+          df -> df
+        end)
+
+      assert_raise RuntimeError, "cannot build the batch", fn ->
+        DF.write_batches(chunks, path, :csv)
+      end
+
+      refute File.exists?(path)
+    end
+
+    @tag :tmp_dir
+    test "removes the file when the writer is dropped without being closed", %{
+      tmp_dir: tmp_dir
+    } do
+      path = Path.join(tmp_dir, "iris.csv")
+      entry = {:local, %FSS.Local.Entry{path: path}}
+
+      # The writer is released once the process that holds it exits,
+      # which may happen shortly after the process is reported down.
+      {pid, ref} =
+        spawn_monitor(fn ->
+          {:ok, writer} = Native.csv_writer_new(entry, true, ?,, [], nil)
+          {:ok, _} = Native.writer_write_batch(writer, Explorer.Datasets.iris().data)
+          true = File.exists?(path)
+        end)
+
+      assert_receive {:DOWN, ^ref, :process, ^pid, :normal}
+      assert Enum.any?(1..50, fn _ -> removed?(path) end)
+    end
+
+    test "returns an error when the upload fails to complete" do
+      bypass = Bypass.open()
+
+      Bypass.expect(bypass, "PUT", "/test-bucket/iris.csv", fn conn ->
+        Plug.Conn.resp(conn, 403, "")
+      end)
+
+      config = %FSS.S3.Config{
+        access_key_id: "test",
+        secret_access_key: "test",
+        endpoint: http_endpoint(bypass) <> "/test-bucket",
+        bucket: nil,
+        region: "us-east-1"
+      }
+
+      entry = %FSS.S3.Entry{key: "iris.csv", config: config}
+      df = Explorer.Datasets.iris()
+
+      assert {:error, %RuntimeError{}} = DF.write_batches([df], entry, :csv)
+    end
+
+    test "raises with an unknown format" do
+      assert_raise ArgumentError, ~r/expected format to be one of/, fn ->
+        DF.write_batches([], "iris.xlsx", :xlsx)
+      end
+    end
+  end
+
   defp http_endpoint(bypass), do: "http://localhost:#{bypass.port}"
+
+  defp removed?(path) do
+    File.exists?(path) && Process.sleep(10)
+    not File.exists?(path)
+  end
 end
@@ -535,6 +535,36 @@ defmodule Explorer.DataFrame.GroupedTest do
       assert df2.dtypes == %{"a" => {:s, 64}, "b" => :string, "c" => {:s, 64}, "d" => {:f, 64}}
       assert df2.groups == ["c"]
     end
+
+    test "adds random draws from a different stream for each group" do
+      df = DF.new(a: [1, 2, 3, 4, 5, 6], c: [1, 1, 1, 2, 2, 2])
+
+      df1 = DF.group_by(df, :c)
+      df2 = DF.mutate(df1, d: draw(a, :uniform, [min: 0.0, max: 1.0], seed: 100))
+      df3 = DF.mutate(df1, d: draw(a, :uniform, [min: 0.0, max: 1.0], seed: 100))
+
+      assert df2.dtypes["d"] == {:f, 64}
+      assert DF.to_columns(df2) == DF.to_columns(df3)
+
+      [group_1, group_2] = df2["d"] |> Series.to_list() |> Enum.chunk_every(3)
+      refute group_1 == group_2
+    end
+
+    test "adds the same random draws to a group wherever it is" do
+      df1 = DF.new(a: [1, 2, 3, 4], c: [1, 1, 2, 2])
+      df2 = DF.new(a: [3, 4, 1, 2], c: [2, 2, 1, 1])
+
+      [df1, df2] =
+        for df <- [df1, df2] do
+          df
+          |> DF.group_by(:c)
+          |> DF.mutate(d: draw(a, :uniform, [min: 0.0, max: 1.0], seed: 100))
+          |> DF.ungroup()
+          |> DF.sort_by(a)
+        end
+
+      assert DF.to_columns(df1) == DF.to_columns(df2)
+    end
   end
 
   describe "mutate_with/2" do
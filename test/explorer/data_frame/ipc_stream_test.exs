@@ -225,5 +225,47 @@ defmodule Explorer.DataFrame.IPCStreamTest do
     end
   end
 
+  describe "stream_ipc_stream/2" do
+    test "reads the file in chunks of rows" do
+      df = Explorer.Datasets.iris()
+      ipc = tmp_ipc_stream_file!(df)
+
+      chunks = ipc |> DF.stream_ipc_stream(chunk_size: 40) |> Enum.to_list()
+
+      assert Enum.map(chunks, &DF.n_rows/1) == [40, 40, 40, 30]
+      assert DF.to_columns(DF.concat_rows(chunks)) == DF.to_columns(df)
+    end
+
+    test "reads the given columns" do
+      df = Explorer.Datasets.iris()
+      ipc = tmp_ipc_stream_file!(df)
+
+      chunks = ipc |> DF.stream_ipc_stream(columns: ["species"]) |> Enum.to_list()
+
+      assert Enum.map(chunks, &DF.names/1) == [["species"]]
+      assert DF.to_columns(hd(chunks)) == DF.to_columns(DF.select(df, ["species"]))
+    end
+
+    test "raises for columns that are not in the file" do
+      ipc = tmp_ipc_stream_file!(Explorer.Datasets.iris())
+
+      assert_raise RuntimeError, ~r/column "unknown" not found/, fn ->
+        ipc |> DF.stream_ipc_stream(columns: ["unknown"]) |> Enum.to_list()
+      end
+    end
+  end
+
+  describe "write_batches/4" do
+    @tag :tmp_dir
+    test "writes the dataframes to a single file", %{tmp_dir: tmp_dir} do
+      df = Explorer.Datasets.iris()
+      path = Path.join(tmp_dir, "iris.ipcstream")
+      chunks = Stream.map(0..149//40, &DF.slice(df, &1, 40))
+
+      assert :ok = DF.write_batches(chunks, path, :ipc_stream, compression: :lz4)
+      assert DF.to_columns(DF.from_ipc_stream!(path)) == DF.to_columns(df)
+    end
+  end
+
   defp http_endpoint(bypass), do: "http://localhost:#{bypass.port}"
 end
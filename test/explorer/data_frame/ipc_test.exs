@@ -146,6 +146,33 @@ defmodule Explorer.DataFrame.IPCTest do
       assert DF.dtypes(df) == DF.dtypes(ipc_df)
       assert DF.to_columns(df) == DF.to_columns(ipc_df)
     end
+
+    @tag :tmp_dir
+    test "writes one file per partition", %{df: df, tmp_dir: tmp_dir} do
+      assert :ok = DF.to_ipc(df, tmp_dir, partition_by: ["class"], compression: :zstd)
+
+      for class <- [1, 2, 3] do
+        path = Path.join([tmp_dir, "class=#{class}", "part-0.ipc"])
+        expected =
+          df
+          |> DF.filter_with(&Explorer.Series.equal(&1["class"], class))
+          |> DF.discard("class")
+
+        assert DF.to_columns(DF.from_ipc!(path)) == DF.to_columns(expected)
+      end
+    end
+  end
+
+  describe "write_batches/4" do
+    @tag :tmp_dir
+    test "writes the dataframes to a single file", %{tmp_dir: tmp_dir} do
+      df = Explorer.Datasets.iris()
+      path = Path.join(tmp_dir, "iris.ipc")
+      chunks = Stream.map(0..149//40, &DF.slice(df, &1, 40))
+
+      assert :ok = DF.write_batches(chunks, path, :ipc, compression: :zstd)
+      assert DF.to_columns(DF.from_ipc!(path)) == DF.to_columns(df)
+    end
   end
 
   describe "cloud reads and writes" do
@@ -170,6 +197,20 @@ defmodule Explorer.DataFrame.IPCTest do
       assert DF.to_columns(saved_df) == DF.to_columns(Explorer.Datasets.wine())
     end
 
+    @tag :cloud_integration
+    test "reads a subset of the columns of an IPC file from S3", %{
+      df: df,
+      s3_config: s3_config
+    } do
+      path = "s3://test-bucket/test-writes/wine-#{System.monotonic_time()}.ipc"
+      assert :ok = DF.to_ipc(df, path, config: s3_config)
+
+      assert {:ok, ipc_df} = DF.from_ipc(path, config: s3_config, columns: [0, 2])
+
+      assert DF.names(ipc_df) == ["class", "malic_acid"]
+      assert DF.to_columns(ipc_df) == DF.to_columns(DF.select(df, ["class", "malic_acid"]))
+    end
+
     @tag :cloud_integration
     test "returns an error in case file is not found in S3 bucket", %{s3_config: s3_config} do
       path = "s3://test-bucket/test-writes/file-does-not-exist.ipc"
@@ -202,10 +243,7 @@ defmodule Explorer.DataFrame.IPCTest do
     end
 
     test "reads a IPC file from an HTTP server", %{bypass: bypass, df: df} do
-      Bypass.expect(bypass, "GET", "/path/to/file.ipc", fn conn ->
-        bytes = Explorer.DataFrame.dump_ipc!(df)
-        Plug.Conn.resp(conn, 200, bytes)
-      end)
+      serve_object(bypass, "/path/to/file.ipc", DF.dump_ipc!(df))
 
       url = http_endpoint(bypass) <> "/path/to/file.ipc"
 
@@ -215,10 +253,8 @@ defmodule Explorer.DataFrame.IPCTest do
     end
 
     test "reads a IPC file from an HTTP server using headers", %{bypass: bypass, df: df} do
-      Bypass.expect(bypass, "GET", "/path/to/file.ipc", fn conn ->
+      serve_object(bypass, "/path/to/file.ipc", DF.dump_ipc!(df), fn conn ->
         assert ["Bearer my-token"] = Plug.Conn.get_req_header(conn, "authorization")
-        bytes = Explorer.DataFrame.dump_ipc!(df)
-        Plug.Conn.resp(conn, 200, bytes)
       end)
 
       url = http_endpoint(bypass) <> "/path/to/file.ipc"
@@ -232,7 +268,7 @@ defmodule Explorer.DataFrame.IPCTest do
     end
 
     test "cannot find a IPC file", %{bypass: bypass} do
-      Bypass.expect(bypass, "GET", "/path/to/file.ipc", fn conn ->
+      Bypass.expect(bypass, "HEAD", "/path/to/file.ipc", fn conn ->
         Plug.Conn.resp(conn, 404, "not found")
       end)
 
@@ -240,6 +276,27 @@ defmodule Explorer.DataFrame.IPCTest do
 
       assert {:error, %ArgumentError{message: "resource not found (404)"}} = DF.from_ipc(url)
     end
+
+    test "scans a IPC file lazily", %{bypass: bypass, df: df} do
+      serve_object(bypass, "/path/to/file.ipc", DF.dump_ipc!(df))
+
+      url = http_endpoint(bypass) <> "/path/to/file.ipc"
+
+      assert {:ok, ldf} = DF.from_ipc(url, lazy: true)
+      assert DF.to_columns(DF.compute(ldf)) == DF.to_columns(df)
+    end
+
+    test "scans a IPC file lazily using headers", %{bypass: bypass, df: df} do
+      serve_object(bypass, "/path/to/file.ipc", DF.dump_ipc!(df), fn conn ->
+        assert ["Bearer my-token"] = Plug.Conn.get_req_header(conn, "authorization")
+      end)
+
+      url = http_endpoint(bypass) <> "/path/to/file.ipc"
+      config = [headers: [{"authorization", "Bearer my-token"}]]
+
+      assert {:ok, ldf} = DF.from_ipc(url, config: config, lazy: true)
+      assert DF.to_columns(DF.compute(ldf)) == DF.to_columns(df)
+    end
   end
 
   defp http_endpoint(bypass), do: "http://localhost:#{bypass.port}"
@@ -173,6 +173,29 @@ defmodule Explorer.DataFrame.LazyTest do
              )
   end
 
+  @tag :tmp_dir
+  test "from_csv/2 - with dialect options", %{tmp_dir: tmp_dir} do
+    path = Path.join([tmp_dir, "dialect.csv"])
+
+    File.write!(path, """
+    a;b
+    # skipped
+    '1,5';x;extra
+    """)
+
+    ldf =
+      DF.from_csv!(path,
+        lazy: true,
+        delimiter: ";",
+        quote_char: "'",
+        comment_prefix: "#",
+        decimal_comma: true,
+        truncate_ragged_lines: true
+      )
+
+    assert DF.to_columns(DF.compute(ldf), atom_keys: true) == %{a: [1.5], b: ["x"]}
+  end
+
   @tag :tmp_dir
   test "from_parquet/2 - with defaults", %{df: df, tmp_dir: tmp_dir} do
     path = Path.join([tmp_dir, "fossil_fuels.parquet"])
@@ -212,6 +235,54 @@ defmodule Explorer.DataFrame.LazyTest do
       assert DF.n_columns(df1) == 3
       assert DF.names(df1) == ["country", "year", "total"]
     end
+
+    @tag :tmp_dir
+    test "hive_partitioning", %{df: df, tmp_dir: tmp_dir} do
+      DF.to_parquet!(df, tmp_dir, partition_by: ["year"])
+
+      ldf = DF.from_parquet!(tmp_dir, lazy: true)
+      assert DF.dtypes(ldf)["year"] == {:s, 64}
+
+      df1 =
+        ldf
+        |> DF.filter_with(&Series.equal(&1["year"], 2011))
+        |> DF.select(DF.names(df))
+        |> DF.compute()
+
+      assert DF.to_rows(df1) == DF.to_rows(DF.filter_with(df, &Series.equal(&1["year"], 2011)))
+
+      ldf = DF.from_parquet!(tmp_dir, lazy: true, hive_partitioning: false)
+      refute "year" in DF.names(ldf)
+    end
+
+    @tag :tmp_dir
+    test "glob with include_file_paths", %{df: df, tmp_dir: tmp_dir} do
+      DF.to_parquet!(DF.slice(df, 0, 10), Path.join(tmp_dir, "a.parquet"))
+      DF.to_parquet!(DF.slice(df, 10, 5), Path.join(tmp_dir, "b.parquet"))
+
+      df1 =
+        tmp_dir
+        |> Path.join("*.parquet")
+        |> DF.from_parquet!(lazy: true, include_file_paths: "path")
+        |> DF.compute()
+
+      paths = df1["path"] |> Series.to_list() |> Enum.map(&Path.basename/1)
+
+      assert paths == List.duplicate("a.parquet", 10) ++ List.duplicate("b.parquet", 5)
+      assert DF.to_rows(DF.discard(df1, "path")) == DF.to_rows(DF.slice(df, 0, 15))
+    end
+
+    @tag :tmp_dir
+    test "hive options are not supported by eager dataframes", %{df: df, tmp_dir: tmp_dir} do
+      path = Path.join([tmp_dir, "fossil_fuels.parquet"])
+      DF.to_parquet!(df, path)
+
+      assert {:error, %ArgumentError{} = error} =
+               DF.from_parquet(path, include_file_paths: "path")
+
+      assert Exception.message(error) ==
+               ":hive_partitioning and :include_file_paths are only supported by lazy dataframes"
+    end
   end
 
   describe "from_parquet/2 - from S3" do
@@ -291,6 +362,28 @@ defmodule Explorer.DataFrame.LazyTest do
     assert DF.to_rows(df1) |> Enum.sort() == DF.to_rows(df) |> Enum.sort()
   end
 
+  @tag :tmp_dir
+  test "to_csv/3 - with formatting options", %{tmp_dir: tmp_dir} do
+    ldf =
+      DF.new([a: [1.5, nil], b: ["x", "y,z"], d: [~D[2024-01-31], ~D[2024-02-01]]], lazy: true)
+
+    opts = [
+      float_precision: 2,
+      nil_value: "NULL",
+      date_format: "%d/%m/%Y",
+      line_terminator: "\r\n",
+      quote_style: :never
+    ]
+
+    for streaming <- [true, false] do
+      path = Path.join([tmp_dir, "formatted-#{streaming}.csv"])
+      assert :ok = DF.to_csv(ldf, path, [streaming: streaming] ++ opts)
+
+      assert File.read!(path) ==
+               "a,b,d\r\n1.50,x,31/01/2024\r\nNULL,y,z,01/02/2024\r\n"
+    end
+  end
+
   @tag :cloud_integration
   test "to_csv/3 - cloud with streaming enabled - ignores streaming option", %{ldf: ldf} do
     config = %FSS.S3.Config{
@@ -377,6 +470,219 @@ defmodule Explorer.DataFrame.LazyTest do
     assert DF.to_columns(df) == DF.to_columns(saved_df)
   end
 
+  @tag :tmp_dir
+  test "to_ipc_stream/2 - with defaults", %{ldf: ldf, tmp_dir: tmp_dir} do
+    path = Path.join([tmp_dir, "fossil_fuels.ipcstream"])
+
+    ldf = DF.head(ldf, 15)
+    DF.to_ipc_stream!(ldf, path)
+
+    df = DF.compute(ldf)
+    df1 = DF.from_ipc_stream!(path)
+
+    assert DF.to_rows(df1) == DF.to_rows(df)
+  end
+
+  @tag :tmp_dir
+  test "to_ipc_stream/3 - with compression", %{ldf: ldf, tmp_dir: tmp_dir} do
+    path = Path.join([tmp_dir, "fossil_fuels.ipcstream"])
+
+    ldf = DF.head(ldf, 15)
+    DF.to_ipc_stream!(ldf, path, compression: :zstd)
+
+    df = DF.compute(ldf)
+    df1 = DF.from_ipc_stream!(path)
+
+    assert DF.to_rows(df1) == DF.to_rows(df)
+  end
+
+  @tag :tmp_dir
+  test "to_ipc/3 and to_ipc_stream/3 - with an unknown compression", %{
+    ldf: ldf,
+    tmp_dir: tmp_dir
+  } do
+    entry = %FSS.Local.Entry{path: Path.join([tmp_dir, "fossil_fuels.ipc"])}
+
+    for streaming <- [true, false] do
+      assert {:error, error} =
+               Explorer.PolarsBackend.LazyFrame.to_ipc(ldf, entry, {:snappy, nil}, streaming)
+
+      assert Exception.message(error) =~
+               "the algorithm snappy is not supported for IPC compression"
+
+      assert {:error, error} =
+               Explorer.PolarsBackend.LazyFrame.to_ipc_stream(
+                 ldf,
+                 entry,
+                 {:snappy, nil},
+                 streaming
+               )
+
+      assert Exception.message(error) =~
+               "the algorithm snappy is not supported for IPC compression"
+    end
+  end
+
+  @tag :tmp_dir
+  test "to_ipc_stream/3 - without streaming", %{ldf: ldf, tmp_dir: tmp_dir} do
+    path = Path.join([tmp_dir, "fossil_fuels.ipcstream"])
+
+    ldf = DF.head(ldf, 15)
+    DF.to_ipc_stream!(ldf, path, streaming: false)
+
+    df = DF.compute(ldf)
+    df1 = DF.from_ipc_stream!(path)
+
+    assert DF.to_rows(df1) == DF.to_rows(df)
+  end
+
+  @tag :cloud_integration
+  test "to_ipc_stream/3 - cloud with streaming enabled", %{ldf: ldf} do
+    config = %FSS.S3.Config{
+      access_key_id: "test",
+      secret_access_key: "test",
+      endpoint: "http://localhost:4566",
+      region: "us-east-1"
+    }
+
+    path = "s3://test-bucket/test-lazy-writes/wine-#{System.monotonic_time()}.ipcstream"
+
+    ldf = DF.head(ldf, 15)
+    assert :ok = DF.to_ipc_stream(ldf, path, streaming: true, config: config)
+
+    df = DF.compute(ldf)
+    df1 = DF.from_ipc_stream!(path, config: config)
+
+    assert DF.to_rows(df1) == DF.to_rows(df)
+  end
+
+  @tag :cloud_integration
+  test "to_ipc_stream/3 - cloud with streaming disabled", %{ldf: ldf} do
+    config = %FSS.S3.Config{
+      access_key_id: "test",
+      secret_access_key: "test",
+      endpoint: "http://localhost:4566",
+      region: "us-east-1"
+    }
+
+    path = "s3://test-bucket/test-lazy-writes/wine-#{System.monotonic_time()}.ipcstream"
+
+    ldf = DF.head(ldf, 15)
+    assert :ok = DF.to_ipc_stream(ldf, path, streaming: false, config: config)
+
+    df = DF.compute(ldf)
+    df1 = DF.from_ipc_stream!(path, config: config)
+
+    assert DF.to_rows(df1) == DF.to_rows(df)
+  end
+
+  @tag :tmp_dir
+  test "to_ndjson/2 - with defaults", %{ldf: ldf, tmp_dir: tmp_dir} do
+    path = Path.join([tmp_dir, "fossil_fuels.ndjson"])
+
+    ldf = DF.head(ldf, 15)
+    DF.to_ndjson!(ldf, path)
+
+    df = DF.compute(ldf)
+    df1 = DF.from_ndjson!(path)
+
+    assert DF.to_rows(df1) == DF.to_rows(df)
+  end
+
+  @tag :tmp_dir
+  test "to_ndjson/3 - without streaming", %{ldf: ldf, tmp_dir: tmp_dir} do
+    path = Path.join([tmp_dir, "fossil_fuels.ndjson"])
+
+    ldf = DF.head(ldf, 15)
+    DF.to_ndjson!(ldf, path, streaming: false)
+
+    df = DF.compute(ldf)
+    df1 = DF.from_ndjson!(path)
+
+    assert DF.to_rows(df1) == DF.to_rows(df)
+  end
+
+  @tag :tmp_dir
+  test "to_ndjson/3 - with compression", %{ldf: ldf, tmp_dir: tmp_dir} do
+    ldf = DF.head(ldf, 15)
+    expected = DF.dump_ndjson!(DF.compute(ldf))
+
+    for streaming <- [true, false] do
+      path = Path.join([tmp_dir, "fossil_fuels-#{streaming}.ndjson.gz"])
+      DF.to_ndjson!(ldf, path, compression: :gzip, streaming: streaming)
+
+      assert path |> File.read!() |> :zlib.gunzip() == expected
+    end
+  end
+
+  @tag :tmp_dir
+  test "to_csv/3 - with compression", %{ldf: ldf, tmp_dir: tmp_dir} do
+    ldf = DF.head(ldf, 15)
+    df = DF.compute(ldf)
+
+    for streaming <- [true, false] do
+      path = Path.join([tmp_dir, "fossil_fuels-#{streaming}.csv.zst"])
+      DF.to_csv!(ldf, path, compression: {:zstd, 5}, streaming: streaming)
+
+      assert <<0x28, 0xB5, 0x2F, 0xFD, _::binary>> = File.read!(path)
+      assert DF.to_rows(DF.from_csv!(path)) == DF.to_rows(df)
+    end
+  end
+
+  @tag :tmp_dir
+  test "from_csv/2 and from_ndjson/2 - compressed files", %{ldf: ldf, tmp_dir: tmp_dir} do
+    df = ldf |> DF.head(15) |> DF.compute()
+
+    csv_path = Path.join([tmp_dir, "fossil_fuels.csv.gz"])
+    File.write!(csv_path, :zlib.gzip(DF.dump_csv!(df)))
+
+    ndjson_path = Path.join([tmp_dir, "fossil_fuels.ndjson.gz"])
+    File.write!(ndjson_path, :zlib.gzip(DF.dump_ndjson!(df)))
+
+    assert DF.to_rows(DF.compute(DF.from_csv!(csv_path, lazy: true))) == DF.to_rows(df)
+    assert DF.to_rows(DF.compute(DF.from_ndjson!(ndjson_path, lazy: true))) == DF.to_rows(df)
+  end
+
+  @tag :cloud_integration
+  test "to_ndjson/3 - cloud with streaming enabled", %{ldf: ldf} do
+    config = %FSS.S3.Config{
+      access_key_id: "test",
+      secret_access_key: "test",
+      endpoint: "http://localhost:4566",
+      region: "us-east-1"
+    }
+
+    path = "s3://test-bucket/test-lazy-writes/wine-#{System.monotonic_time()}.ndjson"
+
+    ldf = DF.head(ldf, 15)
+    assert :ok = DF.to_ndjson(ldf, path, streaming: true, config: config)
+
+    df = DF.compute(ldf)
+    df1 = DF.from_ndjson!(path, config: config)
+
+    assert DF.to_rows(df1) == DF.to_rows(df)
+  end
+
+  @tag :cloud_integration
+  test "to_ndjson/3 - cloud with streaming disabled", %{ldf: ldf} do
+    config = %FSS.S3.Config{
+      access_key_id: "test",
+      secret_access_key: "test",
+      endpoint: "http://localhost:4566",
+      region: "us-east-1"
+    }
+
+    path = "s3://test-bucket/test-lazy-writes/wine-#{System.monotonic_time()}.ndjson"
+
+    ldf = DF.head(ldf, 15)
+    assert :ok = DF.to_ndjson(ldf, path, streaming: false, config: config)
+
+    df = DF.compute(ldf)
+    df1 = DF.from_ndjson!(path, config: config)
+
+    assert DF.to_rows(df1) == DF.to_rows(df)
+  end
+
   @tag :tmp_dir
   test "to_parquet/2 - with defaults", %{ldf: ldf, tmp_dir: tmp_dir} do
     path = Path.join([tmp_dir, "fossil_fuels.parquet"])
@@ -403,6 +709,56 @@ defmodule Explorer.DataFrame.LazyTest do
     assert DF.to_rows(df1) |> Enum.sort() == DF.to_rows(df) |> Enum.sort()
   end
 
+  @tag :tmp_dir
+  test "to_parquet/3 - with write options", %{tmp_dir: tmp_dir} do
+    path = Path.join([tmp_dir, "numbers.parquet"])
+    ldf = DF.new([a: Enum.to_list(1..150)], lazy: true)
+
+    DF.to_parquet!(ldf, path, streaming: false, row_group_size: 50, statistics: [])
+
+    metadata = DF.parquet_metadata!(path)
+
+    assert Series.to_list(metadata["row_group_num_rows"]) == [50, 50, 50]
+    assert Series.to_list(metadata["min"]) == [nil, nil, nil]
+  end
+
+  @tag :tmp_dir
+  test "to_parquet/3 - partitioned", %{ldf: ldf, tmp_dir: tmp_dir} do
+    ldf = DF.head(ldf, 15)
+    DF.to_parquet!(ldf, tmp_dir, partition_by: ["year"])
+
+    df = ldf |> DF.compute() |> DF.discard("year")
+    df1 = DF.from_parquet!(Path.join([tmp_dir, "year=2010", "part-0.parquet"]))
+
+    assert DF.to_rows(df1) == DF.to_rows(df)
+  end
+
+  @tag :tmp_dir
+  test "to_ipc/3 - partitioned", %{ldf: ldf, tmp_dir: tmp_dir} do
+    ldf = DF.head(ldf, 15)
+    DF.to_ipc!(ldf, tmp_dir, partition_by: ["year"])
+
+    df = ldf |> DF.compute() |> DF.discard("year")
+    df1 = DF.from_ipc!(Path.join([tmp_dir, "year=2010", "part-0.ipc"]))
+
+    assert DF.to_rows(df1) == DF.to_rows(df)
+  end
+
+  @tag :tmp_dir
+  test "to_ipc/3 - partitioned with an unknown compression", %{ldf: ldf, tmp_dir: tmp_dir} do
+    entry = %FSS.Local.Entry{path: tmp_dir}
+
+    assert {:error, error} =
+             Explorer.PolarsBackend.LazyFrame.to_ipc_partitioned(
+               ldf,
+               entry,
+               ["year"],
+               {:snappy, nil}
+             )
+
+    assert Exception.message(error) =~ "the algorithm snappy is not supported for IPC compression"
+  end
+
   @tag :cloud_integration
   test "to_parquet/2 - cloud with streaming enabled", %{ldf: ldf} do
     config = %FSS.S3.Config{
@@ -491,6 +847,42 @@ defmodule Explorer.DataFrame.LazyTest do
                  end
   end
 
+  @tag :tmp_dir
+  test "from_ipc/2 - with hive partitions", %{df: df, tmp_dir: tmp_dir} do
+    DF.to_ipc!(df, tmp_dir, partition_by: ["year"])
+
+    df1 =
+      tmp_dir
+      |> Path.join("**/*.ipc")
+      |> DF.from_ipc!(lazy: true, hive_partitioning: true, include_file_paths: "path")
+      |> DF.filter_with(&Series.equal(&1["year"], 2014))
+      |> DF.compute()
+
+    assert DF.n_rows(df1) == 220
+    assert Series.to_list(Series.distinct(df1["year"])) == [2014]
+
+    assert Series.to_list(Series.distinct(df1["path"])) == [
+             Path.join([tmp_dir, "year=2014", "part-0.ipc"])
+           ]
+  end
+
+  @tag :tmp_dir
+  test "from_csv/2 - glob with include_file_paths", %{df: df, tmp_dir: tmp_dir} do
+    DF.to_csv!(DF.slice(df, 0, 10), Path.join(tmp_dir, "a.csv"))
+    DF.to_csv!(DF.slice(df, 10, 5), Path.join(tmp_dir, "b.csv"))
+
+    df1 =
+      tmp_dir
+      |> Path.join("*.csv")
+      |> DF.from_csv!(lazy: true, include_file_paths: "path")
+      |> DF.compute()
+
+    paths = df1["path"] |> Series.to_list() |> Enum.map(&Path.basename/1)
+
+    assert paths == List.duplicate("a.csv", 10) ++ List.duplicate("b.csv", 5)
+    assert DF.to_rows(DF.discard(df1, "path")) == DF.to_rows(DF.slice(df, 0, 15))
+  end
+
   @tag :tmp_dir
   test "from_ipc_stream/2 - with defaults", %{df: df, tmp_dir: tmp_dir} do
     path = Path.join([tmp_dir, "fossil_fuels.ipc"])
@@ -589,17 +981,6 @@ defmodule Explorer.DataFrame.LazyTest do
       [config: config]
     end
 
-    test "from_ipc/2", %{config: config} do
-      path = "s3://test-bucket/test-lazy-writes/wine.ipc"
-
-      assert {:error, error} = DF.from_ipc(path, config: config, lazy: true)
-
-      assert error ==
-               ArgumentError.exception(
-                 "reading IPC from AWS S3 is not supported for Lazy dataframes"
-               )
-    end
-
     test "from_ipc_stream/2", %{config: config} do
       path = "s3://test-bucket/test-lazy-writes/wine.ipcstream"
 
@@ -610,27 +991,67 @@ defmodule Explorer.DataFrame.LazyTest do
                  "reading IPC Stream from AWS S3 is not supported for Lazy dataframes"
                )
     end
+  end
 
-    test "from_ndjson/2", %{config: config} do
-      path = "s3://test-bucket/test-lazy-writes/wine.ndjson"
+  describe "cloud readers" do
+    setup do
+      config = %FSS.S3.Config{
+        access_key_id: "test",
+        secret_access_key: "test",
+        endpoint: "http://localhost:4566",
+        region: "us-east-1"
+      }
+
+      [config: config, df: Explorer.Datasets.wine()]
+    end
 
-      assert {:error, error} = DF.from_ndjson(path, config: config, lazy: true)
+    @tag :cloud_integration
+    test "from_csv/2", %{config: config, df: df} do
+      path = "s3://test-bucket/test-lazy-reads/wine-#{System.monotonic_time()}.csv"
+      assert :ok = DF.to_csv(df, path, config: config)
 
-      assert error ==
-               ArgumentError.exception(
-                 "reading NDJSON from AWS S3 is not supported for Lazy dataframes"
+      assert {:ok, ldf} = DF.from_csv(path, config: config, lazy: true)
+
+      assert DF.to_columns(DF.compute(ldf)) == DF.to_columns(df)
+    end
+
+    @tag :cloud_integration
+    test "from_csv/2 - with max_rows and columns", %{config: config, df: df} do
+      path = "s3://test-bucket/test-lazy-reads/wine-#{System.monotonic_time()}.csv"
+      assert :ok = DF.to_csv(df, path, config: config)
+
+      assert {:ok, ldf} =
+               DF.from_csv(path,
+                 config: config,
+                 lazy: true,
+                 max_rows: 10,
+                 columns: ["class", "alcohol"]
                )
+
+      df1 = DF.compute(ldf)
+
+      assert DF.names(df1) == ["class", "alcohol"]
+      assert DF.n_rows(df1) == 10
     end
 
-    test "from_csv/2", %{config: config} do
-      path = "s3://test-bucket/test-lazy-writes/wine.csv"
+    @tag :cloud_integration
+    test "from_ipc/2", %{config: config, df: df} do
+      path = "s3://test-bucket/test-lazy-reads/wine-#{System.monotonic_time()}.ipc"
+      assert :ok = DF.to_ipc(df, path, config: config)
 
-      assert {:error, error} = DF.from_csv(path, config: config, lazy: true)
+      assert {:ok, ldf} = DF.from_ipc(path, config: config, lazy: true, columns: ["class"])
 
-      assert error ==
-               ArgumentError.exception(
-                 "reading CSV from AWS S3 is not supported for Lazy dataframes"
-               )
+      assert DF.to_columns(DF.compute(ldf)) == DF.to_columns(DF.select(df, "class"))
+    end
+
+    @tag :cloud_integration
+    test "from_ndjson/2", %{config: config, df: df} do
+      path = "s3://test-bucket/test-lazy-reads/wine-#{System.monotonic_time()}.ndjson"
+      assert :ok = DF.to_ndjson(df, path, config: config)
+
+      assert {:ok, ldf} = DF.from_ndjson(path, config: config, lazy: true)
+
+      assert DF.to_columns(DF.compute(ldf)) == DF.to_columns(df)
     end
   end
 
@@ -930,6 +1351,48 @@ defmodule Explorer.DataFrame.LazyTest do
       assert ldf1.dtypes == df.dtypes
     end
 
+    test "adds random draws with parameters from columns" do
+      ldf = DF.new([a: [1, 2, 3], min: [0, 10, nil]], lazy: true)
+
+      ldf1 =
+        DF.mutate_with(ldf, fn ldf ->
+          [
+            b: Series.draw(ldf["a"], :discrete_uniform, [min: ldf["min"], max: 10], seed: 100),
+            c: Series.draw(ldf["a"], :uniform, [min: 0.0, max: 1.0], seed: 100)
+          ]
+        end)
+
+      assert ldf1.dtypes["b"] == {:s, 64}
+      assert ldf1.dtypes["c"] == {:f, 64}
+
+      df = DF.compute(ldf1)
+
+      assert [_, 10, nil] = Series.to_list(df["b"])
+      assert Enum.all?(Series.to_list(df["c"]), &(&1 >= 0.0 and &1 < 1.0))
+      assert ldf1.dtypes == df.dtypes
+
+      eager =
+        DF.mutate_with(DF.compute(ldf), fn df ->
+          [c: Series.draw(df["a"], :uniform, [min: 0.0, max: 1.0], seed: 100)]
+        end)
+
+      assert Series.to_list(eager["c"]) == Series.to_list(df["c"])
+    end
+
+    test "draws the same values each time the frame is computed" do
+      ldf = DF.new([a: [1, 2, 3, 4], c: [1, 1, 2, 2]], lazy: true)
+
+      draw = fn ldf ->
+        [d: Series.draw(ldf["a"], :uniform, [min: 0.0, max: 1.0], seed: 100)]
+      end
+
+      ldf1 = DF.mutate_with(ldf, draw)
+      assert DF.to_columns(DF.compute(ldf1)) == DF.to_columns(DF.compute(ldf1))
+
+      ldf2 = ldf |> DF.group_by("c") |> DF.mutate_with(draw)
+      assert DF.to_columns(DF.compute(ldf2)) == DF.to_columns(DF.compute(ldf2))
+    end
+
     test "adds literal columns" do
       ldf = DF.new([d: ~w(a b c)], lazy: true)
       ldf1 = DF.mutate_with(ldf, fn _ -> [a: 1, b: 2.0, c: true] end)
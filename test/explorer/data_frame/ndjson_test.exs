@@ -262,6 +262,58 @@ defmodule Explorer.DataFrame.NDJSONTest do
     end
   end
 
+  describe "compression" do
+    setup do
+      [df: Explorer.Datasets.iris() |> DF.slice(0, 10)]
+    end
+
+    @tag :tmp_dir
+    test "reads compressed files", %{df: df, tmp_dir: tmp_dir} do
+      path = Path.join(tmp_dir, "iris.ndjson.gz")
+      File.write!(path, :zlib.gzip(DF.dump_ndjson!(df)))
+
+      assert DF.to_columns(DF.from_ndjson!(path)) == DF.to_columns(df)
+    end
+
+    test "loads compressed binaries", %{df: df} do
+      contents = :zlib.gzip(DF.dump_ndjson!(df))
+
+      assert DF.to_columns(DF.load_ndjson!(contents)) == DF.to_columns(df)
+    end
+
+    @tag :tmp_dir
+    test "writes compressed files", %{df: df, tmp_dir: tmp_dir} do
+      path = Path.join(tmp_dir, "iris.ndjson.gz")
+      assert :ok = DF.to_ndjson(df, path, compression: {:gzip, 9})
+
+      assert path |> File.read!() |> :zlib.gunzip() == DF.dump_ndjson!(df)
+
+      path = Path.join(tmp_dir, "iris.ndjson.zst")
+      assert :ok = DF.to_ndjson(df, path, compression: :zstd)
+
+      assert <<0x28, 0xB5, 0x2F, 0xFD, _::binary>> = File.read!(path)
+      assert DF.to_columns(DF.from_ndjson!(path)) == DF.to_columns(df)
+    end
+
+    test "dumps compressed binaries", %{df: df} do
+      assert {:ok, gzipped} = DF.dump_ndjson(df, compression: :gzip)
+      assert :zlib.gunzip(gzipped) == DF.dump_ndjson!(df)
+
+      assert {:ok, zstd} = DF.dump_ndjson(df, compression: {:zstd, 19})
+      assert DF.to_columns(DF.load_ndjson!(zstd)) == DF.to_columns(df)
+    end
+
+    test "raises on unsupported compression", %{df: df} do
+      assert_raise ArgumentError, "unsupported :compression :snappy for NDJSON", fn ->
+        DF.dump_ndjson(df, compression: :snappy)
+      end
+
+      assert_raise ArgumentError,
+                   "gzip compression level must be between 1 and 9 inclusive or nil, got 10",
+                   fn -> DF.dump_ndjson(df, compression: {:gzip, 10}) end
+    end
+  end
+
   describe "cloud reads and writes" do
     setup do
       s3_config = %FSS.S3.Config{
@@ -284,6 +336,15 @@ defmodule Explorer.DataFrame.NDJSONTest do
       assert DF.to_columns(saved_df) == DF.to_columns(Explorer.Datasets.wine())
     end
 
+    @tag :cloud_integration
+    test "reads a compressed NDJSON file from S3", %{df: df, s3_config: s3_config} do
+      path = "s3://test-bucket/test-writes/wine-#{System.monotonic_time()}.ndjson.gz"
+      assert :ok = DF.to_ndjson(df, path, config: s3_config, compression: :gzip)
+
+      assert {:ok, ndjson_df} = DF.from_ndjson(path, config: s3_config)
+      assert DF.to_columns(ndjson_df) == DF.to_columns(df)
+    end
+
     @tag :cloud_integration
     test "returns an error in case file is not found in S3 bucket", %{s3_config: s3_config} do
       path = "s3://test-bucket/test-writes/file-does-not-exist.ndjson"
@@ -337,6 +398,127 @@ defmodule Explorer.DataFrame.NDJSONTest do
 
       assert {:error, %ArgumentError{message: "resource not found (404)"}} = DF.from_ndjson(url)
     end
+
+    test "scans a NDJSON file lazily", %{bypass: bypass, df: df} do
+      serve_object(bypass, "/path/to/file.ndjson", DF.dump_ndjson!(df))
+
+      url = http_endpoint(bypass) <> "/path/to/file.ndjson"
+
+      assert {:ok, ldf} = DF.from_ndjson(url, lazy: true)
+      assert DF.to_columns(DF.compute(ldf)) == DF.to_columns(df)
+    end
+
+    test "scans a NDJSON file lazily using headers", %{bypass: bypass, df: df} do
+      serve_object(bypass, "/path/to/file.ndjson", DF.dump_ndjson!(df), fn conn ->
+        assert ["Bearer my-token"] = Plug.Conn.get_req_header(conn, "authorization")
+      end)
+
+      url = http_endpoint(bypass) <> "/path/to/file.ndjson"
+      config = [headers: [{"authorization", "Bearer my-token"}]]
+
+      assert {:ok, ldf} = DF.from_ndjson(url, config: config, lazy: true)
+      assert DF.to_columns(DF.compute(ldf)) == DF.to_columns(df)
+    end
+  end
+
+  describe "stream_ndjson/2" do
+    @tag :tmp_dir
+    test "reads the file in chunks of rows", %{tmp_dir: tmp_dir} do
+      ndjson_path = to_ndjson(tmp_dir)
+
+      chunks = ndjson_path |> DF.stream_ndjson(chunk_size: 5) |> Enum.to_list()
+
+      assert Enum.map(chunks, &DF.n_rows/1) == [5, 5, 2]
+
+      assert DF.to_columns(DF.concat_rows(chunks)) ==
+               DF.to_columns(DF.from_ndjson!(ndjson_path))
+    end
+
+    @tag :tmp_dir
+    test "decompresses the file as it is read", %{tmp_dir: tmp_dir} do
+      df = DF.from_ndjson!(to_ndjson(tmp_dir))
+
+      for {compression, extension} <- [gzip: "gz", zstd: "zst"] do
+        path = Path.join(tmp_dir, "test.ndjson.#{extension}")
+        :ok = DF.to_ndjson(df, path, compression: compression)
+
+        chunks = path |> DF.stream_ndjson(chunk_size: 5) |> Enum.to_list()
+
+        assert Enum.map(chunks, &DF.n_rows/1) == [5, 5, 2]
+        assert DF.to_columns(DF.concat_rows(chunks)) == DF.to_columns(df)
+      end
+    end
+
+    @tag :tmp_dir
+    test "reads all the chunks with the same dtypes", %{tmp_dir: tmp_dir} do
+      ndjson_path = Path.join(tmp_dir, "test.ndjson")
+
+      File.write!(ndjson_path, """
+      {"a":1.5, "b":"x"}
+
+      {"a":2.5, "b":"y"}
+      {"a":3, "b":"z"}
+      """)
+
+      chunks = ndjson_path |> DF.stream_ndjson(chunk_size: 2) |> Enum.to_list()
+
+      assert Enum.map(chunks, &DF.dtypes/1) == [
+               %{"a" => {:f, 64}, "b" => :string},
+               %{"a" => {:f, 64}, "b" => :string}
+             ]
+
+      assert Enum.map(chunks, &DF.to_columns(&1, atom_keys: true)) == [
+               %{a: [1.5, 2.5], b: ["x", "y"]},
+               %{a: [3.0], b: ["z"]}
+             ]
+    end
+
+    @tag :tmp_dir
+    test "infers the schema from the rows of every chunk", %{tmp_dir: tmp_dir} do
+      ndjson_path = Path.join(tmp_dir, "test.ndjson")
+
+      File.write!(ndjson_path, """
+      {"a":1}
+      {"a":2}
+      {"a":3, "b":"z"}
+      """)
+
+      chunks = ndjson_path |> DF.stream_ndjson(chunk_size: 2) |> Enum.to_list()
+
+      assert Enum.map(chunks, &DF.to_columns(&1, atom_keys: true)) == [
+               %{a: [1, 2], b: [nil, nil]},
+               %{a: [3], b: ["z"]}
+             ]
+    end
+
+    @tag :tmp_dir
+    test "raises for keys after the rows read for schema inference", %{tmp_dir: tmp_dir} do
+      ndjson_path = Path.join(tmp_dir, "test.ndjson")
+
+      File.write!(ndjson_path, """
+      {"a":1}
+      {"a":2}
+      {"a":3, "b":"z"}
+      """)
+
+      stream = DF.stream_ndjson(ndjson_path, chunk_size: 2, infer_schema_length: 2)
+
+      assert_raise RuntimeError, ~r/the key "b" is not in the schema inferred/, fn ->
+        Enum.to_list(stream)
+      end
+    end
+  end
+
+  describe "write_batches/4" do
+    @tag :tmp_dir
+    test "writes the dataframes to a single file", %{tmp_dir: tmp_dir} do
+      df = Explorer.Datasets.iris()
+      path = Path.join(tmp_dir, "iris.ndjson")
+      chunks = Stream.map(0..149//40, &DF.slice(df, &1, 40))
+
+      assert :ok = DF.write_batches(chunks, path, :ndjson)
+      assert DF.to_columns(DF.from_ndjson!(path)) == DF.to_columns(df)
+    end
   end
 
   defp http_endpoint(bypass), do: "http://localhost:#{bypass.port}"
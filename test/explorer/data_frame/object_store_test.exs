@@ -0,0 +1,182 @@
+defmodule Explorer.DataFrame.ObjectStoreTest do
+  use ExUnit.Case, async: true
+
+  alias Explorer.DataFrame, as: DF
+  alias Explorer.FSS.Azure
+  alias Explorer.FSS.GCS
+  alias Explorer.FSS.Memory
+
+  setup do
+    [df: Explorer.Datasets.wine() |> DF.head(20)]
+  end
+
+  describe "in-memory store" do
+    setup do
+      [store: Memory.new_store()]
+    end
+
+    for format <- [:csv, :parquet, :ipc, :ipc_stream, :ndjson] do
+      @format format
+
+      test "writes and reads #{format}", %{df: df, store: store} do
+        entry = Memory.entry(store, "wine/data.#{@format}")
+
+        assert :ok = apply(DF, :"to_#{@format}", [df, entry])
+        assert {:ok, df1} = apply(DF, :"from_#{@format}", [entry])
+
+        assert DF.to_columns(df1) == DF.to_columns(df)
+      end
+
+      test "writes and reads #{format} lazily", %{df: df, store: store} do
+        entry = Memory.entry(store, "wine/lazy-data.#{@format}")
+
+        assert :ok = apply(DF, :"to_#{@format}", [DF.lazy(df), entry])
+        assert {:ok, ldf} = apply(DF, :"from_#{@format}", [entry, [lazy: true]])
+
+        assert DF.to_columns(DF.compute(ldf)) == DF.to_columns(df)
+      end
+    end
+
+    test "writes batches of a stream", %{df: df, store: store} do
+      entry = Memory.entry(store, "wine/batches.parquet")
+      chunks = Stream.map(0..(DF.n_rows(df) - 1)//50, &DF.slice(df, &1, 50))
+
+      assert :ok = DF.write_batches(chunks, entry, :parquet)
+      assert DF.to_columns(DF.from_parquet!(entry)) == DF.to_columns(df)
+    end
+
+    test "reads compressed CSV", %{df: df, store: store} do
+      entry = Memory.entry(store, "wine/data.csv.gz")
+
+      assert :ok = DF.to_csv(df, entry, compression: :gzip)
+      assert DF.to_columns(DF.from_csv!(entry)) == DF.to_columns(df)
+    end
+
+    test "keeps the objects apart from other stores", %{df: df, store: store} do
+      entry = Memory.entry(store, "wine/data.parquet")
+      assert :ok = DF.to_parquet(df, entry)
+
+      other_entry = Memory.entry(Memory.new_store(), "wine/data.parquet")
+      assert {:error, %RuntimeError{}} = DF.from_parquet(other_entry)
+    end
+  end
+
+  describe "GCS.parse/2" do
+    test "takes the bucket and key from the URL" do
+      assert {:ok, %GCS.Entry{key: "path/to/file.parquet", config: config}} =
+               GCS.parse("gs://my-bucket/path/to/file.parquet",
+                 config: [endpoint: "http://localhost:4443"]
+               )
+
+      assert config.bucket == "my-bucket"
+      assert config.endpoint == "http://localhost:4443"
+    end
+
+    test "returns an error for invalid URLs" do
+      assert {:error, %ArgumentError{message: message}} = GCS.parse("gs:///file.parquet")
+      assert message == "expected gs://<bucket>/<key> URL, got: gs:///file.parquet"
+    end
+  end
+
+  describe "Azure.parse/2" do
+    test "takes the container and key from the URL" do
+      assert {:ok, %Azure.Entry{key: "path/to/file.parquet", config: config}} =
+               Azure.parse("az://my-container/path/to/file.parquet",
+                 config: [account: "my-account", access_key: "my-key"]
+               )
+
+      assert config.container == "my-container"
+      assert config.account == "my-account"
+    end
+
+    test "requires the account" do
+      assert {:error, %ArgumentError{message: message}} =
+               Azure.parse("az://my-container/file.parquet", config: %Azure.Config{})
+
+      assert message == "the :account is required to access Azure"
+    end
+  end
+
+  describe "Google Cloud Storage" do
+    setup do
+      [config: [endpoint: "http://localhost:4443"]]
+    end
+
+    @tag :cloud_integration
+    test "writes and reads parquet", %{df: df, config: config} do
+      path = "gs://test-bucket/wine-#{System.monotonic_time()}.parquet"
+
+      assert :ok = DF.to_parquet(df, path, config: config)
+      assert DF.to_columns(DF.from_parquet!(path, config: config)) == DF.to_columns(df)
+    end
+
+    @tag :cloud_integration
+    test "writes and reads CSV lazily", %{df: df, config: config} do
+      path = "gs://test-bucket/wine-#{System.monotonic_time()}.csv"
+
+      assert :ok = DF.to_csv(DF.lazy(df), path, config: config)
+      assert {:ok, ldf} = DF.from_csv(path, config: config, lazy: true)
+
+      assert DF.to_columns(DF.compute(ldf)) == DF.to_columns(df)
+    end
+
+    @tag :cloud_integration
+    test "sinks and scans parquet lazily", %{df: df, config: config} do
+      path = "gs://test-bucket/wine-#{System.monotonic_time()}.parquet"
+
+      assert :ok = DF.to_parquet(DF.lazy(df), path, config: config, streaming: true)
+      assert {:ok, ldf} = DF.from_parquet(path, config: config, lazy: true)
+
+      assert DF.to_columns(DF.compute(ldf)) == DF.to_columns(df)
+    end
+
+    test "keeps the service account key along with the endpoint", %{config: config} do
+      config = Keyword.put(config, :service_account_key, "not a key")
+
+      assert {:error, error} = DF.from_parquet("gs://test-bucket/wine.parquet", config: config)
+      assert Exception.message(error) =~ "invalid GCS service account key"
+    end
+  end
+
+  describe "Azure Blob Storage" do
+    setup do
+      # The well-known account and key of the Azurite emulator.
+      config = [
+        account: "devstoreaccount1",
+        access_key:
+          "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==",
+        endpoint: "http://127.0.0.1:10000/devstoreaccount1"
+      ]
+
+      [config: config]
+    end
+
+    @tag :cloud_integration
+    test "writes and reads parquet", %{df: df, config: config} do
+      path = "az://test-container/wine-#{System.monotonic_time()}.parquet"
+
+      assert :ok = DF.to_parquet(df, path, config: config)
+      assert DF.to_columns(DF.from_parquet!(path, config: config)) == DF.to_columns(df)
+    end
+
+    @tag :cloud_integration
+    test "writes and reads NDJSON lazily", %{df: df, config: config} do
+      path = "az://test-container/wine-#{System.monotonic_time()}.ndjson"
+
+      assert :ok = DF.to_ndjson(DF.lazy(df), path, config: config)
+      assert {:ok, ldf} = DF.from_ndjson(path, config: config, lazy: true)
+
+      assert DF.to_columns(DF.compute(ldf)) == DF.to_columns(df)
+    end
+
+    @tag :cloud_integration
+    test "sinks and scans IPC lazily", %{df: df, config: config} do
+      path = "az://test-container/wine-#{System.monotonic_time()}.ipc"
+
+      assert :ok = DF.to_ipc(DF.lazy(df), path, config: config, streaming: true)
+      assert {:ok, ldf} = DF.from_ipc(path, config: config, lazy: true)
+
+      assert DF.to_columns(DF.compute(ldf)) == DF.to_columns(df)
+    end
+  end
+end
@@ -3,6 +3,7 @@ defmodule Explorer.DataFrame.ParquetTest do
 
   use ExUnit.Case, async: true
   alias Explorer.DataFrame, as: DF
+  alias Explorer.Series
   import Explorer.IOHelpers
 
   test "from_parquet/2" do
@@ -88,6 +89,26 @@ defmodule Explorer.DataFrame.ParquetTest do
 
       assert DF.to_columns(df) == DF.to_columns(Explorer.Datasets.wine())
     end
+
+    @tag :cloud_integration
+    test "reads a subset of the columns and rows of a parquet file from S3" do
+      config = %FSS.S3.Config{
+        access_key_id: "test",
+        secret_access_key: "test",
+        endpoint: "http://localhost:4566",
+        region: "us-east-1"
+      }
+
+      assert {:ok, df} =
+               DF.from_parquet("s3://test-bucket/wine.parquet",
+                 config: config,
+                 columns: [0, 2],
+                 max_rows: 10
+               )
+
+      assert DF.names(df) == ["class", "malic_acid"]
+      assert DF.n_rows(df) == 10
+    end
   end
 
   describe "from_parquet/2 - HTTP" do
@@ -96,10 +117,7 @@ defmodule Explorer.DataFrame.ParquetTest do
     end
 
     test "reads a parquet file from an HTTP server", %{bypass: bypass, df: df} do
-      Bypass.expect(bypass, "GET", "/path/to/file.parquet", fn conn ->
-        bytes = Explorer.DataFrame.dump_parquet!(df)
-        Plug.Conn.resp(conn, 200, bytes)
-      end)
+      serve_object(bypass, "/path/to/file.parquet", DF.dump_parquet!(df))
 
       url = http_endpoint(bypass) <> "/path/to/file.parquet"
 
@@ -109,10 +127,8 @@ defmodule Explorer.DataFrame.ParquetTest do
     end
 
     test "reads a parquet file from an HTTP server using headers", %{bypass: bypass, df: df} do
-      Bypass.expect(bypass, "GET", "/path/to/file.parquet", fn conn ->
+      serve_object(bypass, "/path/to/file.parquet", DF.dump_parquet!(df), fn conn ->
         assert ["Bearer my-token"] = Plug.Conn.get_req_header(conn, "authorization")
-        bytes = Explorer.DataFrame.dump_parquet!(df)
-        Plug.Conn.resp(conn, 200, bytes)
       end)
 
       url = http_endpoint(bypass) <> "/path/to/file.parquet"
@@ -126,15 +142,12 @@ defmodule Explorer.DataFrame.ParquetTest do
     end
 
     test "reads a parquet file from an FSS entry", %{bypass: bypass, df: df_expected} do
-      # Setup a `GET` expectation for `path` that returns the expected
-      # DataFrame as a `parquet` binary.
+      # Serve the expected DataFrame as a `parquet` binary at `path`.
       path = "/path/to/file.parquet"
       authorization = "Bearer my-token"
 
-      Bypass.expect(bypass, "GET", path, fn conn ->
+      serve_object(bypass, path, DF.dump_parquet!(df_expected), fn conn ->
         assert [^authorization] = Plug.Conn.get_req_header(conn, "authorization")
-        bytes = Explorer.DataFrame.dump_parquet!(df_expected)
-        Plug.Conn.resp(conn, 200, bytes)
       end)
 
       # Build an `%FSS.HTTP.Entry{}` for `path` manually.
@@ -148,7 +161,7 @@ defmodule Explorer.DataFrame.ParquetTest do
     end
 
     test "cannot find a parquet file", %{bypass: bypass} do
-      Bypass.expect(bypass, "GET", "/path/to/file.parquet", fn conn ->
+      Bypass.expect(bypass, "HEAD", "/path/to/file.parquet", fn conn ->
         Plug.Conn.resp(conn, 404, "not found")
       end)
 
@@ -156,6 +169,50 @@ defmodule Explorer.DataFrame.ParquetTest do
 
       assert {:error, %ArgumentError{message: "resource not found (404)"}} = DF.from_parquet(url)
     end
+
+    test "only fetches the selected columns", %{bypass: bypass} do
+      # The columns are large enough to be fetched in separate requests.
+      series = Explorer.Series.from_list(Enum.map(1..1_000_000, &(&1 * 1.5)))
+      df = DF.new(a: series, b: series, c: series, d: series)
+      bytes = DF.dump_parquet!(df)
+      serve_object(bypass, "/path/to/file.parquet", bytes)
+
+      url = http_endpoint(bypass) <> "/path/to/file.parquet"
+
+      assert {:ok, df1} = DF.from_parquet(url, columns: ["a"])
+      assert DF.to_columns(df1) == DF.to_columns(DF.select(df, "a"))
+
+      assert served_bytes("/path/to/file.parquet") < byte_size(bytes) / 2
+    end
+
+    test "scans a parquet file lazily", %{bypass: bypass, df: df} do
+      serve_object(bypass, "/path/to/file.parquet", DF.dump_parquet!(df))
+
+      url = http_endpoint(bypass) <> "/path/to/file.parquet"
+
+      assert {:ok, ldf} = DF.from_parquet(url, lazy: true)
+      assert DF.to_columns(DF.compute(ldf)) == DF.to_columns(df)
+    end
+
+    test "scans a parquet file lazily using headers", %{bypass: bypass, df: df} do
+      serve_object(bypass, "/path/to/file.parquet", DF.dump_parquet!(df), fn conn ->
+        assert ["Bearer my-token"] = Plug.Conn.get_req_header(conn, "authorization")
+      end)
+
+      url = http_endpoint(bypass) <> "/path/to/file.parquet"
+      config = [headers: [{"authorization", "Bearer my-token"}]]
+
+      assert {:ok, ldf} = DF.from_parquet(url, config: config, lazy: true)
+      assert DF.to_columns(DF.compute(ldf)) == DF.to_columns(df)
+    end
+  end
+
+  defp served_bytes(path, acc \\ 0) do
+    receive do
+      {:served, ^path, size} -> served_bytes(path, acc + size)
+    after
+      0 -> acc
+    end
   end
 
   defp http_endpoint(bypass), do: "http://localhost:#{bypass.port}"
@@ -267,6 +324,107 @@ defmodule Explorer.DataFrame.ParquetTest do
     assert is_binary(parquet)
   end
 
+  describe "stream_parquet/2" do
+    test "reads the file in chunks of rows" do
+      df = Explorer.Datasets.iris()
+      chunks = df |> tmp_parquet_file!() |> DF.stream_parquet(chunk_size: 40) |> Enum.to_list()
+
+      assert Enum.map(chunks, &DF.n_rows/1) == [40, 40, 40, 30]
+      assert DF.to_columns(DF.concat_rows(chunks)) == DF.to_columns(df)
+    end
+
+    @tag :tmp_dir
+    test "reads chunks across row groups", %{tmp_dir: tmp_dir} do
+      df = Explorer.Datasets.iris()
+      path = Path.join(tmp_dir, "iris.parquet")
+      DF.to_parquet!(df, path, row_group_size: 50)
+
+      chunks = path |> DF.stream_parquet(chunk_size: 40) |> Enum.to_list()
+
+      assert Enum.map(chunks, &DF.n_rows/1) == [40, 40, 40, 30]
+      assert DF.to_columns(DF.concat_rows(chunks)) == DF.to_columns(df)
+    end
+
+    test "reads the given columns" do
+      df = Explorer.Datasets.iris()
+      parquet = tmp_parquet_file!(df)
+
+      for columns <- [["petal_width", "species"], [3, 4]] do
+        chunks = parquet |> DF.stream_parquet(chunk_size: 100, columns: columns) |> Enum.to_list()
+
+        assert Enum.map(chunks, &DF.names/1) == [
+                 ["petal_width", "species"],
+                 ["petal_width", "species"]
+               ]
+
+        assert DF.to_columns(DF.concat_rows(chunks)) ==
+                 DF.to_columns(DF.select(df, ["petal_width", "species"]))
+      end
+    end
+
+    test "raises with a zero chunk size" do
+      parquet = tmp_parquet_file!(Explorer.Datasets.iris())
+
+      assert_raise RuntimeError, ~r/"batch_size" expected to be non zero/, fn ->
+        parquet |> DF.stream_parquet(chunk_size: 0) |> Enum.to_list()
+      end
+    end
+  end
+
+  describe "write_batches/4" do
+    @tag :tmp_dir
+    test "writes the dataframes to a single file", %{tmp_dir: tmp_dir} do
+      df = Explorer.Datasets.iris()
+      path = Path.join(tmp_dir, "iris.parquet")
+      chunks = Stream.map(0..149//40, &DF.slice(df, &1, 40))
+
+      assert :ok = DF.write_batches(chunks, path, :parquet, compression: {:zstd, 3})
+      assert DF.to_columns(DF.from_parquet!(path)) == DF.to_columns(df)
+    end
+
+    @tag :tmp_dir
+    test "writes the stream of another file", %{tmp_dir: tmp_dir} do
+      df = Explorer.Datasets.iris()
+      path = Path.join(tmp_dir, "iris.parquet")
+
+      df
+      |> tmp_parquet_file!()
+      |> DF.stream_parquet(chunk_size: 40)
+      |> DF.write_batches!(path, :parquet)
+
+      assert DF.to_columns(DF.from_parquet!(path)) == DF.to_columns(df)
+    end
+
+    @tag :tmp_dir
+    test "returns an error when the dataframes do not match", %{tmp_dir: tmp_dir} do
+      df = Explorer.Datasets.iris()
+      path = Path.join(tmp_dir, "iris.parquet")
+      chunks = [df, DF.select(df, ["species"])]
+
+      assert {:error, %RuntimeError{} = error} = DF.write_batches(chunks, path, :parquet)
+
+      assert Exception.message(error) =~
+               "the dataframe does not have the same columns and dtypes as the first batch"
+
+      refute File.exists?(path)
+    end
+
+    @tag :tmp_dir
+    test "uses the write options", %{tmp_dir: tmp_dir} do
+      df = DF.new(a: Enum.to_list(1..150))
+      path = Path.join(tmp_dir, "a.parquet")
+      chunks = [DF.slice(df, 0, 100), DF.slice(df, 100, 50)]
+
+      assert :ok = DF.write_batches(chunks, path, :parquet, row_group_size: 40, statistics: [])
+
+      metadata = DF.parquet_metadata!(path)
+
+      assert Series.to_list(metadata["row_group_num_rows"]) == [40, 40, 20, 40, 10]
+      assert Series.to_list(metadata["min"]) == [nil, nil, nil, nil, nil]
+      assert DF.to_columns(DF.from_parquet!(path)) == DF.to_columns(df)
+    end
+  end
+
   describe "to_parquet/2" do
     setup do
       [df: Explorer.Datasets.iris()]
@@ -322,6 +480,131 @@ defmodule Explorer.DataFrame.ParquetTest do
         assert_equal_from_path(df, parquet_path)
       end
     end
+
+    @tag :tmp_dir
+    test "writes one file per partition", %{df: df, tmp_dir: tmp_dir} do
+      assert :ok = DF.to_parquet(df, tmp_dir, partition_by: ["species"])
+
+      for species <- ["Iris-setosa", "Iris-versicolor", "Iris-virginica"] do
+        path = Path.join([tmp_dir, "species=#{species}", "part-0.parquet"])
+        expected =
+          df
+          |> DF.filter_with(&Explorer.Series.equal(&1["species"], species))
+          |> DF.discard("species")
+
+        assert DF.to_columns(DF.from_parquet!(path)) == DF.to_columns(expected)
+      end
+    end
+
+    @tag :tmp_dir
+    test "requires at least one partition column", %{df: df, tmp_dir: tmp_dir} do
+      assert {:error, %ArgumentError{} = error} = DF.to_parquet(df, tmp_dir, partition_by: [])
+      assert Exception.message(error) == ":partition_by must have at least one column"
+    end
+
+    test "cannot write partitions over HTTP", %{df: df} do
+      assert {:error, %ArgumentError{} = error} =
+               DF.to_parquet(df, "https://example.com/iris", partition_by: ["species"])
+
+      assert Exception.message(error) == "partitioned writes are not supported over HTTP"
+    end
+  end
+
+  describe "parquet_metadata/2" do
+    @tag :tmp_dir
+    test "reads the metadata of each column chunk", %{tmp_dir: tmp_dir} do
+      path = Path.join(tmp_dir, "test.parquet")
+      df = DF.new(a: [1, nil, 3], b: ["x", "y", "z"])
+      DF.to_parquet!(df, path, compression: :zstd)
+
+      metadata = DF.parquet_metadata!(path)
+
+      assert DF.n_rows(metadata) == 2
+      assert Series.to_list(metadata["num_rows"]) == [3, 3]
+      assert Series.to_list(metadata["row_group"]) == [0, 0]
+      assert Series.to_list(metadata["row_group_num_rows"]) == [3, 3]
+      assert Series.to_list(metadata["column"]) == ["a", "b"]
+      assert Series.to_list(metadata["physical_type"]) == ["Int64", "ByteArray"]
+      assert Series.to_list(metadata["compression"]) == ["zstd", "zstd"]
+      assert Series.to_list(metadata["null_count"]) == [1, 0]
+      assert Series.to_list(metadata["min"]) == ["1", "x"]
+      assert Series.to_list(metadata["max"]) == ["3", "z"]
+      assert Series.to_list(metadata["created_by"]) == ["Polars", "Polars"]
+    end
+
+    test "reads the schema of files without row groups" do
+      metadata = DF.parquet_metadata!("test/support/no_row_groups.parquet")
+
+      assert DF.to_rows(metadata, atom_keys: true) == [
+               %{
+                 num_rows: 0,
+                 created_by: "explorer",
+                 row_group: nil,
+                 row_group_num_rows: nil,
+                 row_group_byte_size: nil,
+                 column: "a",
+                 physical_type: "Int64",
+                 logical_type: nil,
+                 compression: nil,
+                 encodings: [],
+                 num_values: nil,
+                 compressed_size: nil,
+                 uncompressed_size: nil,
+                 null_count: nil,
+                 distinct_count: nil,
+                 min: nil,
+                 max: nil
+               }
+             ]
+
+      loaded = DF.load_parquet_metadata!(File.read!("test/support/no_row_groups.parquet"))
+      assert DF.to_rows(loaded) == DF.to_rows(metadata)
+    end
+
+    test "returns an error for a file that is not Parquet" do
+      assert {:error, %RuntimeError{}} = DF.load_parquet_metadata("not a parquet file")
+    end
+  end
+
+  describe "write options" do
+    setup do
+      [df: DF.new(a: Enum.to_list(1..150))]
+    end
+
+    test "row_group_size", %{df: df} do
+      metadata = DF.load_parquet_metadata!(DF.dump_parquet!(df, row_group_size: 50))
+
+      assert Series.to_list(metadata["row_group_num_rows"]) == [50, 50, 50]
+      assert Series.to_list(metadata["min"]) == ["1", "51", "101"]
+      assert Series.to_list(metadata["null_count"]) == [0, 0, 0]
+    end
+
+    test "statistics", %{df: df} do
+      metadata = DF.load_parquet_metadata!(DF.dump_parquet!(df, statistics: []))
+
+      assert Series.to_list(metadata["min"]) == [nil]
+      assert Series.to_list(metadata["null_count"]) == [nil]
+    end
+
+    @tag :tmp_dir
+    test "are used by partitioned writes", %{tmp_dir: tmp_dir} do
+      df = DF.new(a: Enum.to_list(1..100), b: List.duplicate("x", 100))
+      assert :ok = DF.to_parquet(df, tmp_dir, partition_by: ["b"], row_group_size: 25)
+
+      metadata = DF.parquet_metadata!(Path.join([tmp_dir, "b=x", "part-0.parquet"]))
+      assert Series.to_list(metadata["row_group_num_rows"]) == [25, 25, 25, 25]
+    end
+
+    test "are validated", %{df: df} do
+      assert_raise ArgumentError,
+                   "expected :row_group_size to be a positive integer or nil, got: 0",
+                   fn -> DF.dump_parquet(df, row_group_size: 0) end
+
+      assert_raise ArgumentError,
+                   "expected :statistics to be a list with :min_max, :null_count or " <>
+                     ":distinct_count, got: [:mean]",
+                   fn -> DF.to_parquet(df, "unused.parquet", statistics: [:mean]) end
+    end
   end
 
   describe "to_parquet/3 - cloud" do
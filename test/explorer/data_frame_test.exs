@@ -3703,6 +3703,40 @@ defmodule Explorer.DataFrameTest do
     end
   end
 
+  describe "to_arrow_c_data/1 and from_arrow_c_data/2" do
+    test "round trips the dataframe" do
+      df = Explorer.Datasets.iris()
+      imported = df |> DF.to_arrow_c_data() |> DF.from_arrow_c_data()
+
+      assert DF.names(imported) == DF.names(df)
+      assert DF.dtypes(imported) == DF.dtypes(df)
+      assert DF.to_columns(imported) == DF.to_columns(df)
+    end
+
+    test "raises when the array is not a struct" do
+      c_data = Series.to_arrow_c_data(Series.from_list([1, 2, 3]))
+
+      assert_raise RuntimeError, ~r/expected `Struct`/, fn ->
+        DF.from_arrow_c_data(c_data)
+      end
+    end
+
+    test "raises when the array was already imported" do
+      c_data = DF.to_arrow_c_data(DF.new(a: [1, 2]))
+      DF.from_arrow_c_data(c_data)
+
+      assert_raise RuntimeError, ~r/the array was already released, or imported/, fn ->
+        DF.from_arrow_c_data(c_data)
+      end
+    end
+
+    test "raises on lazy frames" do
+      assert_raise RuntimeError, ~r/to_arrow_c_data\/1/, fn ->
+        DF.to_arrow_c_data(DF.lazy(DF.new(a: [1, 2])))
+      end
+    end
+  end
+
   describe "select/2" do
     test "keep column names" do
       df = DF.new(a: ["a", "b", "c"], b: [1, 2, 3])
@@ -0,0 +1,88 @@
+defmodule Explorer.PolarsBackend.DensityTest do
+  use ExUnit.Case, async: true
+
+  alias Explorer.PolarsBackend.Shared
+  alias Explorer.Series
+
+  # Reference values from `scipy.stats.gaussian_kde` (Scott's rule)
+  # and from R's `bw.nrd0` (Silverman's rule).
+  @values [1.0, 2.0, 2.5, 4.0, 7.0]
+
+  defp kde(points, kernel, bandwidth) do
+    @values
+    |> Series.from_list()
+    |> Shared.apply_series(:s_kde, [Series.from_list(points).data, kernel, bandwidth])
+    |> Series.to_list()
+  end
+
+  defp ecdf(values, points) do
+    values
+    |> Series.from_list()
+    |> Shared.apply_series(:s_ecdf, [Series.from_list(points).data])
+    |> Series.to_list()
+  end
+
+  defp assert_all_in_delta(values, expected) do
+    assert length(values) == length(expected)
+
+    for {value, expected} <- Enum.zip(values, expected) do
+      case expected do
+        nil -> assert value == nil
+        expected -> assert_in_delta value, expected, 1.0e-12
+      end
+    end
+  end
+
+  describe "kde" do
+    test "gaussian kernel with Scott's rule" do
+      assert_all_in_delta(kde([0.0, 2.0, 5.0, nil], :gaussian, :scott), [
+        0.08177224422831424,
+        0.15594529545733615,
+        0.09155585863475953,
+        nil
+      ])
+    end
+
+    test "gaussian kernel with Silverman's rule" do
+      assert_all_in_delta(kde([0.0, 2.0, 5.0], :gaussian, :silverman), [
+        0.06134455174968226,
+        0.2120761245781655,
+        0.06205536037945318
+      ])
+    end
+
+    test "fixed bandwidth" do
+      assert_all_in_delta(kde([0.0, 2.0, 5.0], :gaussian, {:fixed, 1.0}), [
+        0.06272476435215991,
+        0.20939415698351568,
+        0.06361113403272058
+      ])
+
+      assert_all_in_delta(kde([0.0, 2.0, 5.0], :epanechnikov, {:fixed, 1.0}), [
+        0.0,
+        0.2625,
+        0.0
+      ])
+    end
+
+    test "raises on invalid bandwidths" do
+      assert_raise RuntimeError, ~r/kde bandwidth must be a positive number, got -1/, fn ->
+        kde([0.0], :gaussian, {:fixed, -1.0})
+      end
+
+      assert_raise RuntimeError, ~r/kde needs at least two values/, fn ->
+        [1.0]
+        |> Series.from_list()
+        |> Shared.apply_series(:s_kde, [Series.from_list([0.0]).data, :gaussian, :scott])
+      end
+    end
+  end
+
+  describe "ecdf" do
+    test "is the fraction of values less than or equal to each point" do
+      values = [3.0, 1.0, nil, 2.0, 2.0, :nan]
+
+      assert ecdf(values, [0.0, 1.0, 2.0, 2.5, 3.0, nil]) == [0.0, 0.25, 0.75, 0.75, 1.0, nil]
+    end
+  end
+end
@@ -0,0 +1,245 @@
+defmodule Explorer.PolarsBackend.DistributionTest do
+  use ExUnit.Case, async: true
+
+  alias Explorer.PolarsBackend.Shared
+  alias Explorer.Series
+
+  defp apply_dist(series, fun, args) do
+    series
+    |> Shared.apply_series(fun, args)
+    |> Series.to_list()
+  end
+
+  defp assert_all_in_delta(values, expected) do
+    assert length(values) == length(expected)
+
+    for {value, expected} <- Enum.zip(values, expected) do
+      case expected do
+        nil -> assert value == nil
+        expected when is_atom(expected) -> assert value == expected
+        expected -> assert_in_delta value, expected, 1.0e-12
+      end
+    end
+  end
+
+  describe "discrete distributions" do
+    test "binomial pmf, ln_pmf, cdf and inverse_cdf" do
+      s = Series.from_list([0, 5, 10, nil])
+
+      assert_all_in_delta(apply_dist(s, :s_binomial_pmf, [0.5, 10]), [
+        0.0009765625,
+        0.24609375,
+        0.0009765625,
+        nil
+      ])
+
+      assert_all_in_delta(apply_dist(s, :s_binomial_ln_pmf, [0.5, 10]), [
+        :math.log(0.0009765625),
+        -1.4020427180880297,
+        :math.log(0.0009765625),
+        nil
+      ])
+
+      assert_all_in_delta(apply_dist(s, :s_binomial_cdf, [0.5, 10]), [
+        0.0009765625,
+        0.623046875,
+        1.0,
+        nil
+      ])
+
+      p = Series.from_list([0.5, 1.0, nil])
+      assert apply_dist(p, :s_binomial_inverse_cdf, [0.5, 10]) == [5, 10, nil]
+    end
+
+    test "poisson pmf, ln_pmf and cdf" do
+      s = Series.from_list([2])
+
+      assert_all_in_delta(apply_dist(s, :s_poisson_pmf, [3.0]), [0.22404180765538775])
+      assert_all_in_delta(apply_dist(s, :s_poisson_ln_pmf, [3.0]), [-1.4959226032237258])
+      assert_all_in_delta(apply_dist(s, :s_poisson_cdf, [3.0]), [0.42319008112684353])
+    end
+
+    test "geometric, hypergeometric and negative binomial pmf and cdf" do
+      s = Series.from_list([1, 2])
+
+      assert_all_in_delta(apply_dist(s, :s_geometric_pmf, [0.25]), [0.25, 0.1875])
+      assert_all_in_delta(apply_dist(s, :s_geometric_cdf, [0.25]), [0.25, 0.4375])
+
+      assert_all_in_delta(apply_dist(s, :s_hypergeometric_pmf, [50, 5, 10]), [
+        0.43133719722856767,
+        0.20983971757065453
+      ])
+
+      assert_all_in_delta(apply_dist(s, :s_hypergeometric_cdf, [50, 5, 10]), [
+        0.7418999792331363,
+        0.9517396968037909
+      ])
+
+      assert_all_in_delta(apply_dist(s, :s_negative_binomial_pmf, [2.0, 0.5]), [0.25, 0.1875])
+      assert_all_in_delta(apply_dist(s, :s_negative_binomial_cdf, [2.0, 0.5]), [0.5, 0.6875])
+    end
+
+    test "negative values have zero probability" do
+      s = Series.from_list([-1], dtype: {:s, 8})
+
+      assert apply_dist(s, :s_poisson_pmf, [3.0]) == [0.0]
+      assert apply_dist(s, :s_poisson_ln_pmf, [3.0]) == [:neg_infinity]
+      assert apply_dist(s, :s_poisson_cdf, [3.0]) == [0.0]
+    end
+
+    test "probabilities outside of [0, 1] have no quantile" do
+      p = Series.from_list([-0.5, 1.5, :nan])
+      assert apply_dist(p, :s_poisson_inverse_cdf, [3.0]) == [nil, nil, nil]
+    end
+
+    test "invalid parameters name the distribution and its parameters" do
+      s = Series.from_list([1])
+
+      message =
+        "Invalid parameters for the Binomial distribution (p = 1.5, n = 10): " <>
+          "Probability is NaN or not in [0, 1]"
+
+      assert_raise RuntimeError, ~r/#{Regex.escape(message)}/, fn ->
+        apply_dist(s, :s_binomial_pmf, [1.5, 10])
+      end
+    end
+
+    test "only accept integer series" do
+      s = Series.from_list([1.0])
+
+      assert_raise RuntimeError, ~r/expected an integer series, got f64/, fn ->
+        apply_dist(s, :s_poisson_pmf, [3.0])
+      end
+    end
+  end
+
+  describe "continuous distributions" do
+    test "normal pdf, ln_pdf, cdf, sf and inverse_cdf" do
+      s = Series.from_list([0.0, 1.96, nil])
+
+      assert_all_in_delta(apply_dist(s, :s_normal_pdf, [0.0, 1.0]), [
+        0.3989422804014327,
+        0.05844094433345147,
+        nil
+      ])
+
+      assert_all_in_delta(apply_dist(s, :s_normal_ln_pdf, [0.0, 1.0]), [
+        -0.9189385332046727,
+        -2.8397385332046725,
+        nil
+      ])
+
+      assert_all_in_delta(apply_dist(s, :s_normal_cdf, [0.0, 1.0]), [
+        0.5,
+        0.9750021048517795,
+        nil
+      ])
+
+      assert_all_in_delta(apply_dist(s, :s_normal_sf, [0.0, 1.0]), [
+        0.5,
+        0.024997895148220435,
+        nil
+      ])
+
+      p = Series.from_list([0.5, 0.975])
+      expected = [0.0, 1.959963984540054]
+      assert_all_in_delta(apply_dist(p, :s_normal_inverse_cdf, [0.0, 1.0]), expected)
+    end
+
+    test "exponential, gamma, beta, uniform and students t" do
+      s = Series.from_list([1.0])
+
+      assert_all_in_delta(apply_dist(s, :s_exp_pdf, [2.0]), [0.2706705664732254])
+      assert_all_in_delta(apply_dist(s, :s_exp_ln_pdf, [2.0]), [-1.3068528194400546])
+      assert_all_in_delta(apply_dist(s, :s_exp_cdf, [2.0]), [0.8646647167633873])
+      assert_all_in_delta(apply_dist(s, :s_exp_sf, [2.0]), [0.1353352832366127])
+      assert_all_in_delta(apply_dist(s, :s_gamma_cdf, [2.0, 1.0]), [0.26424111765711533])
+      assert_all_in_delta(apply_dist(s, :s_uniform_pdf, [0.0, 4.0]), [0.25])
+      assert_all_in_delta(apply_dist(s, :s_uniform_cdf, [0.0, 4.0]), [0.25])
+      assert_all_in_delta(apply_dist(s, :s_students_t_cdf, [0.0, 1.0, 1.0]), [0.75])
+
+      half = Series.from_list([0.5])
+      assert_all_in_delta(apply_dist(half, :s_beta_pdf, [2.0, 2.0]), [1.5])
+      assert_all_in_delta(apply_dist(half, :s_beta_cdf, [2.0, 2.0]), [0.5])
+    end
+
+    test "invalid parameters name the distribution and its parameters" do
+      s = Series.from_list([1.0])
+
+      message =
+        "Invalid parameters for the Normal distribution (mu = 0.0, sigma = -1.0): " <>
+          "Standard deviation is NaN, zero or less than zero"
+
+      assert_raise RuntimeError, ~r/#{Regex.escape(message)}/, fn ->
+        apply_dist(s, :s_normal_cdf, [0.0, -1.0])
+      end
+    end
+
+    test "probabilities outside of [0, 1] have no quantile" do
+      p = Series.from_list([-0.5, 1.5])
+      assert apply_dist(p, :s_normal_inverse_cdf, [0.0, 1.0]) == [nil, nil]
+    end
+  end
+
+  describe "discrete survival functions and mass functions" do
+    test "sf is the complement of the cdf" do
+      s = Series.from_list([-1, 5, nil])
+
+      assert_all_in_delta(apply_dist(s, :s_binomial_sf, [0.5, 10]), [1.0, 0.376953125, nil])
+    end
+
+    test "bernoulli" do
+      s = Series.from_list([0, 1, 2])
+
+      assert_all_in_delta(apply_dist(s, :s_bernoulli_pmf, [0.25]), [0.75, 0.25, 0.0])
+      assert_all_in_delta(apply_dist(s, :s_bernoulli_cdf, [0.25]), [0.75, 1.0, 1.0])
+    end
+
+    test "categorical" do
+      s = Series.from_list([0, 1, 2])
+
+      assert_all_in_delta(apply_dist(s, :s_categorical_pmf, [[1.0, 3.0, 4.0]]), [
+        0.125,
+        0.375,
+        0.5
+      ])
+
+      assert_all_in_delta(apply_dist(s, :s_categorical_cdf, [[1.0, 3.0, 4.0]]), [
+        0.125,
+        0.5,
+        1.0
+      ])
+    end
+
+    test "invalid parameters name the distribution and its parameters" do
+      message =
+        "Invalid parameters for the Categorical distribution (prob_mass = []): " <>
+          "Probability mass is empty"
+
+      assert_raise RuntimeError, ~r/#{Regex.escape(message)}/, fn ->
+        apply_dist(Series.from_list([1]), :s_categorical_pmf, [[]])
+      end
+    end
+
+    test "discrete uniform accepts negative values" do
+      s = Series.from_list([-3, -2, 0, 3])
+
+      assert_all_in_delta(apply_dist(s, :s_discrete_uniform_pmf, [-2, 1]), [
+        0.0,
+        0.25,
+        0.25,
+        0.0
+      ])
+
+      assert_all_in_delta(apply_dist(s, :s_discrete_uniform_cdf, [-2, 1]), [
+        0.0,
+        0.25,
+        0.75,
+        1.0
+      ])
+
+      p = Series.from_list([0.2, 0.9])
+      assert apply_dist(p, :s_discrete_uniform_inverse_cdf, [-2, 1]) == [-2, 1]
+    end
+  end
+end
@@ -0,0 +1,142 @@
+defmodule Explorer.PolarsBackend.FitTest do
+  use ExUnit.Case, async: true
+
+  alias Explorer.PolarsBackend.Shared
+  alias Explorer.Series
+
+  # Reference values are the closed form (or numerically solved) maximum
+  # likelihood estimates, which is what R's `MASS::fitdistr` returns.
+  @positive [0.5, 1.2, 2.3, 0.8, 3.1, 1.7, 2.0, nil]
+
+  defp fit(values, fun) do
+    values
+    |> Series.from_list()
+    |> Shared.apply_series(fun)
+    |> Shared.create_dataframe!()
+    |> Explorer.DataFrame.to_rows()
+    |> hd()
+  end
+
+  defp assert_fit(result, expected, delta \\ 1.0e-9) do
+    assert Map.keys(result) |> Enum.sort() == Map.keys(expected) |> Enum.sort()
+
+    for {name, value} <- expected do
+      assert_in_delta result[name], value, delta, "#{name}: #{result[name]} != #{value}"
+    end
+  end
+
+  test "normal" do
+    assert_fit(fit(@positive, :s_fit_normal), %{
+      "mu" => 1.6571428571428571,
+      "sigma" => 0.83641606676025412,
+      "log_likelihood" => -8.6821660170990427,
+      "aic" => 21.364332034198085,
+      "bic" => 21.256152332308712
+    })
+  end
+
+  test "log-normal parameters are named like the log-normal functions" do
+    result = fit(@positive, :s_fit_log_normal)
+
+    assert_fit(result, %{
+      "location" => 0.35058821299544569,
+      "scale" => 0.59026994339663347,
+      "log_likelihood" => -8.6964600146410372,
+      "aic" => 21.392920029282074,
+      "bic" => 21.284740327392701
+    })
+
+    cdf =
+      Series.from_list([1.0])
+      |> Shared.apply_series(:s_log_normal_cdf, [result["location"], result["scale"]])
+      |> Series.to_list()
+
+    assert_in_delta hd(cdf), 0.27627426210797399, 1.0e-9
+  end
+
+  test "exponential" do
+    assert_fit(fit(@positive, :s_fit_exp), %{
+      "rate" => 0.60344827586206897,
+      "log_likelihood" => -10.53566464339904,
+      "aic" => 23.071329286798079,
+      "bic" => 23.017239435853393
+    })
+  end
+
+  test "gamma" do
+    assert_fit(
+      fit(@positive, :s_fit_gamma),
+      %{
+        "shape" => 3.3937043505676599,
+        "rate" => 2.0479250391356569,
+        "log_likelihood" => -8.4518123595005476,
+        "aic" => 20.903624719001095,
+        "bic" => 20.795445017111722
+      },
+      1.0e-6
+    )
+  end
+
+  test "weibull" do
+    assert_fit(
+      fit(@positive, :s_fit_weibull),
+      %{
+        "shape" => 2.113103649831508,
+        "scale" => 1.8760705919174276,
+        "log_likelihood" => -8.3378584996834145,
+        "aic" => 20.675716999366829,
+        "bic" => 20.567537297477456
+      },
+      1.0e-6
+    )
+  end
+
+  test "beta parameters are named like the beta functions" do
+    assert_fit(fit([0.2, 0.35, 0.5, 0.65, 0.4], :s_fit_beta), %{
+      "a" => 4.1070796460176991,
+      "b" => 5.6716814159292035,
+      "log_likelihood" => 2.5196772160988651,
+      "aic" => -1.0393544321977303,
+      "bic" => -1.8204786073295295
+    })
+  end
+
+  test "poisson" do
+    assert_fit(fit([1, 3, 2, 0, 4, 2], :s_fit_poisson), %{
+      "lambda" => 2.0,
+      "log_likelihood" => -10.038341493976548,
+      "aic" => 22.076682987953095,
+      "bic" => 21.86844245718115
+    })
+  end
+
+  test "raises on values outside of the support" do
+    assert_raise RuntimeError, ~r/fitting this distribution needs positive values/, fn ->
+      fit([1.0, -1.0], :s_fit_gamma)
+    end
+
+    assert_raise RuntimeError, ~r/fitting this distribution needs at least 2 values, got 1/, fn ->
+      fit([1.0], :s_fit_normal)
+    end
+  end
+
+  test "raises on samples with no variation" do
+    message =
+      "Invalid parameters for the Normal distribution (mu = 2, sigma = 0): " <>
+        "All the values are equal, so there is no variation to fit sigma to"
+
+    assert_raise RuntimeError, message, fn -> fit([2.0, 2.0, 2.0], :s_fit_normal) end
+
+    message =
+      "Invalid parameters for the Exp distribution (rate = inf): " <>
+        "All the values are zero, so the rate is infinite"
+
+    assert_raise RuntimeError, message, fn -> fit([0.0, 0.0], :s_fit_exp) end
+
+    message =
+      "Invalid parameters for the Poisson distribution (lambda = 0): " <>
+        "All the values are zero, so lambda is zero"
+
+    assert_raise RuntimeError, message, fn -> fit([0, 0, 0], :s_fit_poisson) end
+  end
+end
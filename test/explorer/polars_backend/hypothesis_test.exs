@@ -0,0 +1,123 @@
+defmodule Explorer.PolarsBackend.HypothesisTest do
+  use ExUnit.Case, async: true
+
+  alias Explorer.DataFrame, as: DF
+  alias Explorer.PolarsBackend.Shared
+  alias Explorer.Series
+
+  # Reference values from R (`t.test` and `chisq.test`) and from
+  # `scipy.stats` (`ttest_ind`, `chisquare` and `chi2_contingency`).
+
+  defp hypothesis_test(fun, args) do
+    fun
+    |> Shared.apply(args)
+    |> Shared.create_dataframe!()
+    |> DF.to_rows(atom_keys: true)
+    |> hd()
+  end
+
+  defp assert_result(result, statistic, p_value, df) do
+    assert_in_delta result.statistic, statistic, 1.0e-9
+    assert_in_delta result.p_value, p_value, 1.0e-9
+
+    if df do
+      assert_in_delta result.df, df, 1.0e-9
+    else
+      assert result.df == nil
+    end
+  end
+
+  describe "t-test" do
+    setup do
+      left = Series.from_list([19.1, 20.3, 21.0, 18.7, 22.4, nil])
+      right = Series.from_list([23.2, 21.8, 24.5, 22.9, 25.1, 23.7])
+
+      [left: left.data, right: right.data]
+    end
+
+    test "welch", %{left: left, right: right} do
+      hypothesis_test(:s_t_test, [left, right, false])
+      |> assert_result(-3.932252914392010, 0.004813081642716868, 7.594882249221776)
+    end
+
+    test "student", %{left: left, right: right} do
+      hypothesis_test(:s_t_test, [left, right, true])
+      |> assert_result(-4.025778179700554, 0.002991920924279960, 9.0)
+    end
+
+    test "raises when both samples have zero variance" do
+      left = Series.from_list([1.0, 1.0, 1.0])
+      right = Series.from_list([2.0, 2.0])
+
+      message = "t-test needs at least one sample with a non-zero variance"
+
+      assert_raise RuntimeError, ~r/#{message}/, fn ->
+        hypothesis_test(:s_t_test, [left.data, right.data, false])
+      end
+    end
+
+    test "raises with less than two values" do
+      left = Series.from_list([1.0])
+      right = Series.from_list([2.0, 3.0])
+
+      assert_raise RuntimeError, ~r/t-test needs at least two values in each sample/, fn ->
+        hypothesis_test(:s_t_test, [left.data, right.data, false])
+      end
+    end
+  end
+
+  describe "chi-squared goodness-of-fit test" do
+    test "with equally likely categories" do
+      observed = Series.from_list([16, 18, 16, 14, 12, 12])
+
+      hypothesis_test(:s_chi_squared_gof, [observed.data, nil])
+      |> assert_result(2.0, 0.8491450360846096, 5.0)
+    end
+
+    test "with expected frequencies" do
+      observed = Series.from_list([16, 18, 16, 14, 12, 12])
+      expected = Series.from_list([16, 16, 16, 16, 16, 8])
+
+      hypothesis_test(:s_chi_squared_gof, [observed.data, expected.data])
+      |> assert_result(3.5, 0.6233876277495820, 5.0)
+    end
+
+    test "raises on zero expected frequencies" do
+      observed = Series.from_list([16, 18])
+      expected = Series.from_list([1, 0])
+
+      assert_raise RuntimeError, ~r/needs positive expected frequencies/, fn ->
+        hypothesis_test(:s_chi_squared_gof, [observed.data, expected.data])
+      end
+    end
+  end
+
+  describe "chi-squared independence test" do
+    test "with a long contingency table" do
+      df =
+        DF.new(
+          row: ["a", "a", "a", "b", "b", "b"],
+          col: ["x", "y", "z", "x", "y", "z"],
+          count: [20, 15, 25, 30, 25, 10]
+        )
+
+      hypothesis_test(:df_chi_squared_independence_test, [df.data, "row", "col", "count"])
+      |> assert_result(10.745764652014652, 0.004640735900808554, 2.0)
+    end
+
+    test "raises on empty rows or columns" do
+      df =
+        DF.new(
+          row: ["a", "a", "b", "b"],
+          col: ["x", "y", "x", "y"],
+          count: [20, 0, 30, 0]
+        )
+
+      message = "needs a non-zero count in every row and column"
+
+      assert_raise RuntimeError, ~r/#{message}/, fn ->
+        hypothesis_test(:df_chi_squared_independence_test, [df.data, "row", "col", "count"])
+      end
+    end
+  end
+end
@@ -25,7 +25,7 @@ defmodule Explorer.RandomTest do
   test "draw_from_normal is reproducible - test case" do
     :rand.seed(:exro928ss, {42, 42, 42})
 
-    df = Random.draw_from_normal(0.0, 1.0, 5)
+    assert {:ok, df} = Random.draw_from_normal(0.0, 1.0, 5)
 
     assert Series.to_list(df[:x]) == [
       1.5517820245173137,
@@ -45,14 +45,14 @@ defmodule Explorer.RandomTest do
               sigma <- StreamData.float(min: 0.0) do
       # Set up a seed to make things reproducible
       :rand.seed(:exro928ss, seed)
-      df1 = Random.draw_from_normal(mu, sigma, nr_of_draws)
+      df1 = Random.draw_from_normal!(mu, sigma, nr_of_draws)
 
       # Draw more numbers and discard them
-      _discard = Random.draw_from_normal(0.0, 1.0, :rand.uniform(1000))
+      _discard = Random.draw_from_normal!(0.0, 1.0, :rand.uniform(1000))
 
       # Set up a new seed and draw more random numbers
       :rand.seed(:exro928ss, seed)
-      df2 = Random.draw_from_normal(mu, sigma, nr_of_draws)
+      df2 = Random.draw_from_normal!(mu, sigma, nr_of_draws)
 
       # The values should be equal
       assert Series.to_list(df1[:x]) == Series.to_list(df2[:x])
@@ -66,62 +66,215 @@ defmodule Explorer.RandomTest do
               b <- StreamData.float(min: 0.1) do
       # Set up a seed to make things reproducible
       :rand.seed(:exro928ss, seed)
-      df1 = Random.draw_from_beta(a, b, nr_of_draws)
+      df1 = Random.draw_from_beta!(a, b, nr_of_draws)
 
       # Draw more numbers and discard them
-      _discard = Random.draw_from_beta(1.0, 1.0, :rand.uniform(1000))
+      _discard = Random.draw_from_beta!(1.0, 1.0, :rand.uniform(1000))
 
       # Set up a new seed and draw more random numbers
       :rand.seed(:exro928ss, seed)
-      df2 = Random.draw_from_beta(a, b, nr_of_draws)
+      df2 = Random.draw_from_beta!(a, b, nr_of_draws)
 
       # The values should be equal
       assert dataframe_all_equal(df1, df2)
     end
   end
 
-  # # Skip this test until we re-implement the dirichlet distribution
-  # # from the distributions in `statrs` (previously it was implemented
-  # # with the distributions from `rand_dist`)
-  # property "draw_from_dirichlet is reproducible if the seed is set" do
-  #   check all seed <- random_seed_gen(),
-  #             nr_of_draws <- StreamData.integer(0..20_000),
-  #             alphas <- StreamData.list_of(StreamData.float(min: 0.1),
-  #                                          min_length: 2,
-  #                                          max_length: 32) do
-  #     # Set up a seed to make things reproducible
-  #     :rand.seed(:exro928ss, seed)
-  #     df1 = Random.draw_from_dirichlet(alphas, nr_of_draws)
+  property "draw_from_poisson is reproducible if the seed is set" do
+    check all seed <- random_seed_gen(),
+              nr_of_draws <- StreamData.integer(0..20_000),
+              lambda <- StreamData.float(min: 0.1, max: 1000.0) do
+      # Set up a seed to make things reproducible
+      :rand.seed(:exro928ss, seed)
+      df1 = Random.draw_from_poisson!(lambda, nr_of_draws)
+
+      # Draw more numbers and discard them
+      _discard = Random.draw_from_poisson!(1.0, :rand.uniform(1000))
+
+      # Set up a new seed and draw more random numbers
+      :rand.seed(:exro928ss, seed)
+      df2 = Random.draw_from_poisson!(lambda, nr_of_draws)
+
+      # The values should be equal
+      assert dataframe_all_equal(df1, df2)
+    end
+  end
+
+  test "discrete distributions return integer values" do
+    assert Series.dtype(Random.draw_from_poisson!(2.0, 10)[:x]) == {:u, 64}
+    assert Series.dtype(Random.draw_from_geometric!(0.5, 10)[:x]) == {:u, 64}
+    assert Series.dtype(Random.draw_from_hypergeometric!(50, 10, 5, 10)[:x]) == {:u, 64}
+    assert Series.dtype(Random.draw_from_binomial!(0.3, 20, 10)[:x]) == {:u, 64}
+    assert Series.dtype(Random.draw_from_categorical!([1, 2, 3], 10)[:x]) == {:u, 64}
+    assert Series.dtype(Random.draw_from_discrete_uniform!(-5, 5, 10)[:x]) == {:s, 64}
+
+    df = Random.draw_from_bernoulli!(0.5, 100)
+    assert Series.dtype(df[:x]) == {:u, 64}
+    assert Enum.all?(Series.to_list(df[:x]), &(&1 in [0, 1]))
+  end
+
+  test "invalid distribution parameters return an error instead of crashing" do
+    message =
+      "Invalid parameters for the Normal distribution (mu = 0.0, sigma = -1.0): " <>
+        "Standard deviation is NaN, zero or less than zero"
+
+    assert {:error, %ArgumentError{message: ^message}} = Random.draw_from_normal(0.0, -1.0, 10)
+
+    assert_raise ArgumentError, message, fn ->
+      Random.draw_from_normal!(0.0, -1.0, 10)
+    end
+
+    message =
+      "Invalid parameters for the Poisson distribution (lambda = 0.0): " <>
+        "Lambda is NaN, zero or less than zero"
+
+    assert_raise ArgumentError, message, fn ->
+      Random.draw_from_poisson!(0.0, 10)
+    end
+  end
+
+  test "invalid parameters of multivariate distributions name the distribution" do
+    message =
+      "Invalid parameters for the Dirichlet distribution (alpha = [1.0]): " <>
+        "Alpha contains less than two elements"
+
+    assert_raise ArgumentError, message, fn ->
+      Random.draw_from_dirichlet!([1.0], 10)
+    end
+
+    message =
+      "Invalid parameters for the Multinomial distribution (p = [0.5, -0.5], n = 10): " <>
+        "At least one probability is NaN, infinity or less than zero"
+
+    assert_raise ArgumentError, message, fn ->
+      Random.draw_from_multinomial!([0.5, -0.5], 10, 10)
+    end
+
+    message =
+      "Invalid parameters for the MultivariateNormal distribution " <>
+        "(cov = [[1.0, 0.5], [0.2, 1.0]]): " <>
+        "Covariance matrix must be symmetric, but cov[0][1] = 0.5 and cov[1][0] = 0.2"
+
+    assert_raise ArgumentError, message, fn ->
+      Random.draw_from_multivariate_normal!([0.0, 1.0], [[1.0, 0.5], [0.2, 1.0]], 10)
+    end
+  end
+
+  property "draw_from_dirichlet is reproducible if the seed is set" do
+    check all seed <- random_seed_gen(),
+              nr_of_draws <- StreamData.integer(0..20_000),
+              alphas <- StreamData.list_of(StreamData.float(min: 0.1),
+                                           min_length: 2,
+                                           max_length: 32) do
+      # Set up a seed to make things reproducible
+      :rand.seed(:exro928ss, seed)
+      df1 = Random.draw_from_dirichlet!(alphas, nr_of_draws)
+
+      # Draw more numbers and discard them
+      _discard = Random.draw_from_dirichlet!(alphas, :rand.uniform(1000))
+
+      # Set up a new seed and draw more random numbers
+      :rand.seed(:exro928ss, seed)
+      df2 = Random.draw_from_dirichlet!(alphas, nr_of_draws)
+
+      # The values should be equal
+      assert dataframe_all_equal(df1, df2)
+    end
+  end
+
+  property "draw_from_dirichlet generated variables x_0, x_1, ... x_n" do
+    check all nr_of_draws <- StreamData.integer(0..5),
+              alphas <- StreamData.list_of(StreamData.float(min: 0.1),
+                                           min_length: 2,
+                                           max_length: 32) do
+
+      df = Random.draw_from_dirichlet!(alphas, nr_of_draws)
+
+      expected_columns = ["draw"] ++ (for i <- 0..(length(alphas) - 1), do: "x_#{i}")
+
+      assert DataFrame.names(df) == expected_columns
+    end
+  end
 
-  #     # Draw more numbers and discard them
-  #     _discard = Random.draw_from_dirichlet(alphas, :rand.uniform(1000))
+  test "draw_from_multinomial returns the counts for each event" do
+    df = Random.draw_from_multinomial!([0.2, 0.3, 0.5], 10, 100)
 
-  #     # Set up a new seed and draw more random numbers
-  #     :rand.seed(:exro928ss, seed)
-  #     df2 = Random.draw_from_dirichlet(alphas, nr_of_draws)
+    assert DataFrame.names(df) == ["draw", "x_0", "x_1", "x_2"]
+    assert Series.dtype(df[:x_0]) == {:u, 64}
 
-  #     # The values should be equal
-  #     assert dataframe_all_equal(df1, df2)
-  #   end
-  # end
+    totals = Series.add(Series.add(df[:x_0], df[:x_1]), df[:x_2])
+    assert Enum.all?(Series.to_list(totals), &(&1 == 10))
+  end
 
-  # # Skip this test until we re-implement the dirichlet distribution
-  # # from the distributions in `statrs` (previously it was implemented
-  # # with the distributions from `rand_dist`)
-  # @tag skip: true
-  # property "draw_from_dirichlet generated variables x1, x2, ... xn" do
-  #   check all nr_of_draws <- StreamData.integer(0..5),
-  #             alphas <- StreamData.list_of(StreamData.float(min: 0.1),
-  #                                          min_length: 2,
-  #                                          max_length: 32) do
+  test "draw_from_multivariate_normal validates the covariance matrix" do
+    df = Random.draw_from_multivariate_normal!([0, 1], [[1, 0.5], [0.5, 2]], 10)
+    assert DataFrame.names(df) == ["draw", "x_0", "x_1"]
 
-  #     df = Random.draw_from_dirichlet(alphas, nr_of_draws)
+    assert_raise ArgumentError, ~r/Covariance matrix must be 2x2/, fn ->
+      Random.draw_from_multivariate_normal!([0, 1], [[1, 0.5, 0], [0.5, 2, 0]], 10)
+    end
+  end
 
-  #     expected_columns = ["draw"] ++ (for i <- 1..length(alphas), do: "x#{i}")
+  test "draw_from_multivariate_normal accepts a covariance matrix with rounding errors" do
+    cov = [[1.0, 0.1 + 0.2], [0.3, 2.0]]
+    df = Random.draw_from_multivariate_normal!([0, 1], cov, 10)
+    assert DataFrame.n_rows(df) == 10
 
-  #     assert DataFrame.names(df) == expected_columns
-  #   end
-  # end
+    assert_raise ArgumentError, ~r/Covariance matrix must be symmetric/, fn ->
+      Random.draw_from_multivariate_normal!([0, 1], [[1, 0.5], [0.4999, 2]], 10)
+    end
+  end
+
+  describe "draws as expressions" do
+    alias Explorer.PolarsBackend.Native
+    alias Explorer.PolarsBackend.Shared
+
+    defp mutate_with_draw(df, draw_expr) do
+      {:ok, lf} = Native.df_lazy(df.data)
+      {:ok, lf} = Native.lf_mutate_with(lf, [Native.expr_alias(draw_expr, "x")])
+      {:ok, polars_df} = Native.lf_compute(lf)
+
+      Shared.create_dataframe!(polars_df)
+    end
+
+    test "draws one value per row with parameters from other columns" do
+      df = DataFrame.new(mu: [0.0, 1000.0, nil, -1000.0])
+
+      draw = Native.expr_draw_from_normal(42, Native.expr_column("mu"), Native.expr_float(1.0))
+      result = mutate_with_draw(df, draw)
+
+      assert [a, b, nil, c] = Series.to_list(result[:x])
+      assert abs(a) < 10
+      assert abs(b - 1000) < 10
+      assert abs(c + 1000) < 10
+    end
+
+    test "draws are reproducible and broadcast literal parameters" do
+      df = DataFrame.new(a: Enum.to_list(1..100))
+
+      draw = fn -> Native.expr_draw_from_poisson(7, Native.expr_float(3.0)) end
+      result1 = mutate_with_draw(df, draw.())
+      result2 = mutate_with_draw(df, draw.())
+
+      assert Series.dtype(result1[:x]) == {:u, 64}
+      assert Series.size(result1[:x]) == 100
+      assert Series.n_distinct(result1[:x]) > 1
+      assert Series.to_list(result1[:x]) == Series.to_list(result2[:x])
+    end
+  end
+
+  test "large draws only depend on the seed and on the number of draws" do
+    # Draws are generated in parallel chunks of 65536 values,
+    # so this covers values from different chunks.
+    {:ok, df1} = Explorer.PolarsBackend.Native.df_draw_from_normal(42, 0.0, 1.0, 200_000)
+    {:ok, df2} = Explorer.PolarsBackend.Native.df_draw_from_normal(42, 0.0, 1.0, 150_000)
+
+    df1 = Explorer.PolarsBackend.Shared.create_dataframe!(df1)
+    df2 = Explorer.PolarsBackend.Shared.create_dataframe!(df2)
+
+    assert Series.to_list(Series.head(df1[:x], 150_000)) == Series.to_list(df2[:x])
+    assert Series.n_distinct(df1[:x]) == 200_000
+  end
 
   # Tests that check for problems with numerical accuracy
 
@@ -142,7 +295,7 @@ defmodule Explorer.RandomTest do
 
         :rand.seed(:exro928ss, seed)
 
-        df = Random.draw_from_normal(mu, sigma, nr_of_draws)
+        df = Random.draw_from_normal!(mu, sigma, nr_of_draws)
         values = Series.to_list(df[:x])
 
         # The values should be all different
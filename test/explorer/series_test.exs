@@ -3689,6 +3689,82 @@ defmodule Explorer.SeriesTest do
     end
   end
 
+  describe "draw/4" do
+    test "draws one value per element" do
+      s = Series.from_list([10, 20, 30, 40, 50])
+
+      result = Series.draw(s, :uniform, [min: 1.0, max: 2.0], seed: 100)
+
+      assert Series.size(result) == 5
+      assert Series.dtype(result) == {:f, 64}
+      assert Enum.all?(Series.to_list(result), &(&1 >= 1.0 and &1 < 2.0))
+    end
+
+    test "is reproducible with a seed" do
+      s = Series.from_list([1, 2, 3, 4, 5])
+
+      assert Series.to_list(Series.draw(s, :normal, [mu: 0.0, sigma: 1.0], seed: 100)) ==
+               Series.to_list(Series.draw(s, :normal, [mu: 0.0, sigma: 1.0], seed: 100))
+
+      refute Series.to_list(Series.draw(s, :normal, [mu: 0.0, sigma: 1.0], seed: 100)) ==
+               Series.to_list(Series.draw(s, :normal, [mu: 0.0, sigma: 1.0], seed: 101))
+    end
+
+    test "takes parameters from series" do
+      s = Series.from_list([1, 2, 3])
+      min = Series.from_list([0, 10, nil])
+      max = Series.from_list([0, 10, 20])
+
+      result = Series.draw(s, :discrete_uniform, [min: min, max: max], seed: 100)
+
+      assert Series.dtype(result) == {:s, 64}
+      assert Series.to_list(result) == [0, 10, nil]
+    end
+
+    test "draws unsigned integers from discrete distributions" do
+      s = Series.from_list([1, 2, 3])
+
+      result = Series.draw(s, :binomial, [p: 1.0, n: 5], seed: 100)
+
+      assert Series.dtype(result) == {:u, 64}
+      assert Series.to_list(result) == [5, 5, 5]
+    end
+
+    test "raises on unknown distributions and parameters" do
+      s = Series.from_list([1, 2, 3])
+
+      assert_raise ArgumentError, ~r/unsupported distribution :foo/, fn ->
+        Series.draw(s, :foo, p: 0.5)
+      end
+
+      assert_raise ArgumentError, ~r/missing parameter :sigma for the :normal distribution/, fn ->
+        Series.draw(s, :normal, mu: 0.0)
+      end
+
+      assert_raise ArgumentError, ~r/unknown keys \[:lambda\]/, fn ->
+        Series.draw(s, :normal, mu: 0.0, sigma: 1.0, lambda: 1.0)
+      end
+
+      message = "Explorer.Series.draw/4 not implemented for dtype :string"
+
+      assert_raise ArgumentError, ~r/#{Regex.escape(message)}/, fn ->
+        Series.draw(s, :poisson, lambda: Series.from_list(["a", "b", "c"]))
+      end
+    end
+
+    test "raises on invalid parameters" do
+      s = Series.from_list([1, 2, 3])
+
+      message =
+        "Invalid parameters for the Normal distribution (mu = 0.0, sigma = -1.0): " <>
+          "Standard deviation is NaN, zero or less than zero"
+
+      assert_raise RuntimeError, ~r/#{Regex.escape(message)}/, fn ->
+        Series.draw(s, :normal, mu: 0.0, sigma: -1.0)
+      end
+    end
+  end
+
   describe "select/3" do
     test "select elements of the same type" do
       predicate = [true, false, false, true, false] |> Series.from_list()
@@ -6286,6 +6362,59 @@ defmodule Explorer.SeriesTest do
     end
   end
 
+  describe "to_arrow_c_data/1 and from_arrow_c_data/2" do
+    test "round trips the series with its name and dtype" do
+      for series <- [
+            Series.from_list([1, nil, 3], dtype: :u8),
+            Series.from_list([1.5, nil, :nan]),
+            Series.from_list(["a", nil, "c"]),
+            Series.from_list(["a", "b", "a"], dtype: :category),
+            Series.from_list([~N[2024-01-01 10:00:00.123456], nil]),
+            Series.from_list([[1, 2], [], nil]),
+            Series.from_list([%{a: 1, b: "x"}, %{a: 2, b: nil}])
+          ] do
+        series = Series.rename(series, "column")
+        imported = series |> Series.to_arrow_c_data() |> Series.from_arrow_c_data()
+
+        assert imported.name == "column"
+        assert imported.dtype == series.dtype
+        assert Series.to_list(imported) == Series.to_list(series)
+      end
+    end
+
+    test "returns the addresses of the structs" do
+      c_data = Series.to_arrow_c_data(Series.from_list([1, 2, 3]))
+
+      assert %{array: array, schema: schema, owner: owner} = c_data
+      assert is_integer(array) and array > 0
+      assert is_integer(schema) and schema > 0
+      assert is_reference(owner)
+    end
+
+    test "raises with a null address" do
+      assert_raise RuntimeError, ~r/Incorrect schema pointer/, fn ->
+        Series.from_arrow_c_data(%{array: 0, schema: 0})
+      end
+    end
+
+    test "raises when the array was already imported" do
+      c_data = Series.to_arrow_c_data(Series.from_list([1, 2, 3]))
+
+      assert Series.to_list(Series.from_arrow_c_data(c_data)) == [1, 2, 3]
+
+      assert_raise RuntimeError, ~r/the array was already released, or imported/, fn ->
+        Series.from_arrow_c_data(c_data)
+      end
+    end
+
+    test "exports a series with many chunks" do
+      series = Series.concat([Series.from_list([1, 2]), Series.from_list([3])])
+      imported = series |> Series.to_arrow_c_data() |> Series.from_arrow_c_data()
+
+      assert Series.to_list(imported) == [1, 2, 3]
+    end
+  end
+
   describe "from_binary/2" do
     test "64-bit signed integer" do
       series =
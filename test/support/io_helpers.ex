@@ -41,4 +41,42 @@ defmodule Explorer.IOHelpers do
       tmp_filename(fn filename -> :ok = apply(DF, unquote(to_name), [df, filename]) end)
     end
   end
+
+  # Serves `bytes` at `path` like object stores do, answering `HEAD` requests
+  # and the "range" header. The size of each `GET` response is sent to the
+  # test process as `{:served, path, size}`.
+  def serve_object(bypass, path, bytes, check_conn \\ fn _conn -> :ok end) do
+    test_pid = self()
+    size = byte_size(bytes)
+
+    Bypass.stub(bypass, "HEAD", path, fn conn ->
+      check_conn.(conn)
+      Plug.Conn.resp(conn, 200, bytes)
+    end)
+
+    Bypass.stub(bypass, "GET", path, fn conn ->
+      check_conn.(conn)
+
+      case Plug.Conn.get_req_header(conn, "range") do
+        ["bytes=" <> range] ->
+          {first, last} =
+            case String.split(range, "-") do
+              ["", suffix] -> {max(size - String.to_integer(suffix), 0), size - 1}
+              [first, ""] -> {String.to_integer(first), size - 1}
+              [first, last] -> {String.to_integer(first), min(String.to_integer(last), size - 1)}
+            end
+
+          part = binary_part(bytes, first, last - first + 1)
+          send(test_pid, {:served, path, byte_size(part)})
+
+          conn
+          |> Plug.Conn.put_resp_header("content-range", "bytes #{first}-#{last}/#{size}")
+          |> Plug.Conn.resp(206, part)
+
+        [] ->
+          send(test_pid, {:served, path, size})
+          Plug.Conn.resp(conn, 200, bytes)
+      end
+    end)
+  end
 end
@@ -4,6 +4,10 @@
 # Along with the service, it creates a bucket and store a parquet
 # file there.
 #
+# It also starts the "fake-gcs-server" and "Azurite" emulators, of
+# Google Cloud Storage and Azure Blob Storage, with a bucket and a
+# container respectively.
+#
 # This script requires podman or docker, and the aws-cli installed.
 
 # Exit in the first error.
@@ -43,6 +47,8 @@ fi
 # Run podman or docker.
 command "$container_tool" run -d -p 4566:4566 docker.io/localstack/localstack:s3-latest
 command "$container_tool" run -d -p 1338:1338 amazon/amazon-ec2-metadata-mock:v1.9.2 --imdsv2
+command "$container_tool" run -d -p 4443:4443 docker.io/fsouza/fake-gcs-server -scheme http
+command "$container_tool" run -d -p 10000:10000 mcr.microsoft.com/azure-storage/azurite azurite-blob --blobHost 0.0.0.0
 echo "waiting a little bit.."
 sleep 5
 
@@ -53,3 +59,12 @@ sleep 2
 
 echo "uploading file"
 aws --region us-east-1 --endpoint-url=http://localhost:4566 s3 cp "$FILE_PATH" s3://test-bucket/wine.parquet
+
+echo "creating GCS bucket"
+curl --silent --fail -X POST http://localhost:4443/storage/v1/b -H "Content-Type: application/json" -d '{"name": "test-bucket"}'
+
+echo "creating Azure container"
+# The well-known account and key of Azurite.
+AZURITE_CONNECTION_STRING="DefaultEndpointsProtocol=http;AccountName=devstoreaccount1;AccountKey=Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==;BlobEndpoint=http://127.0.0.1:10000/devstoreaccount1;"
+command "$container_tool" run --rm --network host mcr.microsoft.com/azure-cli \
+  az storage container create --name test-container --connection-string "$AZURITE_CONNECTION_STRING"